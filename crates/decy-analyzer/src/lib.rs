@@ -9,6 +9,7 @@
 pub mod lock_analysis;
 pub mod output_params;
 pub mod patterns;
+pub mod race_analysis;
 pub mod subprocess_analysis;
 pub mod tagged_union_analysis;
 pub mod void_ptr_analysis;
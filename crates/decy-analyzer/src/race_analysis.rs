@@ -0,0 +1,482 @@
+//! Data-race diagnostics for unsynchronized shared globals (DECY-267).
+//!
+//! Flags globals that are mutated from more than one function, or guarded by
+//! a check-then-act accessor, or published via a flag without a
+//! release/acquire fence - the shapes covered by the race-condition property
+//! tests in `decy-core` - without yet being wrapped in the atomic or
+//! `Mutex<T>` lowerings. Each finding reports the global name, the
+//! conflicting functions, and the race class so callers can decide whether
+//! to opt into those lowerings.
+
+use decy_hir::{HirExpression, HirFunction, HirStatement};
+use std::collections::{HashMap, HashSet};
+
+/// The kind of data race a [`RaceDiagnostic`] describes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RaceClass {
+    /// Two or more functions perform a read-modify-write on the same global
+    /// without synchronization, so concurrent writers can silently drop an
+    /// update (e.g. `x = x + 1;` from more than one writer).
+    LostUpdate,
+    /// A function reads a global, branches on its value, and then mutates
+    /// it - a classic time-of-check-to-time-of-use (TOCTOU) race under
+    /// concurrent calls.
+    CheckThenAct,
+    /// A function writes a "payload" global and then a separate "ready"
+    /// flag; another function branches on the flag and then reads the
+    /// payload. Without a release/acquire fence, observing the flag does not
+    /// guarantee observing the payload write.
+    PublicationWithoutFence,
+}
+
+/// A single data-race finding: the unsynchronized global(s), the conflicting
+/// functions, and the race class.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaceDiagnostic {
+    /// Name of the unsynchronized global (for [`RaceClass::PublicationWithoutFence`],
+    /// the payload global; see `flag` for the companion global).
+    pub global: String,
+    /// For [`RaceClass::PublicationWithoutFence`], the flag global published
+    /// alongside `global`. `None` for the other race classes.
+    pub flag: Option<String>,
+    /// Names of the functions involved in the race, in the order discovered
+    /// (sorted by name for [`RaceClass::LostUpdate`]).
+    pub functions: Vec<String>,
+    /// The kind of race detected.
+    pub race_class: RaceClass,
+}
+
+/// Analyzes a set of functions for unsynchronized shared-global races.
+pub struct RaceAnalyzer;
+
+impl RaceAnalyzer {
+    /// Create a new race analyzer.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Finds every unsynchronized global race across `functions`.
+    pub fn analyze(&self, functions: &[HirFunction]) -> Vec<RaceDiagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut flagged_globals: HashSet<String> = HashSet::new();
+
+        // Check-then-act: a function reads a global in a guard condition and
+        // mutates it in the guard's then-block.
+        for func in functions {
+            if let Some(global) = Self::check_then_act_global(func.body()) {
+                flagged_globals.insert(global.clone());
+                diagnostics.push(RaceDiagnostic {
+                    global,
+                    flag: None,
+                    functions: vec![func.name().to_string()],
+                    race_class: RaceClass::CheckThenAct,
+                });
+            }
+        }
+
+        // Publication without fence: a producer writes a payload then a
+        // flag; a separate consumer branches on the flag and then reads the
+        // payload.
+        for pair in Self::publication_pairs(functions) {
+            flagged_globals.insert(pair.global.clone());
+            diagnostics.push(pair);
+        }
+
+        // Lost update: a global assigned from two or more distinct
+        // functions, not already explained by a more specific race class.
+        let writers = Self::writers_by_global(functions);
+        let mut lost_update_globals: Vec<&String> = writers
+            .iter()
+            .filter(|(global, funcs)| funcs.len() >= 2 && !flagged_globals.contains(*global))
+            .map(|(global, _)| global)
+            .collect();
+        lost_update_globals.sort();
+        for global in lost_update_globals {
+            let mut funcs: Vec<String> = writers[global].iter().cloned().collect();
+            funcs.sort();
+            diagnostics.push(RaceDiagnostic {
+                global: global.clone(),
+                flag: None,
+                functions: funcs,
+                race_class: RaceClass::LostUpdate,
+            });
+        }
+
+        diagnostics
+    }
+
+    /// Maps each global name to the set of functions that assign to it
+    /// (directly or inside nested control flow). Names a function declares
+    /// as a parameter or local variable are not counted for that function.
+    fn writers_by_global(functions: &[HirFunction]) -> HashMap<String, HashSet<String>> {
+        let mut writers: HashMap<String, HashSet<String>> = HashMap::new();
+        for func in functions {
+            let mut assigned = HashSet::new();
+            let mut declared: HashSet<String> =
+                func.parameters().iter().map(|p| p.name().to_string()).collect();
+            Self::collect_assigned_names(func.body(), &mut assigned, &mut declared);
+            for name in assigned.difference(&declared) {
+                writers
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(func.name().to_string());
+            }
+        }
+        writers
+    }
+
+    fn collect_assigned_names(
+        stmts: &[HirStatement],
+        out: &mut HashSet<String>,
+        declared: &mut HashSet<String>,
+    ) {
+        for stmt in stmts {
+            match stmt {
+                HirStatement::Assignment { target, .. } => {
+                    out.insert(target.clone());
+                }
+                HirStatement::VariableDeclaration { name, .. } => {
+                    declared.insert(name.clone());
+                }
+                HirStatement::If {
+                    then_block,
+                    else_block,
+                    ..
+                } => {
+                    Self::collect_assigned_names(then_block, out, declared);
+                    if let Some(else_stmts) = else_block {
+                        Self::collect_assigned_names(else_stmts, out, declared);
+                    }
+                }
+                HirStatement::While { body, .. } => {
+                    Self::collect_assigned_names(body, out, declared)
+                }
+                HirStatement::For { body, .. } => Self::collect_assigned_names(body, out, declared),
+                _ => {}
+            }
+        }
+    }
+
+    /// Detects a function whose body is (or contains, at the top level) an
+    /// `if` that reads `global` in its condition and assigns to the same
+    /// `global` in its then-block - the guarded read-modify-write shape that
+    /// races under concurrent calls regardless of how many functions touch
+    /// the global.
+    fn check_then_act_global(body: &[HirStatement]) -> Option<String> {
+        for stmt in body {
+            if let HirStatement::If {
+                condition,
+                then_block,
+                ..
+            } = stmt
+            {
+                if let Some(guarded) = Self::referenced_variable(condition) {
+                    let assigns_guarded = then_block.iter().any(
+                        |s| matches!(s, HirStatement::Assignment { target, .. } if target == &guarded),
+                    );
+                    if assigns_guarded {
+                        return Some(guarded);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the variable name referenced by a simple `name <op> literal`
+    /// (or `literal <op> name`) comparison, the shape used by the
+    /// check-then-act and publication guard conditions this module detects.
+    fn referenced_variable(expr: &HirExpression) -> Option<String> {
+        let HirExpression::BinaryOp { left, right, .. } = expr else {
+            return None;
+        };
+        match (left.as_ref(), right.as_ref()) {
+            (HirExpression::Variable(name), _) => Some(name.clone()),
+            (_, HirExpression::Variable(name)) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
+    /// Finds producer/consumer pairs where one function assigns a payload
+    /// global then a flag global, in that order, and a different function
+    /// branches on the flag and then reads the payload.
+    fn publication_pairs(functions: &[HirFunction]) -> Vec<RaceDiagnostic> {
+        let mut producers: Vec<(String, String, String)> = Vec::new();
+        let mut consumers: Vec<(String, String, String)> = Vec::new();
+
+        for func in functions {
+            let mut assignments = Vec::new();
+            Self::collect_ordered_assignments(func.body(), &mut assignments);
+            for i in 0..assignments.len() {
+                for j in (i + 1)..assignments.len() {
+                    let (payload, _) = &assignments[i];
+                    let (flag, flag_value) = &assignments[j];
+                    if payload != flag && matches!(flag_value, HirExpression::IntLiteral(_)) {
+                        producers.push((payload.clone(), flag.clone(), func.name().to_string()));
+                    }
+                }
+            }
+
+            if let Some((flag, payload)) = Self::consumer_flag_and_payload(func.body()) {
+                consumers.push((flag, payload, func.name().to_string()));
+            }
+        }
+
+        let mut pairs = Vec::new();
+        for (payload, flag, producer_fn) in &producers {
+            for (cons_flag, cons_payload, consumer_fn) in &consumers {
+                if flag == cons_flag && payload == cons_payload && producer_fn != consumer_fn {
+                    pairs.push(RaceDiagnostic {
+                        global: payload.clone(),
+                        flag: Some(flag.clone()),
+                        functions: vec![producer_fn.clone(), consumer_fn.clone()],
+                        race_class: RaceClass::PublicationWithoutFence,
+                    });
+                }
+            }
+        }
+        pairs
+    }
+
+    fn collect_ordered_assignments(stmts: &[HirStatement], out: &mut Vec<(String, HirExpression)>) {
+        for stmt in stmts {
+            match stmt {
+                HirStatement::Assignment { target, value } => {
+                    out.push((target.clone(), value.clone()));
+                }
+                HirStatement::If {
+                    then_block,
+                    else_block,
+                    ..
+                } => {
+                    Self::collect_ordered_assignments(then_block, out);
+                    if let Some(else_stmts) = else_block {
+                        Self::collect_ordered_assignments(else_stmts, out);
+                    }
+                }
+                HirStatement::While { body, .. } => Self::collect_ordered_assignments(body, out),
+                HirStatement::For { body, .. } => Self::collect_ordered_assignments(body, out),
+                _ => {}
+            }
+        }
+    }
+
+    /// Detects a function whose body is (or contains, at the top level) an
+    /// `if` that branches on a flag global and whose then-block returns a
+    /// different, payload, global - the consumer half of the publication
+    /// race.
+    fn consumer_flag_and_payload(body: &[HirStatement]) -> Option<(String, String)> {
+        for stmt in body {
+            if let HirStatement::If {
+                condition,
+                then_block,
+                ..
+            } = stmt
+            {
+                let Some(flag) = Self::referenced_variable(condition) else {
+                    continue;
+                };
+                for inner in then_block {
+                    if let HirStatement::Return(Some(HirExpression::Variable(payload))) = inner {
+                        if payload != &flag {
+                            return Some((flag, payload.clone()));
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+impl Default for RaceAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decy_hir::{BinaryOperator, HirType};
+
+    fn func(name: &str, body: Vec<HirStatement>) -> HirFunction {
+        HirFunction::new_with_body(name.to_string(), HirType::Int, vec![], body)
+    }
+
+    #[test]
+    fn test_detects_lost_update_across_two_functions() {
+        let increment = func(
+            "increment",
+            vec![HirStatement::Assignment {
+                target: "counter".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: BinaryOperator::Add,
+                    left: Box::new(HirExpression::Variable("counter".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            }],
+        );
+        let decrement = func(
+            "decrement",
+            vec![HirStatement::Assignment {
+                target: "counter".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: BinaryOperator::Subtract,
+                    left: Box::new(HirExpression::Variable("counter".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            }],
+        );
+
+        let diagnostics = RaceAnalyzer::new().analyze(&[increment, decrement]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].global, "counter");
+        assert_eq!(diagnostics[0].race_class, RaceClass::LostUpdate);
+        assert_eq!(
+            diagnostics[0].functions,
+            vec!["decrement".to_string(), "increment".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_detects_check_then_act() {
+        let allocate = func(
+            "allocate_resource",
+            vec![
+                HirStatement::If {
+                    condition: HirExpression::BinaryOp {
+                        op: BinaryOperator::GreaterThan,
+                        left: Box::new(HirExpression::Variable("resource_count".to_string())),
+                        right: Box::new(HirExpression::IntLiteral(0)),
+                    },
+                    then_block: vec![
+                        HirStatement::Assignment {
+                            target: "resource_count".to_string(),
+                            value: HirExpression::BinaryOp {
+                                op: BinaryOperator::Subtract,
+                                left: Box::new(HirExpression::Variable(
+                                    "resource_count".to_string(),
+                                )),
+                                right: Box::new(HirExpression::IntLiteral(1)),
+                            },
+                        },
+                        HirStatement::Return(Some(HirExpression::IntLiteral(1))),
+                    ],
+                    else_block: None,
+                },
+                HirStatement::Return(Some(HirExpression::IntLiteral(0))),
+            ],
+        );
+
+        let diagnostics = RaceAnalyzer::new().analyze(&[allocate]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].global, "resource_count");
+        assert_eq!(diagnostics[0].race_class, RaceClass::CheckThenAct);
+    }
+
+    #[test]
+    fn test_detects_check_then_act_after_unrelated_leading_if() {
+        // The first `if` in the body has a condition `referenced_variable`
+        // can't parse (a bare function-call guard), and must not abort the
+        // scan of the remaining statements - the real guarded
+        // check-then-act on `resource_count` is the second `if`.
+        let allocate = func(
+            "allocate_resource",
+            vec![
+                HirStatement::If {
+                    condition: HirExpression::FunctionCall {
+                        function: "should_log".to_string(),
+                        arguments: vec![],
+                    },
+                    then_block: vec![HirStatement::Assignment {
+                        target: "log_count".to_string(),
+                        value: HirExpression::IntLiteral(1),
+                    }],
+                    else_block: None,
+                },
+                HirStatement::If {
+                    condition: HirExpression::BinaryOp {
+                        op: BinaryOperator::GreaterThan,
+                        left: Box::new(HirExpression::Variable("resource_count".to_string())),
+                        right: Box::new(HirExpression::IntLiteral(0)),
+                    },
+                    then_block: vec![HirStatement::Assignment {
+                        target: "resource_count".to_string(),
+                        value: HirExpression::BinaryOp {
+                            op: BinaryOperator::Subtract,
+                            left: Box::new(HirExpression::Variable("resource_count".to_string())),
+                            right: Box::new(HirExpression::IntLiteral(1)),
+                        },
+                    }],
+                    else_block: None,
+                },
+            ],
+        );
+
+        let diagnostics = RaceAnalyzer::new().analyze(&[allocate]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].global, "resource_count");
+        assert_eq!(diagnostics[0].race_class, RaceClass::CheckThenAct);
+    }
+
+    #[test]
+    fn test_detects_publication_without_fence() {
+        let producer = func(
+            "producer",
+            vec![
+                HirStatement::Assignment {
+                    target: "shared_data".to_string(),
+                    value: HirExpression::IntLiteral(42),
+                },
+                HirStatement::Assignment {
+                    target: "data_ready".to_string(),
+                    value: HirExpression::IntLiteral(1),
+                },
+            ],
+        );
+        let consumer = func(
+            "consumer",
+            vec![
+                HirStatement::If {
+                    condition: HirExpression::BinaryOp {
+                        op: BinaryOperator::Equal,
+                        left: Box::new(HirExpression::Variable("data_ready".to_string())),
+                        right: Box::new(HirExpression::IntLiteral(1)),
+                    },
+                    then_block: vec![HirStatement::Return(Some(HirExpression::Variable(
+                        "shared_data".to_string(),
+                    )))],
+                    else_block: None,
+                },
+                HirStatement::Return(Some(HirExpression::IntLiteral(0))),
+            ],
+        );
+
+        let diagnostics = RaceAnalyzer::new().analyze(&[producer, consumer]);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].global, "shared_data");
+        assert_eq!(diagnostics[0].flag, Some("data_ready".to_string()));
+        assert_eq!(
+            diagnostics[0].race_class,
+            RaceClass::PublicationWithoutFence
+        );
+    }
+
+    #[test]
+    fn test_no_diagnostics_for_single_writer() {
+        let increment = func(
+            "increment",
+            vec![HirStatement::Assignment {
+                target: "counter".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: BinaryOperator::Add,
+                    left: Box::new(HirExpression::Variable("counter".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            }],
+        );
+
+        assert!(RaceAnalyzer::new().analyze(&[increment]).is_empty());
+    }
+}
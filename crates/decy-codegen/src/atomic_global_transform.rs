@@ -0,0 +1,240 @@
+//! Atomic global transformation module for global-counter → `AtomicI32` conversions.
+//!
+//! Transforms C global scalar counters that are only ever read wholly,
+//! written via a self-referencing add/subtract-by-constant (`x = x + k;`,
+//! `x = x - k;`, increment/decrement), or plainly stored to, into
+//! `AtomicI32` globals with `load`/`fetch_add`/`fetch_sub`/`store` access
+//! instead of `static mut` + `unsafe`.
+//!
+//! Part of DECY-264: Lower global integer counters to atomics.
+
+use decy_hir::{HirExpression, HirFunction, HirStatement};
+
+/// If `value` is a self-referencing `name + k` or `name - k` expression
+/// (the shape produced by `x = x + k;`, `x++`, `x--`, etc.), returns the
+/// signed delta `k` (negative for subtraction). Returns `None` for any
+/// other shape, including non-constant operands or a mismatched variable.
+pub fn self_rmw_delta(name: &str, value: &HirExpression) -> Option<i32> {
+    let HirExpression::BinaryOp { op, left, right } = value else {
+        return None;
+    };
+
+    let HirExpression::Variable(left_name) = left.as_ref() else {
+        return None;
+    };
+    if left_name != name {
+        return None;
+    }
+
+    let HirExpression::IntLiteral(k) = right.as_ref() else {
+        return None;
+    };
+
+    match op {
+        decy_hir::BinaryOperator::Add => Some(*k),
+        decy_hir::BinaryOperator::Subtract => Some(-*k),
+        _ => None,
+    }
+}
+
+/// Checks whether every read of `name` within `expr` is a whole-value read
+/// (i.e. its address is never taken).
+fn expr_is_safe_for_atomic(name: &str, expr: &HirExpression) -> bool {
+    match expr {
+        HirExpression::AddressOf(inner) => {
+            !matches!(inner.as_ref(), HirExpression::Variable(n) if n == name)
+        }
+        HirExpression::IntLiteral(_)
+        | HirExpression::StringLiteral(_)
+        | HirExpression::NullLiteral
+        | HirExpression::Variable(_)
+        | HirExpression::Sizeof { .. } => true,
+        HirExpression::BinaryOp { left, right, .. } => {
+            expr_is_safe_for_atomic(name, left) && expr_is_safe_for_atomic(name, right)
+        }
+        HirExpression::UnaryOp { operand, .. } => expr_is_safe_for_atomic(name, operand),
+        HirExpression::Dereference(inner) | HirExpression::IsNotNull(inner) => {
+            expr_is_safe_for_atomic(name, inner)
+        }
+        HirExpression::FunctionCall { arguments, .. } => arguments
+            .iter()
+            .all(|arg| expr_is_safe_for_atomic(name, arg)),
+        HirExpression::FieldAccess { object, .. } => expr_is_safe_for_atomic(name, object),
+        HirExpression::PointerFieldAccess { pointer, .. } => expr_is_safe_for_atomic(name, pointer),
+        HirExpression::ArrayIndex { array, index } => {
+            expr_is_safe_for_atomic(name, array) && expr_is_safe_for_atomic(name, index)
+        }
+        HirExpression::Calloc { count, .. } => expr_is_safe_for_atomic(name, count),
+        HirExpression::Malloc { size } => expr_is_safe_for_atomic(name, size),
+        HirExpression::Realloc { pointer, new_size } => {
+            expr_is_safe_for_atomic(name, pointer) && expr_is_safe_for_atomic(name, new_size)
+        }
+    }
+}
+
+/// Checks whether every statement in `body` accesses `name` only through a
+/// whole-value read or a plain-store / self-RMW assignment, recursing into
+/// nested control-flow bodies and conditions.
+fn stmts_are_safe_for_atomic(name: &str, stmts: &[HirStatement]) -> bool {
+    stmts.iter().all(|stmt| stmt_is_safe_for_atomic(name, stmt))
+}
+
+fn stmt_is_safe_for_atomic(name: &str, stmt: &HirStatement) -> bool {
+    match stmt {
+        HirStatement::Assignment { target, value } => {
+            if target == name {
+                self_rmw_delta(name, value).is_some()
+                    || (expr_is_safe_for_atomic(name, value)
+                        && !matches!(value, HirExpression::Variable(n) if n == name))
+            } else {
+                expr_is_safe_for_atomic(name, value)
+            }
+        }
+        HirStatement::VariableDeclaration { initializer, .. } => initializer
+            .as_ref()
+            .map_or(true, |init| expr_is_safe_for_atomic(name, init)),
+        HirStatement::Return(value) => value
+            .as_ref()
+            .map_or(true, |v| expr_is_safe_for_atomic(name, v)),
+        HirStatement::If {
+            condition,
+            then_block,
+            else_block,
+        } => {
+            expr_is_safe_for_atomic(name, condition)
+                && stmts_are_safe_for_atomic(name, then_block)
+                && else_block
+                    .as_ref()
+                    .map_or(true, |e| stmts_are_safe_for_atomic(name, e))
+        }
+        HirStatement::While { condition, body } => {
+            expr_is_safe_for_atomic(name, condition) && stmts_are_safe_for_atomic(name, body)
+        }
+        HirStatement::For {
+            init,
+            condition,
+            increment,
+            body,
+        } => {
+            init.as_deref()
+                .map_or(true, |s| stmt_is_safe_for_atomic(name, s))
+                && expr_is_safe_for_atomic(name, condition)
+                && increment
+                    .as_deref()
+                    .map_or(true, |s| stmt_is_safe_for_atomic(name, s))
+                && stmts_are_safe_for_atomic(name, body)
+        }
+        HirStatement::Switch {
+            condition,
+            cases,
+            default_case,
+        } => {
+            expr_is_safe_for_atomic(name, condition)
+                && cases
+                    .iter()
+                    .all(|c| stmts_are_safe_for_atomic(name, &c.body))
+                && default_case
+                    .as_ref()
+                    .map_or(true, |d| stmts_are_safe_for_atomic(name, d))
+        }
+        HirStatement::DerefAssignment { target, value } => {
+            expr_is_safe_for_atomic(name, target) && expr_is_safe_for_atomic(name, value)
+        }
+        HirStatement::ArrayIndexAssignment {
+            array,
+            index,
+            value,
+        } => {
+            expr_is_safe_for_atomic(name, array)
+                && expr_is_safe_for_atomic(name, index)
+                && expr_is_safe_for_atomic(name, value)
+        }
+        HirStatement::FieldAssignment { object, value, .. } => {
+            expr_is_safe_for_atomic(name, object) && expr_is_safe_for_atomic(name, value)
+        }
+        HirStatement::Free { pointer } => expr_is_safe_for_atomic(name, pointer),
+        HirStatement::Break | HirStatement::Continue => true,
+    }
+}
+
+/// Returns `true` if `name` is only ever accessed, across every function in
+/// `functions`, through a whole-value read or a plain-store / self-RMW
+/// assignment — i.e. it is a safe candidate for `AtomicI32` lowering.
+pub fn is_atomic_candidate(name: &str, functions: &[HirFunction]) -> bool {
+    functions
+        .iter()
+        .all(|func| stmts_are_safe_for_atomic(name, func.body()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decy_hir::{BinaryOperator, HirType};
+
+    #[test]
+    fn test_self_rmw_delta_detects_add() {
+        let value = HirExpression::BinaryOp {
+            op: BinaryOperator::Add,
+            left: Box::new(HirExpression::Variable("counter".to_string())),
+            right: Box::new(HirExpression::IntLiteral(3)),
+        };
+        assert_eq!(self_rmw_delta("counter", &value), Some(3));
+    }
+
+    #[test]
+    fn test_self_rmw_delta_detects_subtract() {
+        let value = HirExpression::BinaryOp {
+            op: BinaryOperator::Subtract,
+            left: Box::new(HirExpression::Variable("counter".to_string())),
+            right: Box::new(HirExpression::IntLiteral(1)),
+        };
+        assert_eq!(self_rmw_delta("counter", &value), Some(-1));
+    }
+
+    #[test]
+    fn test_self_rmw_delta_rejects_other_variable() {
+        let value = HirExpression::BinaryOp {
+            op: BinaryOperator::Add,
+            left: Box::new(HirExpression::Variable("other".to_string())),
+            right: Box::new(HirExpression::IntLiteral(1)),
+        };
+        assert_eq!(self_rmw_delta("counter", &value), None);
+    }
+
+    #[test]
+    fn test_candidate_accepts_self_rmw_and_whole_reads() {
+        let func = HirFunction::new_with_body(
+            "increment".to_string(),
+            HirType::Void,
+            vec![],
+            vec![
+                HirStatement::Assignment {
+                    target: "counter".to_string(),
+                    value: HirExpression::BinaryOp {
+                        op: BinaryOperator::Add,
+                        left: Box::new(HirExpression::Variable("counter".to_string())),
+                        right: Box::new(HirExpression::IntLiteral(1)),
+                    },
+                },
+                HirStatement::Return(Some(HirExpression::Variable("counter".to_string()))),
+            ],
+        );
+        assert!(is_atomic_candidate("counter", &[func]));
+    }
+
+    #[test]
+    fn test_candidate_rejects_address_taken() {
+        let func = HirFunction::new_with_body(
+            "snapshot".to_string(),
+            HirType::Void,
+            vec![],
+            vec![HirStatement::Assignment {
+                target: "ptr".to_string(),
+                value: HirExpression::AddressOf(Box::new(HirExpression::Variable(
+                    "counter".to_string(),
+                ))),
+            }],
+        );
+        assert!(!is_atomic_candidate("counter", &[func]));
+    }
+}
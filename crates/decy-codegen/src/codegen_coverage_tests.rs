@@ -871,6 +871,11 @@ fn expr_compound_literal_array_empty_unsized() {
 
 #[test]
 fn expr_compound_literal_array_single_init() {
+    // DECY-277: A single initializer zero-fills the remaining elements
+    // (`{0,0,0,...}`), it does not repeat across the whole array - this
+    // happens to look the same as `[0; 10]` when the initializer is 0, so
+    // this test alone can't catch a regression to repeat-syntax. See
+    // `expr_compound_literal_array_single_nonzero_init_zero_fills` below.
     let gen = CodeGenerator::new();
     let result = gen.generate_expression(&HirExpression::CompoundLiteral {
         literal_type: HirType::Array {
@@ -879,7 +884,24 @@ fn expr_compound_literal_array_single_init() {
         },
         initializers: vec![HirExpression::IntLiteral(0)],
     });
-    assert!(result.contains("[0; 10]"));
+    assert!(result.contains("[0, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32]"));
+}
+
+#[test]
+fn expr_compound_literal_array_single_nonzero_init_zero_fills() {
+    // DECY-277: `int arr[4] = {1};` is `{1, 0, 0, 0}` in C99, not `[1; 4]`
+    // (`{1, 1, 1, 1}`) - a non-zero initializer is the only way to catch a
+    // regression to Rust's `[x; n]` repeat syntax.
+    let gen = CodeGenerator::new();
+    let result = gen.generate_expression(&HirExpression::CompoundLiteral {
+        literal_type: HirType::Array {
+            element_type: Box::new(HirType::Int),
+            size: Some(4),
+        },
+        initializers: vec![HirExpression::IntLiteral(1)],
+    });
+    assert!(result.contains("[1, 0i32, 0i32, 0i32]"));
+    assert!(!result.contains("[1; 4]"));
 }
 
 #[test]
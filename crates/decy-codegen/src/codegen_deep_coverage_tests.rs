@@ -1103,7 +1103,10 @@ fn expr_compound_literal_array_single_init() {
         initializers: vec![HirExpression::IntLiteral(0)],
     };
     let code = cg.generate_expression(&expr);
-    assert_eq!(code, "[0; 10]");
+    // C99 zero-fills the remaining elements; it must not become Rust's
+    // `[0; 10]` *repeat* syntax, which only happens to look right here
+    // because the initializer and the padding default are both zero.
+    assert_eq!(code, "[0, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32]");
 }
 
 #[test]
@@ -17618,7 +17621,7 @@ fn expr_target_compound_literal_array() {
     assert!(result.contains("[1, 2, 3]"), "Got: {}", result);
 }
 
-// --- CompoundLiteral: array single init → repeat ---
+// --- CompoundLiteral: array single init → C99 zero-fill, not a Rust repeat ---
 #[test]
 fn expr_target_compound_literal_array_single_init() {
     let cg = CodeGenerator::new();
@@ -17631,7 +17634,27 @@ fn expr_target_compound_literal_array_single_init() {
         initializers: vec![HirExpression::IntLiteral(0)],
     };
     let result = cg.generate_expression_with_target_type(&expr, &ctx, None);
-    assert!(result.contains("[0; 10]"), "Got: {}", result);
+    assert!(
+        result.contains("[0, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32, 0i32]"),
+        "Got: {}",
+        result
+    );
+}
+
+#[test]
+fn expr_target_compound_literal_array_single_nonzero_init_zero_fills() {
+    let cg = CodeGenerator::new();
+    let ctx = TypeContext::new();
+    let expr = HirExpression::CompoundLiteral {
+        literal_type: HirType::Array {
+            element_type: Box::new(HirType::Int),
+            size: Some(4),
+        },
+        initializers: vec![HirExpression::IntLiteral(1)],
+    };
+    let result = cg.generate_expression_with_target_type(&expr, &ctx, None);
+    assert!(result.contains("[1, 0i32, 0i32, 0i32]"), "Got: {}", result);
+    assert!(!result.contains("[1; 4]"), "Got: {}", result);
 }
 
 // --- CompoundLiteral: empty array with size → default fill ---
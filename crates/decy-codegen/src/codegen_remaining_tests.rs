@@ -956,25 +956,26 @@ fn compound_literal_struct_empty() {
 }
 
 // ============================================================================
-// 38. CompoundLiteral array with size and single init → repeat
+// 38. CompoundLiteral array with size and single init → C99 zero-fill
 // ============================================================================
 
 #[test]
-fn compound_literal_array_single_init_repeats() {
+fn compound_literal_array_single_init_zero_fills() {
     let c = ctx();
     let expr = HirExpression::CompoundLiteral {
         literal_type: HirType::Array {
             element_type: Box::new(HirType::Int),
             size: Some(5),
         },
-        initializers: vec![ilit(0)],
+        initializers: vec![ilit(1)],
     };
     let result = expr_tt(&expr, &c, None);
     assert!(
-        result.contains("[0; 5]"),
-        "Single init array should repeat, got: {}",
+        result.contains("[1, 0i32, 0i32, 0i32, 0i32]"),
+        "Single init array should zero-fill, not repeat, got: {}",
         result
     );
+    assert!(!result.contains("[1; 5]"), "Got: {}", result);
 }
 
 // ============================================================================
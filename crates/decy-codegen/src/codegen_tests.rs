@@ -1431,3 +1431,51 @@ fn test_main_function_with_return_becomes_exit() {
     assert!(!code.contains("-> i32")); // Must NOT have -> i32
     assert!(code.contains("std::process::exit(0)")); // return 0 becomes exit
 }
+
+#[test]
+fn test_generate_function_asserts_constant_array_bound() {
+    // DECY-079: `fill(int* arr) { for (i = 0; i < 16; i++) arr[i] = 0; }` has
+    // no paired length parameter, so the slice's length must be asserted at
+    // runtime against the constant the loop bound resolved to.
+    let func = HirFunction::new_with_body(
+        "fill".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "arr".to_string(),
+            HirType::Pointer(Box::new(HirType::Int)),
+        )],
+        vec![HirStatement::For {
+            init: Some(Box::new(HirStatement::VariableDeclaration {
+                name: "i".to_string(),
+                var_type: HirType::Int,
+                initializer: Some(HirExpression::IntLiteral(0)),
+            })),
+            condition: HirExpression::BinaryOp {
+                op: BinaryOperator::LessThan,
+                left: Box::new(HirExpression::Variable("i".to_string())),
+                right: Box::new(HirExpression::IntLiteral(16)),
+            },
+            increment: Some(Box::new(HirStatement::Assignment {
+                target: "i".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: BinaryOperator::Add,
+                    left: Box::new(HirExpression::Variable("i".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            })),
+            body: vec![HirStatement::ArrayIndexAssignment {
+                array: Box::new(HirExpression::Variable("arr".to_string())),
+                index: Box::new(HirExpression::Variable("i".to_string())),
+                value: Box::new(HirExpression::IntLiteral(0)),
+            }],
+        }],
+    );
+
+    let codegen = CodeGenerator::new();
+    let code = codegen.generate_function(&func);
+
+    assert!(
+        code.contains("debug_assert_eq!(arr.len(), 16);"),
+        "Should assert the loop-derived constant bound on the slice: {code}"
+    );
+}
@@ -0,0 +1,425 @@
+//! Error-code / errno transformation for C's check-then-return idiom.
+//!
+//! Recognizes the common C pattern of checking a call's return value against
+//! an error sentinel and returning early - `if (fp == NULL) return -1;`,
+//! `if (n < 0) return n;` - and describes how to rewrite a function built
+//! entirely out of that pattern into the `Result<T, E>` + `?` idiom shown by
+//! `examples/cli/simple_grep.rs`. This is detection and a rewrite plan, not
+//! full codegen integration: emitting `Result<T, E>` signatures touches the
+//! lifetime annotator and void-pointer generics in [`crate::CodeGenerator`],
+//! which is cross-cutting enough to warrant its own follow-up (DECY-280).
+//!
+//! Part of DECY-279: recognize return-code/errno error handling and plan its
+//! lowering to Result/`?`.
+
+use decy_hir::{BinaryOperator, HirExpression, HirFunction, HirStatement, HirType};
+use std::collections::HashMap;
+
+/// The error condition a checked call's result is tested against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorSentinel {
+    /// The call's result is a pointer compared against `NULL`.
+    NullPointer,
+    /// The call's result is an integer compared as `< 0`.
+    NegativeInt,
+    /// A caller-supplied predicate for a callee whose error sentinel isn't
+    /// one of the built-in shapes above: the result is compared against
+    /// `threshold` with `op`.
+    Custom {
+        /// Comparison operator the guard condition uses.
+        op: BinaryOperator,
+        /// Literal the result is compared against.
+        threshold: i32,
+    },
+}
+
+/// Per-function overrides for callees whose error convention isn't "null
+/// pointer" or "negative int" - the "user-supplied table of fn ->
+/// error-predicate" from DECY-279.
+#[derive(Debug, Clone, Default)]
+pub struct SentinelTable(HashMap<String, ErrorSentinel>);
+
+impl SentinelTable {
+    /// Create an empty table; callees not registered here fall back to the
+    /// built-in null-pointer / negative-int recognizers.
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    /// Register the sentinel a specific callee uses to signal failure.
+    pub fn register(&mut self, function: impl Into<String>, sentinel: ErrorSentinel) {
+        self.0.insert(function.into(), sentinel);
+    }
+
+    fn get(&self, function: &str) -> Option<&ErrorSentinel> {
+        self.0.get(function)
+    }
+}
+
+/// A detected `let binding = function(arguments); if (<sentinel check>) {
+/// return error_return; }`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckedCall {
+    /// Name the call's result is bound to.
+    pub binding: String,
+    /// Declared type of `binding`.
+    pub binding_type: HirType,
+    /// Name of the checked callee.
+    pub function: String,
+    /// Arguments passed to the callee.
+    pub arguments: Vec<HirExpression>,
+    /// Sentinel the guard condition checks `binding` against.
+    pub sentinel: ErrorSentinel,
+    /// Expression returned when the guard fires.
+    pub error_return: HirExpression,
+}
+
+/// Whether `condition` is the guard shape `binding <op> threshold` for
+/// `sentinel`. Narrow and syntactic, like
+/// [`crate::guarded_cas_transform::detect_guarded_decrement`]: it matches
+/// the exact shapes DECY-279 calls out, not a general dataflow analysis.
+fn condition_matches(binding: &str, condition: &HirExpression, sentinel: &ErrorSentinel) -> bool {
+    let HirExpression::BinaryOp { op, left, right } = condition else {
+        return false;
+    };
+    let HirExpression::Variable(name) = left.as_ref() else {
+        return false;
+    };
+    if name != binding {
+        return false;
+    }
+    match sentinel {
+        ErrorSentinel::NullPointer => {
+            *op == BinaryOperator::Equal && matches!(right.as_ref(), HirExpression::NullLiteral)
+        }
+        ErrorSentinel::NegativeInt => {
+            *op == BinaryOperator::LessThan
+                && matches!(right.as_ref(), HirExpression::IntLiteral(0))
+        }
+        ErrorSentinel::Custom { op: want_op, threshold } => {
+            op == want_op
+                && matches!(right.as_ref(), HirExpression::IntLiteral(n) if n == threshold)
+        }
+    }
+}
+
+/// Detects the `binding = f(args); if (<sentinel check>) { return <error>;
+/// }` idiom at the start of `stmts`, consulting `table` for callees with a
+/// non-default sentinel and otherwise trying "null pointer" then "negative
+/// int".
+///
+/// Returns the detected call along with how many leading statements it
+/// consumed, so callers can slide a window over the rest of the body.
+pub fn detect_checked_call(
+    stmts: &[HirStatement],
+    table: &SentinelTable,
+) -> Option<(CheckedCall, usize)> {
+    let HirStatement::VariableDeclaration {
+        name,
+        var_type,
+        initializer: Some(HirExpression::FunctionCall { function, arguments }),
+    } = stmts.first()?
+    else {
+        return None;
+    };
+    let HirStatement::If {
+        condition,
+        then_block,
+        else_block: None,
+    } = stmts.get(1)?
+    else {
+        return None;
+    };
+    let [HirStatement::Return(Some(error_return))] = then_block.as_slice() else {
+        return None;
+    };
+
+    let sentinel = if let Some(custom) = table.get(function) {
+        if !condition_matches(name, condition, custom) {
+            return None;
+        }
+        custom.clone()
+    } else if condition_matches(name, condition, &ErrorSentinel::NullPointer) {
+        ErrorSentinel::NullPointer
+    } else if condition_matches(name, condition, &ErrorSentinel::NegativeInt) {
+        ErrorSentinel::NegativeInt
+    } else {
+        return None;
+    };
+
+    Some((
+        CheckedCall {
+            binding: name.clone(),
+            binding_type: var_type.clone(),
+            function: function.clone(),
+            arguments: arguments.clone(),
+            sentinel,
+            error_return: error_return.clone(),
+        },
+        2,
+    ))
+}
+
+/// A function body that is entirely a sequence of [`CheckedCall`]s followed
+/// by a single `return <success>;` - a full rewrite plan for lowering the
+/// function to `Result<T, E>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResultLowering {
+    /// Checked calls found in body order.
+    pub calls: Vec<CheckedCall>,
+    /// The expression in the function's trailing `return`, to be wrapped in
+    /// `Ok(..)`.
+    pub success: HirExpression,
+}
+
+/// Attempts to plan a lowering for `func`'s entire body: every statement
+/// must be consumed either by [`detect_checked_call`] or be the trailing
+/// `return <success>;`. Returns `None` - leaving the function unchanged, per
+/// DECY-279 - if any statement doesn't fit.
+pub fn lower_function_to_result(func: &HirFunction, table: &SentinelTable) -> Option<ResultLowering> {
+    let mut calls = Vec::new();
+    let mut rest = func.body();
+
+    loop {
+        if let [HirStatement::Return(Some(success))] = rest {
+            return Some(ResultLowering {
+                calls,
+                success: success.clone(),
+            });
+        }
+        let (call, consumed) = detect_checked_call(rest, table)?;
+        rest = &rest[consumed..];
+        calls.push(call);
+    }
+}
+
+/// The guard condition [`condition_matches`] recognizes for `sentinel`,
+/// rebuilt as a [`HirExpression`] so it can be rendered with the same
+/// [`crate::CodeGenerator::generate_expression`] call used for every other
+/// expression, instead of hand-formatting operator strings here.
+fn sentinel_condition(binding: &str, sentinel: &ErrorSentinel) -> HirExpression {
+    let (op, threshold) = match sentinel {
+        ErrorSentinel::NullPointer => {
+            return HirExpression::BinaryOp {
+                op: BinaryOperator::Equal,
+                left: Box::new(HirExpression::Variable(binding.to_string())),
+                right: Box::new(HirExpression::NullLiteral),
+            };
+        }
+        ErrorSentinel::NegativeInt => (BinaryOperator::LessThan, 0),
+        ErrorSentinel::Custom { op, threshold } => (*op, *threshold),
+    };
+    HirExpression::BinaryOp {
+        op,
+        left: Box::new(HirExpression::Variable(binding.to_string())),
+        right: Box::new(HirExpression::IntLiteral(threshold)),
+    }
+}
+
+/// Render `lowering` (a successful [`lower_function_to_result`] plan for
+/// `func`) as a complete Rust function returning `Result<T, i32>`: each
+/// checked call's guard becomes an early `return Err(..)`, and the trailing
+/// success value is wrapped in `Ok(..)`.
+///
+/// This is the detection/rewrite half of DECY-279's plan made concrete.
+/// Turning the early `return Err(..)`s into genuine `?` operators needs the
+/// checked callees themselves to return `Result`, which - per this module's
+/// own doc comment - touches the lifetime annotator and void-pointer
+/// generics and is its own follow-up (DECY-280).
+pub fn generate_result_lowered_function(func: &HirFunction, lowering: &ResultLowering) -> String {
+    let codegen = crate::CodeGenerator::new();
+    let params: Vec<String> = func
+        .parameters()
+        .iter()
+        .map(|p| format!("{}: {}", p.name(), crate::CodeGenerator::map_type(p.param_type())))
+        .collect();
+
+    let mut body = String::new();
+    for call in &lowering.calls {
+        let call_expr = HirExpression::FunctionCall {
+            function: call.function.clone(),
+            arguments: call.arguments.clone(),
+        };
+        let call_code = codegen.generate_expression(&call_expr);
+        let guard_code = codegen.generate_expression(&sentinel_condition(&call.binding, &call.sentinel));
+        let error_code = codegen.generate_expression(&call.error_return);
+        body.push_str(&format!(
+            "    let {}: {} = {};\n",
+            call.binding,
+            crate::CodeGenerator::map_type(&call.binding_type),
+            call_code
+        ));
+        body.push_str(&format!(
+            "    if {} {{\n        return Err({});\n    }}\n",
+            guard_code, error_code
+        ));
+    }
+    body.push_str(&format!("    Ok({})\n", codegen.generate_expression(&lowering.success)));
+
+    format!(
+        "fn {}({}) -> Result<{}, i32> {{\n{}}}\n",
+        func.name(),
+        params.join(", "),
+        crate::CodeGenerator::map_type(func.return_type()),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grep_style_body() -> Vec<HirStatement> {
+        vec![
+            HirStatement::VariableDeclaration {
+                name: "fp".to_string(),
+                var_type: HirType::Pointer(Box::new(HirType::Struct("FILE".to_string()))),
+                initializer: Some(HirExpression::FunctionCall {
+                    function: "fopen".to_string(),
+                    arguments: vec![
+                        HirExpression::Variable("filename".to_string()),
+                        HirExpression::StringLiteral("r".to_string()),
+                    ],
+                }),
+            },
+            HirStatement::If {
+                condition: HirExpression::BinaryOp {
+                    op: BinaryOperator::Equal,
+                    left: Box::new(HirExpression::Variable("fp".to_string())),
+                    right: Box::new(HirExpression::NullLiteral),
+                },
+                then_block: vec![HirStatement::Return(Some(HirExpression::IntLiteral(-1)))],
+                else_block: None,
+            },
+            HirStatement::Return(Some(HirExpression::IntLiteral(0))),
+        ]
+    }
+
+    #[test]
+    fn detects_null_pointer_checked_call() {
+        let (call, consumed) =
+            detect_checked_call(&grep_style_body(), &SentinelTable::new()).unwrap();
+        assert_eq!(consumed, 2);
+        assert_eq!(call.binding, "fp");
+        assert_eq!(call.function, "fopen");
+        assert_eq!(call.sentinel, ErrorSentinel::NullPointer);
+        assert_eq!(call.error_return, HirExpression::IntLiteral(-1));
+    }
+
+    #[test]
+    fn detects_negative_int_checked_call() {
+        let body = vec![
+            HirStatement::VariableDeclaration {
+                name: "n".to_string(),
+                var_type: HirType::Int,
+                initializer: Some(HirExpression::FunctionCall {
+                    function: "read_chunk".to_string(),
+                    arguments: vec![HirExpression::Variable("buf".to_string())],
+                }),
+            },
+            HirStatement::If {
+                condition: HirExpression::BinaryOp {
+                    op: BinaryOperator::LessThan,
+                    left: Box::new(HirExpression::Variable("n".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(0)),
+                },
+                then_block: vec![HirStatement::Return(Some(HirExpression::Variable(
+                    "n".to_string(),
+                )))],
+                else_block: None,
+            },
+        ];
+        let (call, _) = detect_checked_call(&body, &SentinelTable::new()).unwrap();
+        assert_eq!(call.sentinel, ErrorSentinel::NegativeInt);
+    }
+
+    #[test]
+    fn custom_sentinel_overrides_builtin_recognizers() {
+        let mut table = SentinelTable::new();
+        table.register(
+            "connect",
+            ErrorSentinel::Custom {
+                op: BinaryOperator::Equal,
+                threshold: -1,
+            },
+        );
+        let body = vec![
+            HirStatement::VariableDeclaration {
+                name: "rc".to_string(),
+                var_type: HirType::Int,
+                initializer: Some(HirExpression::FunctionCall {
+                    function: "connect".to_string(),
+                    arguments: vec![],
+                }),
+            },
+            HirStatement::If {
+                condition: HirExpression::BinaryOp {
+                    op: BinaryOperator::Equal,
+                    left: Box::new(HirExpression::Variable("rc".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(-1)),
+                },
+                then_block: vec![HirStatement::Return(Some(HirExpression::IntLiteral(-1)))],
+                else_block: None,
+            },
+        ];
+        let (call, _) = detect_checked_call(&body, &table).unwrap();
+        assert_eq!(
+            call.sentinel,
+            ErrorSentinel::Custom {
+                op: BinaryOperator::Equal,
+                threshold: -1,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_body_not_matching_the_pattern() {
+        let body = vec![HirStatement::Return(Some(HirExpression::IntLiteral(0)))];
+        assert!(detect_checked_call(&body, &SentinelTable::new()).is_none());
+    }
+
+    #[test]
+    fn plans_full_function_lowering() {
+        let func = HirFunction::new_with_body(
+            "open_and_ready".to_string(),
+            HirType::Int,
+            vec![],
+            grep_style_body(),
+        );
+        let lowering = lower_function_to_result(&func, &SentinelTable::new()).unwrap();
+        assert_eq!(lowering.calls.len(), 1);
+        assert_eq!(lowering.calls[0].function, "fopen");
+        assert_eq!(lowering.success, HirExpression::IntLiteral(0));
+    }
+
+    #[test]
+    fn leaves_function_unchanged_when_body_does_not_fit() {
+        let func = HirFunction::new_with_body(
+            "not_this_shape".to_string(),
+            HirType::Int,
+            vec![],
+            vec![HirStatement::Return(None)],
+        );
+        assert!(lower_function_to_result(&func, &SentinelTable::new()).is_none());
+    }
+
+    #[test]
+    fn renders_result_signature_and_early_err_return() {
+        let func = HirFunction::new_with_body(
+            "open_and_ready".to_string(),
+            HirType::Int,
+            vec![],
+            grep_style_body(),
+        );
+        let lowering = lower_function_to_result(&func, &SentinelTable::new()).unwrap();
+        let code = generate_result_lowered_function(&func, &lowering);
+
+        assert!(
+            code.contains("fn open_and_ready() -> Result<i32, i32>"),
+            "Got: {}",
+            code
+        );
+        assert!(code.contains("return Err(-1)"), "Got: {}", code);
+        assert!(code.contains("Ok(0)"), "Got: {}", code);
+    }
+}
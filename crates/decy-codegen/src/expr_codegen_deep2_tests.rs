@@ -1948,21 +1948,22 @@ fn compound_literal_struct_empty_init() {
 // ============================================================================
 
 #[test]
-fn compound_literal_array_single_init_repeats() {
+fn compound_literal_array_single_init_zero_fills() {
     let c = ctx();
     let expr = HirExpression::CompoundLiteral {
         literal_type: HirType::Array {
             element_type: Box::new(HirType::Int),
             size: Some(5),
         },
-        initializers: vec![HirExpression::IntLiteral(0)],
+        initializers: vec![HirExpression::IntLiteral(1)],
     };
     let result = expr_no_tt(&expr, &c);
     assert!(
-        result.contains("[0; 5]"),
-        "Single init with size should repeat, got: {}",
+        result.contains("[1, 0i32, 0i32, 0i32, 0i32]"),
+        "Single init with size should zero-fill, not repeat, got: {}",
         result
     );
+    assert!(!result.contains("[1; 5]"), "Got: {}", result);
 }
 
 #[test]
@@ -2030,6 +2031,68 @@ fn compound_literal_other_type_generates_comment() {
     );
 }
 
+// ============================================================================
+// CompoundLiteral: Lifetime-bound temporaries via hoisting (DECY-276)
+// ============================================================================
+
+#[test]
+fn compound_literal_address_of_struct_hoists_into_block() {
+    let mut c = ctx();
+    c.structs.insert(
+        "Point".to_string(),
+        vec![
+            ("x".to_string(), HirType::Int),
+            ("y".to_string(), HirType::Int),
+        ],
+    );
+    let expr = HirExpression::AddressOf(Box::new(HirExpression::CompoundLiteral {
+        literal_type: HirType::Struct("Point".to_string()),
+        initializers: vec![HirExpression::IntLiteral(10), HirExpression::IntLiteral(20)],
+    }));
+    let result = expr_no_tt(&expr, &c);
+    assert!(
+        result.contains("let __cl = Point { x: 10, y: 20 };") && result.contains("&__cl"),
+        "Address-of a struct compound literal should hoist into a block, got: {}",
+        result
+    );
+}
+
+#[test]
+fn compound_literal_address_of_array_hoists_into_slice_ref() {
+    let c = ctx();
+    let expr = HirExpression::AddressOf(Box::new(HirExpression::CompoundLiteral {
+        literal_type: HirType::Array {
+            element_type: Box::new(HirType::Int),
+            size: None,
+        },
+        initializers: vec![HirExpression::IntLiteral(1), HirExpression::IntLiteral(2)],
+    }));
+    let result = expr_no_tt(&expr, &c);
+    assert!(
+        result.contains("let __cl =") && result.contains("&__cl[..]"),
+        "Address-of an array compound literal should hoist into a slice ref, got: {}",
+        result
+    );
+}
+
+#[test]
+fn compound_literal_array_as_pointer_target_hoists_into_slice_ref() {
+    let c = ctx();
+    let expr = HirExpression::CompoundLiteral {
+        literal_type: HirType::Array {
+            element_type: Box::new(HirType::Int),
+            size: None,
+        },
+        initializers: vec![HirExpression::IntLiteral(1), HirExpression::IntLiteral(2)],
+    };
+    let result = expr_tt(&expr, &c, Some(&HirType::Pointer(Box::new(HirType::Int))));
+    assert!(
+        result.contains("let __cl =") && result.contains("&__cl[..]"),
+        "Array literal decaying to a pointer should hoist into a slice ref, got: {}",
+        result
+    );
+}
+
 // ============================================================================
 // Ternary with target type propagation (DECY-213)
 // ============================================================================
@@ -1553,24 +1553,29 @@ fn test_compound_literal_array_with_size() {
 }
 
 #[test]
-fn test_compound_literal_array_single_initializer_repeats() {
+fn test_compound_literal_array_single_initializer_zero_fills() {
     let codegen = CodeGenerator::new();
     let func = make_func_with_body(vec![HirStatement::VariableDeclaration {
         name: "arr".to_string(),
         var_type: HirType::Array {
             element_type: Box::new(HirType::Int),
-            size: Some(10),
+            size: Some(4),
         },
         initializer: Some(HirExpression::CompoundLiteral {
             literal_type: HirType::Array {
                 element_type: Box::new(HirType::Int),
-                size: Some(10),
+                size: Some(4),
             },
-            initializers: vec![HirExpression::IntLiteral(0)],
+            initializers: vec![HirExpression::IntLiteral(1)],
         }),
     }]);
     let code = codegen.generate_function(&func);
-    assert!(code.contains("[0; 10]"), "Expected repeated array init, got: {}", code);
+    assert!(
+        code.contains("[1, 0i32, 0i32, 0i32]"),
+        "Expected C99 zero-fill, got: {}",
+        code
+    );
+    assert!(!code.contains("[1; 4]"), "Got: {}", code);
 }
 
 #[test]
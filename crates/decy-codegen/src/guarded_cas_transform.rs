@@ -0,0 +1,140 @@
+//! Guarded compare-and-swap transformation module for check-then-act globals.
+//!
+//! Transforms the classic `if (g > 0) { g = g - 1; return 1; } return 0;`
+//! idiom - a data race when `g` is shared - into a lock-free
+//! `compare_exchange_weak` retry loop that recomputes the guard predicate and
+//! the update from a freshly-loaded value on every attempt.
+//!
+//! Part of DECY-265: Lower guarded read-modify-write globals to CAS loops.
+
+use crate::atomic_global_transform::self_rmw_delta;
+use decy_hir::{BinaryOperator, HirExpression, HirStatement};
+
+/// A detected `if (g <op> threshold) { g = g <+/-> delta; return success; }
+/// return failure;` guarded read-modify-write on global `global`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardedDecrement {
+    /// Name of the guarded global.
+    pub global: String,
+    /// Comparison operator applied to the freshly-loaded value and `threshold`.
+    pub op: BinaryOperator,
+    /// Right-hand side of the guard comparison.
+    pub threshold: i32,
+    /// Signed delta applied to the global when the guard passes.
+    pub delta: i32,
+    /// Value returned when the guard passes and the CAS succeeds.
+    pub success_value: i32,
+    /// Value returned when the guard fails.
+    pub failure_value: i32,
+}
+
+/// Detects the guarded read-modify-write idiom as the whole of a function
+/// body: a single `if` with no `else` whose condition compares `global`
+/// against an integer literal, whose then-block performs a self-RMW
+/// assignment to `global` followed by `return <literal>;`, immediately
+/// followed by a trailing `return <literal>;` for the guard-failed case.
+///
+/// Returns `None` if the body doesn't match this exact shape - this is a
+/// narrow, syntactic pattern match, not a general dataflow analysis.
+pub fn detect_guarded_decrement(global: &str, body: &[HirStatement]) -> Option<GuardedDecrement> {
+    let [HirStatement::If {
+        condition,
+        then_block,
+        else_block: None,
+    }, HirStatement::Return(Some(HirExpression::IntLiteral(failure_value)))] = body
+    else {
+        return None;
+    };
+
+    let HirExpression::BinaryOp { op, left, right } = condition else {
+        return None;
+    };
+    let HirExpression::Variable(cond_name) = left.as_ref() else {
+        return None;
+    };
+    if cond_name != global {
+        return None;
+    }
+    let HirExpression::IntLiteral(threshold) = right.as_ref() else {
+        return None;
+    };
+
+    let [HirStatement::Assignment { target, value }, HirStatement::Return(Some(HirExpression::IntLiteral(success_value)))] =
+        then_block.as_slice()
+    else {
+        return None;
+    };
+    if target != global {
+        return None;
+    }
+    let delta = self_rmw_delta(global, value)?;
+
+    Some(GuardedDecrement {
+        global: global.to_string(),
+        op: *op,
+        threshold: *threshold,
+        delta,
+        success_value: *success_value,
+        failure_value: *failure_value,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guarded_decrement_body() -> Vec<HirStatement> {
+        vec![
+            HirStatement::If {
+                condition: HirExpression::BinaryOp {
+                    op: BinaryOperator::GreaterThan,
+                    left: Box::new(HirExpression::Variable("resource_count".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(0)),
+                },
+                then_block: vec![
+                    HirStatement::Assignment {
+                        target: "resource_count".to_string(),
+                        value: HirExpression::BinaryOp {
+                            op: BinaryOperator::Subtract,
+                            left: Box::new(HirExpression::Variable("resource_count".to_string())),
+                            right: Box::new(HirExpression::IntLiteral(1)),
+                        },
+                    },
+                    HirStatement::Return(Some(HirExpression::IntLiteral(1))),
+                ],
+                else_block: None,
+            },
+            HirStatement::Return(Some(HirExpression::IntLiteral(0))),
+        ]
+    }
+
+    #[test]
+    fn test_detects_guarded_decrement() {
+        let detected = detect_guarded_decrement("resource_count", &guarded_decrement_body());
+        assert_eq!(
+            detected,
+            Some(GuardedDecrement {
+                global: "resource_count".to_string(),
+                op: BinaryOperator::GreaterThan,
+                threshold: 0,
+                delta: -1,
+                success_value: 1,
+                failure_value: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_rejects_mismatched_global_name() {
+        assert_eq!(
+            detect_guarded_decrement("other_global", &guarded_decrement_body()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_guarded_body() {
+        let body = vec![HirStatement::Return(Some(HirExpression::IntLiteral(0)))];
+        assert_eq!(detect_guarded_decrement("resource_count", &body), None);
+    }
+}
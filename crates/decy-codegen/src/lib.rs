@@ -29,14 +29,24 @@
 #![warn(clippy::all)]
 #![deny(unsafe_code)]
 
+pub mod atomic_global_transform;
 pub mod box_transform;
 pub mod concurrency_transform;
 pub mod enum_gen;
+pub mod error_result_transform;
+pub mod guarded_cas_transform;
+pub mod mutex_global_transform;
+pub mod packed_layout;
 pub mod pattern_gen;
+pub mod size_expr;
 pub mod test_generator;
 
-use decy_hir::{BinaryOperator, HirExpression, HirFunction, HirStatement, HirType};
+use decy_hir::{
+    BinaryOperator, HirEnum, HirEnumVariant, HirExpression, HirFunction, HirStatement, HirType,
+    SwitchCase,
+};
 use decy_ownership::lifetime_gen::{AnnotatedSignature, AnnotatedType};
+use decy_parser::diagnostic::{Diagnostic, Severity};
 use std::collections::HashMap;
 
 /// Type context for tracking variable types and struct definitions during code generation.
@@ -56,6 +66,16 @@ struct TypeContext {
     // DECY-134b: Track which functions have string iteration params (for call site transformation)
     // Maps func_name -> list of (param_index, is_mutable) for string iter params
     string_iter_funcs: HashMap<String, Vec<(usize, bool)>>,
+    // DECY-262: Track enum definitions so switch lowering can resolve the full
+    // variant set for a discriminant and generate exhaustive matches without `_`.
+    enums: HashMap<String, Vec<HirEnumVariant>>,
+    // DECY-264: Globals lowered to `AtomicI32` instead of `static mut`, so reads
+    // and writes route through atomic ops rather than raw unsafe access.
+    atomic_globals: std::collections::HashSet<String>,
+    // DECY-266: Struct globals lowered to `Mutex<T>`, so every reference -
+    // field read, field write, or the bare struct itself - routes through
+    // `.lock().unwrap()` rather than raw `static mut` access.
+    mutex_globals: std::collections::HashSet<String>,
 }
 
 impl TypeContext {
@@ -67,9 +87,45 @@ impl TypeContext {
             slice_func_args: HashMap::new(),
             string_iter_params: HashMap::new(),
             string_iter_funcs: HashMap::new(),
+            enums: HashMap::new(),
+            atomic_globals: std::collections::HashSet::new(),
+            mutex_globals: std::collections::HashSet::new(),
         }
     }
 
+    /// DECY-264: Register a global as atomic-lowered so reads/writes route
+    /// through `AtomicI32` operations instead of raw `static mut` access.
+    fn add_atomic_global(&mut self, name: String) {
+        self.atomic_globals.insert(name);
+    }
+
+    /// DECY-264: Check whether a name refers to an atomic-lowered global.
+    fn is_atomic_global(&self, name: &str) -> bool {
+        self.atomic_globals.contains(name)
+    }
+
+    /// DECY-266: Register a global as mutex-lowered so every reference to it
+    /// routes through `.lock().unwrap()` instead of raw `static mut` access.
+    fn add_mutex_global(&mut self, name: String) {
+        self.mutex_globals.insert(name);
+    }
+
+    /// DECY-266: Check whether a name refers to a mutex-lowered struct global.
+    fn is_mutex_global(&self, name: &str) -> bool {
+        self.mutex_globals.contains(name)
+    }
+
+    /// DECY-262: Register an enum definition for enum-aware switch lowering.
+    fn add_enum(&mut self, hir_enum: &HirEnum) {
+        self.enums
+            .insert(hir_enum.name().to_string(), hir_enum.variants().to_vec());
+    }
+
+    /// DECY-262: Look up the variant set for a registered enum by name.
+    fn get_enum_variants(&self, enum_name: &str) -> Option<&Vec<HirEnumVariant>> {
+        self.enums.get(enum_name)
+    }
+
     /// DECY-134b: Register a function's string iteration params for call site transformation
     fn add_string_iter_func(&mut self, func_name: String, params: Vec<(usize, bool)>) {
         self.string_iter_funcs.insert(func_name, params);
@@ -286,10 +342,54 @@ impl TypeContext {
                     None
                 }
             }
+            // DECY-272: An int literal's C type is `int`, needed to apply the
+            // usual arithmetic conversions to ternary arms like `cond ? 1 : d`.
+            HirExpression::IntLiteral(_) => Some(HirType::Int),
             _ => None,
         }
     }
 
+    /// DECY-272: C's integer conversion rank for the numeric `HirType`s this
+    /// HIR can represent, after integer promotion (`char` promotes to `int`).
+    /// `None` for non-arithmetic types (pointers, structs, ...), which the
+    /// usual arithmetic conversions don't apply to.
+    fn numeric_conversion_rank(ty: &HirType) -> Option<u8> {
+        match ty {
+            HirType::Char | HirType::Int => Some(2), // char promotes to int
+            HirType::UnsignedInt => Some(3),
+            HirType::Float => Some(4),
+            HirType::Double => Some(5),
+            _ => None,
+        }
+    }
+
+    /// DECY-272: The "usual arithmetic conversions" result type for a pair of
+    /// ternary arm types: floating beats integer, wider beats narrower,
+    /// unsigned beats signed of equal rank. Returns `None` when the arms are
+    /// already the same type, or aren't both arithmetic types - in either
+    /// case no cast is needed.
+    fn usual_arithmetic_conversion_type(then_ty: &HirType, else_ty: &HirType) -> Option<HirType> {
+        if then_ty == else_ty {
+            return None;
+        }
+        let then_rank = Self::numeric_conversion_rank(then_ty)?;
+        let else_rank = Self::numeric_conversion_rank(else_ty)?;
+        Some(if then_rank >= else_rank {
+            Self::promote_char(then_ty)
+        } else {
+            Self::promote_char(else_ty)
+        })
+    }
+
+    /// DECY-272: Integer promotion - a bare `char` arm never survives as the
+    /// common type, it promotes to `int` like every other narrow integer use.
+    fn promote_char(ty: &HirType) -> HirType {
+        match ty {
+            HirType::Char => HirType::Int,
+            other => other.clone(),
+        }
+    }
+
     /// DECY-123: Helper to get field type from a struct type
     fn get_field_type_from_type(&self, obj_type: &HirType, field_name: &str) -> Option<HirType> {
         let struct_name = match obj_type {
@@ -304,10 +404,99 @@ impl TypeContext {
     }
 }
 
+/// DECY-263: Maximum width of a contiguous integer case span that may be
+/// "unrolled" into an OR-pattern (`1 | 2 | 3 | 4 => ..`) instead of a Rust
+/// range pattern (`1..=4 => ..`).
+///
+/// Modeled on the Rhai interpreter's switch-range unrolling, which caps
+/// unrolling at 16 values; spans wider than this always stay as a range
+/// pattern regardless of [`SwitchLoweringMode`], since unrolling, say, a
+/// 1000-value span would bloat the generated match arm for no benefit.
+pub const MAX_SWITCH_UNROLL_WIDTH: usize = 16;
+
+/// DECY-263: Controls how a contiguous span of C `case` labels with an
+/// identical body (a fallthrough group, e.g. `case 10: case 11: ... case 16:
+/// body;`) is rendered once collapsed into a single Rust match arm.
+///
+/// Collapsing itself always happens - it's the only way to avoid an
+/// `unreachable pattern` error from translating each fallthrough label into
+/// its own dead arm - but the pattern syntax used for the collapsed arm is
+/// configurable. Spans wider than [`MAX_SWITCH_UNROLL_WIDTH`] always render
+/// as a range pattern, irrespective of the selected mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SwitchLoweringMode {
+    /// Render a collapsed contiguous span as a Rust range pattern, e.g.
+    /// `10..=16 => body`. This is the default: it keeps the generated code
+    /// compact regardless of span width.
+    #[default]
+    RangePattern,
+    /// Render a collapsed contiguous span no wider than
+    /// [`MAX_SWITCH_UNROLL_WIDTH`] as an explicit OR-pattern, e.g.
+    /// `10 | 11 | 12 | ... | 16 => body`. Spans wider than the threshold
+    /// fall back to [`SwitchLoweringMode::RangePattern`].
+    Unrolled,
+}
+
+/// DECY-275: The `tern!` helper macro's definition, emitted once into a
+/// generated module's prelude when [`TernaryLoweringMode::Macro`] is
+/// selected. `$then`/`$else` are each referenced exactly once, so the macro
+/// form evaluates its arms no more often than the equivalent inline
+/// `if`/`else`.
+pub const TERN_MACRO_SOURCE: &str = concat!(
+    "macro_rules! tern {\n",
+    "    ($cond:expr, $then:expr, $else:expr) => {\n",
+    "        if $cond { $then } else { $else }\n",
+    "    };\n",
+    "}\n"
+);
+
+/// DECY-275: Controls how a C ternary/conditional expression (`cond ? a : b`)
+/// is rendered.
+///
+/// Collapsing to an `if`/`else` expression always preserves semantics - it's
+/// the only thing that changes is *how* that expression is spelled in the
+/// generated source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TernaryLoweringMode {
+    /// Render as an inlined `if cond { a } else { b }` expression. This is
+    /// the default.
+    #[default]
+    Inline,
+    /// Render as a call to the generated `tern!(cond, a, b)` helper macro
+    /// (see [`TERN_MACRO_SOURCE`]), giving a one-to-one line correspondence
+    /// with the original C `?:` call site. The caller is responsible for
+    /// emitting [`TERN_MACRO_SOURCE`] once into the generated module.
+    Macro,
+}
+
+/// DECY-263: A run of C `case` labels collapsed into a single Rust match arm.
+///
+/// Holds every label value in the fallthrough group (in source order) and
+/// the body that terminates it - the body of whichever label in the group
+/// was the only one with statements attached.
+struct CaseGroup<'a> {
+    values: Vec<&'a HirExpression>,
+    body: &'a [HirStatement],
+}
+
 /// Code generator for converting HIR to Rust source code.
 #[derive(Debug, Clone)]
 pub struct CodeGenerator {
     box_transformer: box_transform::BoxTransformer,
+    switch_lowering_mode: SwitchLoweringMode,
+    // DECY-264: Globals the caller has already determined are safe to lower
+    // to `AtomicI32`, seeded into each function's `TypeContext` so reads and
+    // writes of these names route through atomic ops instead of `static mut`.
+    atomic_globals: std::collections::HashSet<String>,
+    // DECY-265: Atomic globals additionally opted into guarded compare-exchange
+    // retry-loop lowering for their check-then-act accessor functions.
+    guarded_cas_globals: std::collections::HashSet<String>,
+    // DECY-266: Struct-typed globals whose multi-field critical sections are
+    // lowered to a single `Mutex<T>`-guarded scope instead of a `static mut`
+    // touched per field.
+    mutex_globals: std::collections::HashSet<String>,
+    // DECY-275: How a ternary/conditional expression is rendered.
+    ternary_lowering_mode: TernaryLoweringMode,
 }
 
 impl CodeGenerator {
@@ -323,6 +512,141 @@ impl CodeGenerator {
     pub fn new() -> Self {
         Self {
             box_transformer: box_transform::BoxTransformer::new(),
+            switch_lowering_mode: SwitchLoweringMode::default(),
+            atomic_globals: std::collections::HashSet::new(),
+            guarded_cas_globals: std::collections::HashSet::new(),
+            mutex_globals: std::collections::HashSet::new(),
+            ternary_lowering_mode: TernaryLoweringMode::default(),
+        }
+    }
+
+    /// DECY-263: Create a code generator with a specific [`SwitchLoweringMode`]
+    /// for collapsed `switch` fallthrough groups.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use decy_codegen::{CodeGenerator, SwitchLoweringMode};
+    ///
+    /// let codegen = CodeGenerator::with_switch_lowering_mode(SwitchLoweringMode::Unrolled);
+    /// ```
+    pub fn with_switch_lowering_mode(mode: SwitchLoweringMode) -> Self {
+        Self {
+            box_transformer: box_transform::BoxTransformer::new(),
+            switch_lowering_mode: mode,
+            atomic_globals: std::collections::HashSet::new(),
+            guarded_cas_globals: std::collections::HashSet::new(),
+            mutex_globals: std::collections::HashSet::new(),
+            ternary_lowering_mode: TernaryLoweringMode::default(),
+        }
+    }
+
+    /// DECY-264: Create a code generator that lowers the given global names
+    /// to `AtomicI32` reads/writes instead of raw `static mut` access.
+    ///
+    /// The caller is responsible for having already verified, via
+    /// [`atomic_global_transform::is_atomic_candidate`], that each name is
+    /// only ever accessed through a whole-value read or a plain-store /
+    /// self-referencing add-or-subtract-by-constant assignment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use decy_codegen::CodeGenerator;
+    /// use std::collections::HashSet;
+    ///
+    /// let globals: HashSet<String> = ["counter".to_string()].into_iter().collect();
+    /// let codegen = CodeGenerator::with_atomic_globals(globals);
+    /// ```
+    pub fn with_atomic_globals(atomic_globals: std::collections::HashSet<String>) -> Self {
+        Self {
+            box_transformer: box_transform::BoxTransformer::new(),
+            switch_lowering_mode: SwitchLoweringMode::default(),
+            atomic_globals,
+            guarded_cas_globals: std::collections::HashSet::new(),
+            mutex_globals: std::collections::HashSet::new(),
+            ternary_lowering_mode: TernaryLoweringMode::default(),
+        }
+    }
+
+    /// DECY-275: Create a code generator with a specific [`TernaryLoweringMode`]
+    /// for `cond ? a : b` expressions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use decy_codegen::{CodeGenerator, TernaryLoweringMode};
+    ///
+    /// let codegen = CodeGenerator::with_ternary_lowering_mode(TernaryLoweringMode::Macro);
+    /// ```
+    pub fn with_ternary_lowering_mode(mode: TernaryLoweringMode) -> Self {
+        Self {
+            box_transformer: box_transform::BoxTransformer::new(),
+            switch_lowering_mode: SwitchLoweringMode::default(),
+            atomic_globals: std::collections::HashSet::new(),
+            guarded_cas_globals: std::collections::HashSet::new(),
+            mutex_globals: std::collections::HashSet::new(),
+            ternary_lowering_mode: mode,
+        }
+    }
+
+    /// DECY-265: Create a code generator that additionally lowers check-then-act
+    /// guarded read-modify-write accessors for the given atomic globals into a
+    /// `compare_exchange_weak` retry loop instead of a plain load/branch/store.
+    ///
+    /// `atomic_globals` seeds the same `AtomicI32` lowering as
+    /// [`CodeGenerator::with_atomic_globals`]; `guarded_cas_globals` is the
+    /// subset of those names whose guarded accessor functions (see
+    /// [`guarded_cas_transform::detect_guarded_decrement`]) should use the CAS
+    /// loop. Names in `guarded_cas_globals` must also appear in
+    /// `atomic_globals`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use decy_codegen::CodeGenerator;
+    /// use std::collections::HashSet;
+    ///
+    /// let globals: HashSet<String> = ["resource_count".to_string()].into_iter().collect();
+    /// let codegen = CodeGenerator::with_guarded_cas_globals(globals.clone(), globals);
+    /// ```
+    pub fn with_guarded_cas_globals(
+        atomic_globals: std::collections::HashSet<String>,
+        guarded_cas_globals: std::collections::HashSet<String>,
+    ) -> Self {
+        Self {
+            box_transformer: box_transform::BoxTransformer::new(),
+            switch_lowering_mode: SwitchLoweringMode::default(),
+            atomic_globals,
+            guarded_cas_globals,
+            mutex_globals: std::collections::HashSet::new(),
+            ternary_lowering_mode: TernaryLoweringMode::default(),
+        }
+    }
+
+    /// DECY-266: Create a code generator that lowers the given struct-typed
+    /// globals to `Mutex<T>`, rewriting each multi-field critical section
+    /// (see [`mutex_global_transform::find_critical_sections`]) into a
+    /// single `{ let mut g = NAME.lock().unwrap(); ... }` scope instead of a
+    /// `static mut` touched per field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use decy_codegen::CodeGenerator;
+    /// use std::collections::HashSet;
+    ///
+    /// let globals: HashSet<String> = ["shared".to_string()].into_iter().collect();
+    /// let codegen = CodeGenerator::with_mutex_globals(globals);
+    /// ```
+    pub fn with_mutex_globals(mutex_globals: std::collections::HashSet<String>) -> Self {
+        Self {
+            box_transformer: box_transform::BoxTransformer::new(),
+            switch_lowering_mode: SwitchLoweringMode::default(),
+            atomic_globals: std::collections::HashSet::new(),
+            guarded_cas_globals: std::collections::HashSet::new(),
+            mutex_globals,
+            ternary_lowering_mode: TernaryLoweringMode::default(),
         }
     }
 
@@ -852,6 +1176,27 @@ impl CodeGenerator {
         self.generate_expression_with_context(expr, &TypeContext::new())
     }
 
+    /// DECY-276: Lower `&(compound literal)` by hoisting the literal into a
+    /// block-scoped `let __cl = ...;` so the borrow outlives the expression
+    /// it was created in, instead of dangling once Rust drops the
+    /// temporary. Returns `None` when `operand` isn't a compound literal, so
+    /// the caller falls back to its normal `&`/pointer-cast codegen.
+    fn hoist_compound_literal_ref(
+        &self,
+        operand: &HirExpression,
+        ctx: &TypeContext,
+    ) -> Option<String> {
+        let HirExpression::CompoundLiteral { literal_type, .. } = operand else {
+            return None;
+        };
+        let literal_code = self.generate_expression_with_context(operand, ctx);
+        let reference = match literal_type {
+            HirType::Array { .. } => "&__cl[..]",
+            _ => "&__cl",
+        };
+        Some(format!("{{ let __cl = {}; {} }}", literal_code, reference))
+    }
+
     /// Generate code for an expression with type context for pointer arithmetic.
     #[allow(clippy::only_used_in_recursion)]
     fn generate_expression_with_context(&self, expr: &HirExpression, ctx: &TypeContext) -> String {
@@ -884,6 +1229,13 @@ impl CodeGenerator {
             // DECY-119: Handle AddressOf when target is raw pointer (struct field assignment)
             // C: node.next = &x;  →  Rust: node.next = &mut x as *mut T;
             HirExpression::AddressOf(inner) => {
+                // DECY-276: `&(struct Point){10, 20}` would borrow a Rust
+                // temporary that drops at the end of the expression. Hoist
+                // the literal into a block-scoped `let` first so the
+                // reference stays valid.
+                if let Some(hoisted) = self.hoist_compound_literal_ref(inner, ctx) {
+                    return hoisted;
+                }
                 if let Some(HirType::Pointer(ptr_inner)) = target_type {
                     let inner_code = self.generate_expression_with_context(inner, ctx);
                     let ptr_type = Self::map_type(&HirType::Pointer(ptr_inner.clone()));
@@ -902,6 +1254,11 @@ impl CodeGenerator {
                 op: decy_hir::UnaryOperator::AddressOf,
                 operand,
             } => {
+                // DECY-276: same compound-literal hoist as the `AddressOf`
+                // variant above.
+                if let Some(hoisted) = self.hoist_compound_literal_ref(operand, ctx) {
+                    return hoisted;
+                }
                 if let Some(HirType::Pointer(ptr_inner)) = target_type {
                     let inner_code = self.generate_expression_with_context(operand, ctx);
                     let ptr_type = Self::map_type(&HirType::Pointer(ptr_inner.clone()));
@@ -958,6 +1315,16 @@ impl CodeGenerator {
                 }
             }
             HirExpression::Variable(name) => {
+                // DECY-264: Reading an atomic-lowered global is a `.load()`, not a bare name.
+                if ctx.is_atomic_global(name) {
+                    return format!("{}.load(std::sync::atomic::Ordering::SeqCst)", name);
+                }
+                // DECY-266: Reading a mutex-lowered struct global whole (not
+                // through a field) needs a locked clone of its contents.
+                if ctx.is_mutex_global(name) {
+                    return format!("{}.lock().unwrap().clone()", name);
+                }
+
                 // DECY-142: Vec to Vec - return directly (no conversion needed)
                 // When target type is Vec<T> and variable is Vec<T>, return as-is
                 if let Some(HirType::Vec(_)) = target_type {
@@ -1081,6 +1448,16 @@ impl CodeGenerator {
                     );
                 }
 
+                // DECY-269: `n * sizeof(long)` (and friends) is platform-width
+                // dependent; emit the symbolic size_of::<usize>()-based form
+                // instead of falling through to map_sizeof_type's literal
+                // (and, for "long"/"void *"/etc., invalid-Rust-type) codegen.
+                if let Some(size_expr) = size_expr::detect_pointer_width_size_expr(expr) {
+                    if let Some(code) = size_expr::SizeExprGenerator::new().emit_expr(&size_expr) {
+                        return format!("({}) as i32", code);
+                    }
+                }
+
                 // Check for Option comparison with NULL → is_none() / is_some()
                 // p == NULL → p.is_none(), p != NULL → p.is_some()
                 if matches!(op, BinaryOperator::Equal | BinaryOperator::NotEqual) {
@@ -2112,6 +2489,14 @@ impl CodeGenerator {
                 }
             }
             HirExpression::FieldAccess { object, field } => {
+                // DECY-266: Reading a field of a mutex-lowered struct global
+                // needs a `.lock().unwrap()` first - it's no longer the bare
+                // struct.
+                if let HirExpression::Variable(name) = object.as_ref() {
+                    if ctx.is_mutex_global(name) {
+                        return format!("{}.lock().unwrap().{}", name, field);
+                    }
+                }
                 format!(
                     "{}.{}",
                     self.generate_expression_with_context(object, ctx),
@@ -2193,6 +2578,16 @@ impl CodeGenerator {
                 // sizeof(struct Data) → std::mem::size_of::<Data>() as i32
                 // Note: size_of returns usize, but C's sizeof returns int (typically i32)
 
+                // DECY-269: sizeof(void*)/sizeof(long)/sizeof(size_t) etc. are
+                // the target's pointer width, not a type map_sizeof_type can
+                // translate to a concrete Rust type name - emit the symbolic
+                // core::mem::size_of::<usize>() form instead.
+                if let Some(size_expr) = size_expr::detect_pointer_width_size_expr(expr) {
+                    if let Some(code) = size_expr::SizeExprGenerator::new().emit_expr(&size_expr) {
+                        return format!("({}) as i32", code);
+                    }
+                }
+
                 // DECY-189: Detect sizeof(expr) that was mis-parsed as sizeof(type)
                 // Pattern: "record name" came from sizeof(record->name) where
                 // the parser tokenized record and name as separate identifiers
@@ -2354,6 +2749,25 @@ impl CodeGenerator {
                 literal_type,
                 initializers,
             } => {
+                // DECY-276: `int* arr = (int[]){...}` decays the array
+                // literal to a pointer in C. Lower it to a borrowed slice by
+                // hoisting the literal into a block-scoped `let`, so the
+                // reference stays valid instead of pointing at a temporary
+                // that Rust would otherwise drop at the end of the
+                // expression.
+                if matches!(target_type, Some(HirType::Pointer(_)))
+                    && matches!(literal_type, HirType::Array { .. })
+                {
+                    let array_code = self.generate_expression_with_context(
+                        &HirExpression::CompoundLiteral {
+                            literal_type: literal_type.clone(),
+                            initializers: initializers.clone(),
+                        },
+                        ctx,
+                    );
+                    return format!("{{ let __cl = {}; &__cl[..] }}", array_code);
+                }
+
                 // C: (struct Point){10, 20} → Rust: Point { x: 10, y: 20 }
                 // C: (int[]){1, 2, 3} → Rust: vec![1, 2, 3] or [1, 2, 3]
                 // Sprint 19 Feature (DECY-060)
@@ -2399,17 +2813,45 @@ impl CodeGenerator {
                             }
                         }
                     }
-                    HirType::Array { .. } => {
+                    HirType::Array { element_type, size } => {
                         // DECY-199: Generate array literal [1, 2, 3] instead of vec![...]
                         // Fixed-size arrays should use array literals, not Vec
-                        if initializers.is_empty() {
-                            "[]".to_string()
-                        } else {
-                            let elements: Vec<String> = initializers
-                                .iter()
-                                .map(|init| self.generate_expression_with_context(init, ctx))
-                                .collect();
-                            format!("[{}]", elements.join(", "))
+                        //
+                        // DECY-276: C99 zero-fills any element the initializer
+                        // list doesn't mention (`(int[5]){1, 2}` leaves indices
+                        // 2..5 at 0), so pad short initializer lists out to the
+                        // declared size instead of emitting a shorter array.
+                        match (initializers.as_slice(), size) {
+                            ([], Some(n)) => {
+                                format!("[{}; {}]", Self::default_value_for_type(element_type), n)
+                            }
+                            ([], None) => "[]".to_string(),
+                            // DECY-277: A short initializer list (including a
+                            // single element) zero-fills the remaining C99
+                            // elements - `int arr[4] = {1};` is `{1,0,0,0}`,
+                            // not `[1; 4]` (`{1,1,1,1}`). Fall through to the
+                            // pad-with-default branch below instead of
+                            // special-casing a single initializer into
+                            // Rust's `[x; n]` *repeat* syntax, which silently
+                            // produces the wrong values for any element type
+                            // whose default isn't equal to the initializer.
+                            (inits, Some(n)) if inits.len() < *n => {
+                                let mut elements: Vec<String> = inits
+                                    .iter()
+                                    .map(|init| self.generate_expression_with_context(init, ctx))
+                                    .collect();
+                                for _ in inits.len()..*n {
+                                    elements.push(Self::default_value_for_type(element_type));
+                                }
+                                format!("[{}]", elements.join(", "))
+                            }
+                            (inits, _) => {
+                                let elements: Vec<String> = inits
+                                    .iter()
+                                    .map(|init| self.generate_expression_with_context(init, ctx))
+                                    .collect();
+                                format!("[{}]", elements.join(", "))
+                            }
                         }
                     }
                     _ => {
@@ -2480,9 +2922,64 @@ impl CodeGenerator {
                 then_expr,
                 else_expr,
             } => {
+                // DECY-274: GNU's omitted-middle elvis extension (`a ?: b`)
+                // parses as a ternary whose then-arm is structurally
+                // identical to its condition. Generating each arm
+                // independently (the general case below) would emit `a`
+                // twice - once for the condition, once for the then-arm -
+                // double-evaluating it, which is wrong when `a` has side
+                // effects (`get() ?: default`). Bind it to a fresh temporary
+                // instead so it's evaluated exactly once.
+                if **then_expr == **condition {
+                    let cond_code = self.generate_expression_with_context(condition, ctx);
+                    let else_code = self.generate_expression_with_context(else_expr, ctx);
+                    let temp = Self::fresh_elvis_temp(ctx);
+                    let truthy = match ctx.infer_expression_type(condition) {
+                        Some(HirType::Option(_)) => format!("{}.is_some()", temp),
+                        Some(HirType::Pointer(_)) => format!("!{}.is_null()", temp),
+                        _ if Self::is_boolean_expression(condition) => temp.clone(),
+                        _ => format!("{} != 0", temp),
+                    };
+                    return format!(
+                        "{{ let {t} = {c}; if {truthy} {{ {t} }} else {{ {e} }} }}",
+                        t = temp,
+                        c = cond_code,
+                        truthy = truthy,
+                        e = else_code
+                    );
+                }
+
                 let cond_code = self.generate_expression_with_context(condition, ctx);
-                let then_code = self.generate_expression_with_context(then_expr, ctx);
-                let else_code = self.generate_expression_with_context(else_expr, ctx);
+                let mut then_code = self.generate_expression_with_context(then_expr, ctx);
+                let mut else_code = self.generate_expression_with_context(else_expr, ctx);
+
+                // DECY-272: C allows the two arms to have different arithmetic
+                // types (`cond ? some_int : some_double`); Rust's `if`/`else`
+                // requires identical arm types, so apply the usual arithmetic
+                // conversions and cast whichever arm isn't already the common
+                // type. Skipped for a NULL-constant arm (`cond ? p : NULL`),
+                // which already lowers to `None` and unifies with the other
+                // arm's `Option<T>` type without any numeric cast.
+                let is_null_arm = matches!(**then_expr, HirExpression::NullLiteral)
+                    || matches!(**else_expr, HirExpression::NullLiteral);
+                if !is_null_arm {
+                    if let (Some(then_ty), Some(else_ty)) = (
+                        ctx.infer_expression_type(then_expr),
+                        ctx.infer_expression_type(else_expr),
+                    ) {
+                        if let Some(common) =
+                            Self::usual_arithmetic_conversion_type(&then_ty, &else_ty)
+                        {
+                            let common_rust = Self::map_type(&common);
+                            if then_ty != common {
+                                then_code = format!("({} as {})", then_code, common_rust);
+                            }
+                            if else_ty != common {
+                                else_code = format!("({} as {})", else_code, common_rust);
+                            }
+                        }
+                    }
+                }
 
                 // Convert condition to boolean if it's not already
                 let cond_bool = if Self::is_boolean_expression(condition) {
@@ -2491,11 +2988,78 @@ impl CodeGenerator {
                     format!("{} != 0", cond_code)
                 };
 
-                format!("if {} {{ {} }} else {{ {} }}", cond_bool, then_code, else_code)
+                // DECY-275: In macro-emission mode, render as a call to the
+                // generated `tern!` helper instead of an inlined `if`/`else`,
+                // giving a one-to-one line correspondence with the original
+                // C `?:` site. A nested ternary else-arm was already lowered
+                // to its own `tern!(...)` call by the recursive call above,
+                // so it nests naturally without any ladder-collapsing.
+                if matches!(self.ternary_lowering_mode, TernaryLoweringMode::Macro) {
+                    return format!("tern!({}, {}, {})", cond_bool, then_code, else_code);
+                }
+
+                // DECY-273: A right-associative ternary chain
+                // (`a ? x : b ? y : z`) parses as a ternary whose else-arm is
+                // itself a ternary. `else_code` was already lowered to its
+                // own `if ... else ...` by the recursive call above, so
+                // dropping the extra `{ }` around it collapses the chain into
+                // a single idiomatic `else if` ladder instead of nesting an
+                // `if` block inside the `else` block. Only fires when the
+                // else-arm is a *direct* nested ternary, not one wrapped in
+                // some other expression, so this is purely a formatting
+                // normalization with no change in semantics.
+                let result = if matches!(**else_expr, HirExpression::Ternary { .. }) {
+                    format!("if {} {{ {} }} else {}", cond_bool, then_code, else_code)
+                } else {
+                    format!(
+                        "if {} {{ {} }} else {{ {} }}",
+                        cond_bool, then_code, else_code
+                    )
+                };
+
+                // DECY-271: Guard against ever hoisting a ternary arm into a
+                // temporary evaluated before the branch, which would force
+                // evaluation of both arms and break C's single-evaluation
+                // semantics for `cond ? read() : write()`-style side effects.
+                debug_assert!(
+                    Self::ternary_preserves_single_evaluation(&result, &then_code, &else_code),
+                    "ternary codegen must keep each arm branch-local, not hoisted: {}",
+                    result
+                );
+
+                result
             }
         }
     }
 
+    /// DECY-271: True when neither ternary arm appears in `generated` before
+    /// its `if` keyword — i.e. neither arm was hoisted into a shared
+    /// temporary evaluated unconditionally ahead of the branch.
+    fn ternary_preserves_single_evaluation(
+        generated: &str,
+        then_code: &str,
+        else_code: &str,
+    ) -> bool {
+        let Some(if_pos) = generated.find("if ") else {
+            return false;
+        };
+        let before_branch = &generated[..if_pos];
+        (then_code.is_empty() || !before_branch.contains(then_code))
+            && (else_code.is_empty() || !before_branch.contains(else_code))
+    }
+
+    /// DECY-274: Pick a temporary name for the elvis operator's condition
+    /// that doesn't collide with a declared variable in scope.
+    fn fresh_elvis_temp(ctx: &TypeContext) -> String {
+        let mut candidate = "__elvis".to_string();
+        let mut suffix = 0u32;
+        while ctx.get_type(&candidate).is_some() {
+            suffix += 1;
+            candidate = format!("__elvis_{}", suffix);
+        }
+        candidate
+    }
+
     /// Convert unary operator to string.
     fn unary_operator_to_string(op: &decy_hir::UnaryOperator) -> &'static str {
         use decy_hir::UnaryOperator;
@@ -2930,6 +3494,385 @@ impl CodeGenerator {
     }
 
     /// Generate code for a statement with type context for pointer arithmetic and return type for null pointer detection.
+    /// DECY-262: Generate a `match` expression for a C `switch`, resolving the
+    /// full variant set when the discriminant's type is a known C `enum`.
+    ///
+    /// When the condition resolves to a registered `HirType::Enum`, each case
+    /// label is matched against the enum's variants (by explicit value or by
+    /// declaration order) and rendered as `EnumName::Variant` instead of a raw
+    /// integer. If every variant is covered, the `_` wildcard is omitted
+    /// entirely so the compiler enforces exhaustiveness; any variants left
+    /// uncovered are either handled by an explicit `default` (kept as `_`) or,
+    /// when there is no `default`, synthesized as empty auto-filled arms
+    /// (mirroring rust-analyzer's "fill match arms" assist). Auto-filled
+    /// variants are recorded in a leading comment so downstream passes can
+    /// warn about them. Non-enum discriminants keep the original behaviour of
+    /// always emitting a `_` arm.
+    ///
+    /// For non-enum discriminants, DECY-263 also collapses contiguous
+    /// fallthrough label groups into a single arm, rendered per this
+    /// generator's configured [`SwitchLoweringMode`].
+    fn generate_switch_statement(
+        &self,
+        condition: &HirExpression,
+        cases: &[SwitchCase],
+        default_case: &Option<Vec<HirStatement>>,
+        function_name: Option<&str>,
+        ctx: &mut TypeContext,
+        return_type: Option<&HirType>,
+    ) -> String {
+        let enum_info: Option<(String, Vec<HirEnumVariant>)> =
+            if let HirExpression::Variable(var_name) = condition {
+                match ctx.get_type(var_name) {
+                    Some(HirType::Enum(enum_name)) => {
+                        let enum_name = enum_name.clone();
+                        ctx.get_enum_variants(&enum_name)
+                            .map(|variants| (enum_name, variants.clone()))
+                    }
+                    _ => None,
+                }
+            } else {
+                None
+            };
+
+        let (cases, duplicate_diagnostics) = Self::dedup_switch_cases(cases);
+
+        let mut code = String::new();
+        code.push_str(&format!(
+            "match {} {{\n",
+            self.generate_expression_with_context(condition, ctx)
+        ));
+
+        let mut handled_variants: Vec<String> = Vec::new();
+        let mut auto_filled: Vec<String> = Vec::new();
+
+        if enum_info.is_none() {
+            // DECY-263: Collapse contiguous fallthrough groups (e.g. `case 10:
+            // case 11: ... case 16: body;`) into a single arm before emitting,
+            // so the generated match doesn't contain dead no-op arms for the
+            // labels that merely fall through to the terminal body.
+            for group in Self::group_fallthrough_cases(&cases) {
+                let pattern = self.render_case_group_pattern(&group.values, ctx);
+                code.push_str(&format!("    {} => {{\n", pattern));
+                for stmt in group.body {
+                    if !matches!(stmt, HirStatement::Break) {
+                        code.push_str("        ");
+                        code.push_str(&self.generate_statement_with_context(
+                            stmt,
+                            function_name,
+                            ctx,
+                            return_type,
+                        ));
+                        code.push('\n');
+                    }
+                }
+                code.push_str("    },\n");
+            }
+        } else {
+            for case in &cases {
+                if let Some(value_expr) = &case.value {
+                    let pattern = match &enum_info {
+                        Some((enum_name, variants)) => {
+                            match Self::match_enum_variant(value_expr, variants) {
+                                Some(variant_name) => {
+                                    handled_variants.push(variant_name.clone());
+                                    format!("{}::{}", enum_name, variant_name)
+                                }
+                                None => self.generate_expression_with_context(value_expr, ctx),
+                            }
+                        }
+                        None => self.generate_expression_with_context(value_expr, ctx),
+                    };
+
+                    code.push_str(&format!("    {} => {{\n", pattern));
+                    for stmt in &case.body {
+                        if !matches!(stmt, HirStatement::Break) {
+                            code.push_str("        ");
+                            code.push_str(&self.generate_statement_with_context(
+                                stmt,
+                                function_name,
+                                ctx,
+                                return_type,
+                            ));
+                            code.push('\n');
+                        }
+                    }
+                    code.push_str("    },\n");
+                }
+            }
+        }
+
+        if let Some((enum_name, variants)) = &enum_info {
+            let uncovered: Vec<&HirEnumVariant> = variants
+                .iter()
+                .filter(|v| !handled_variants.iter().any(|h| h == v.name()))
+                .collect();
+
+            if uncovered.is_empty() {
+                // Every variant is explicitly handled - omit `_` for true exhaustiveness.
+            } else if let Some(default_stmts) = default_case {
+                code.push_str("    _ => {\n");
+                for stmt in default_stmts {
+                    if !matches!(stmt, HirStatement::Break) {
+                        code.push_str("        ");
+                        code.push_str(&self.generate_statement_with_context(
+                            stmt,
+                            function_name,
+                            ctx,
+                            return_type,
+                        ));
+                        code.push('\n');
+                    }
+                }
+                code.push_str("    },\n");
+            } else {
+                for variant in uncovered {
+                    auto_filled.push(variant.name().to_string());
+                    code.push_str(&format!(
+                        "    {}::{} => {{}}, // decy: auto-filled arm (missing case for exhaustiveness)\n",
+                        enum_name,
+                        variant.name()
+                    ));
+                }
+            }
+        } else {
+            // Non-enum discriminant: always keep a `_` arm, as C switches never
+            // carry enough type information to prove exhaustiveness otherwise.
+            code.push_str("    _ => {\n");
+            if let Some(default_stmts) = default_case {
+                for stmt in default_stmts {
+                    if !matches!(stmt, HirStatement::Break) {
+                        code.push_str("        ");
+                        code.push_str(&self.generate_statement_with_context(
+                            stmt,
+                            function_name,
+                            ctx,
+                            return_type,
+                        ));
+                        code.push('\n');
+                    }
+                }
+            }
+            code.push_str("    },\n");
+        }
+
+        code.push('}');
+
+        if !auto_filled.is_empty() {
+            code = format!(
+                "// decy: auto-filled exhaustive arms for {}\n{}",
+                auto_filled.join(", "),
+                code
+            );
+        }
+
+        if !duplicate_diagnostics.is_empty() {
+            // DECY-270: `generate_switch_statement` has no return channel of
+            // its own to bubble a `Vec<Diagnostic>` up to a `transpile_with_*`
+            // caller (unlike `transpile_with_race_diagnostics`'s dedicated
+            // diagnostics-returning entry point), so this still surfaces as a
+            // leading source comment - but the comment text is now rendered
+            // from a real `Diagnostic`'s severity tag and message, not a bare
+            // ad hoc string.
+            let diagnostics = duplicate_diagnostics
+                .iter()
+                .map(|diag| format!("// decy: {}: {}", diag.severity, diag.message))
+                .collect::<Vec<_>>()
+                .join("\n");
+            code = format!("{}\n{}", diagnostics, code);
+        }
+
+        code
+    }
+
+    /// DECY-263: Drop duplicate scalar case labels from a switch before lowering,
+    /// keeping the first occurrence.
+    ///
+    /// C allows two `case 5:` labels in the same switch (the second is dead code
+    /// reached only by fallthrough from the first), but naively translating both
+    /// into Rust `match` arms produces an `unreachable pattern` compile error.
+    /// Since C's fallthrough semantics mean the first matching label always wins,
+    /// dropping every label after the first preserves behavior while guaranteeing
+    /// the emitted `match` compiles. Each dropped duplicate is reported as a
+    /// diagnostic naming the case's scalar value, surfaced by the caller as a
+    /// leading source comment.
+    ///
+    /// Only scalar integer labels are checked; range labels are not yet
+    /// supported by [`SwitchCase`], so overlap detection between ranges is not
+    /// yet applicable here.
+    ///
+    /// DECY-270: Each dropped duplicate is reported as a
+    /// [`decy_parser::diagnostic::Diagnostic`] rather than a bare `String`, so
+    /// it carries a real severity/category instead of an opaque, un-typed
+    /// message. No byte offset is threaded down to this call depth yet (the
+    /// span chunk737-4 added only reaches the parser's own diagnostics), so
+    /// `line`/`column` are left `None` - still an honest, locatable-in-theory
+    /// diagnostic rather than a silent source comment.
+    fn dedup_switch_cases(cases: &[SwitchCase]) -> (Vec<&SwitchCase>, Vec<Diagnostic>) {
+        let mut seen: Vec<i32> = Vec::new();
+        let mut deduped = Vec::with_capacity(cases.len());
+        let mut diagnostics = Vec::new();
+
+        for case in cases {
+            match &case.value {
+                Some(HirExpression::IntLiteral(n)) => {
+                    if seen.contains(n) {
+                        diagnostics.push(Diagnostic::new(
+                            Severity::Warning,
+                            format!(
+                                "duplicate switch case `{}` dropped (first occurrence wins per C fallthrough semantics)",
+                                n
+                            ),
+                        ));
+                        continue;
+                    }
+                    seen.push(*n);
+                    deduped.push(case);
+                }
+                _ => deduped.push(case),
+            }
+        }
+
+        (deduped, diagnostics)
+    }
+
+    /// DECY-263: Group consecutive `case` labels that fall through into a
+    /// shared terminal body into a single [`CaseGroup`] each.
+    ///
+    /// A label with an empty body is assumed to fall through to the next
+    /// label's body (standard C `case N: case M: body;` grouping); a label
+    /// with a non-empty body terminates the group it is accumulating and
+    /// starts a fresh one. A trailing run of empty-bodied labels with no
+    /// terminal body (unusual, but not rejected) is emitted as one group with
+    /// an empty body, so every label in it still runs no statements.
+    fn group_fallthrough_cases<'a>(cases: &'a [&'a SwitchCase]) -> Vec<CaseGroup<'a>> {
+        let mut groups = Vec::new();
+        let mut pending: Vec<&HirExpression> = Vec::new();
+
+        for case in cases {
+            let Some(value_expr) = &case.value else {
+                continue;
+            };
+            pending.push(value_expr);
+            if !case.body.is_empty() {
+                groups.push(CaseGroup {
+                    values: std::mem::take(&mut pending),
+                    body: &case.body,
+                });
+            }
+        }
+
+        if !pending.is_empty() {
+            groups.push(CaseGroup {
+                values: pending,
+                body: &[],
+            });
+        }
+
+        groups
+    }
+
+    /// DECY-263: Render the match pattern for a collapsed [`CaseGroup`].
+    ///
+    /// A single-value group renders as before (just the expression). A
+    /// multi-value group whose labels are all contiguous integer literals
+    /// renders per this generator's configured [`SwitchLoweringMode`]: as a `LOW..=HIGH` range
+    /// pattern, or - when [`SwitchLoweringMode::Unrolled`] is selected and the
+    /// span is no wider than [`MAX_SWITCH_UNROLL_WIDTH`] - as an explicit
+    /// `LOW | ... | HIGH` OR-pattern. Non-contiguous or non-literal groups
+    /// always render as a plain OR-pattern over each label's expression.
+    fn render_case_group_pattern(&self, values: &[&HirExpression], ctx: &mut TypeContext) -> String {
+        if values.len() == 1 {
+            return self.generate_expression_with_context(values[0], ctx);
+        }
+
+        let literals: Option<Vec<i32>> = values
+            .iter()
+            .map(|v| match v {
+                HirExpression::IntLiteral(n) => Some(*n),
+                _ => None,
+            })
+            .collect();
+
+        if let Some(literals) = literals {
+            let is_contiguous = literals
+                .windows(2)
+                .all(|pair| pair[1] == pair[0] + 1);
+
+            if is_contiguous {
+                let low = literals[0];
+                let high = *literals.last().expect("non-empty group");
+                let width = literals.len();
+
+                let use_unrolled = matches!(self.switch_lowering_mode, SwitchLoweringMode::Unrolled)
+                    && width <= MAX_SWITCH_UNROLL_WIDTH;
+
+                return if use_unrolled {
+                    literals
+                        .iter()
+                        .map(|n| n.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" | ")
+                } else {
+                    format!("{}..={}", low, high)
+                };
+            }
+        }
+
+        values
+            .iter()
+            .map(|v| self.generate_expression_with_context(v, ctx))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+
+    /// DECY-262: Resolve a switch case's value expression to an enum variant name.
+    ///
+    /// Supports a bare reference to the variant constant (`case INIT:`) and an
+    /// integer literal matched against the variant's explicit value, or, when
+    /// the variant has none, the previous variant's value plus one (0 for the
+    /// first variant), as in standard C enumeration.
+    fn match_enum_variant(value_expr: &HirExpression, variants: &[HirEnumVariant]) -> Option<String> {
+        match value_expr {
+            HirExpression::Variable(name) => variants
+                .iter()
+                .find(|v| v.name() == name)
+                .map(|v| v.name().to_string()),
+            HirExpression::IntLiteral(n) => {
+                let mut next_value = 0i32;
+                variants.iter().find_map(|v| {
+                    let effective_value = v.value().unwrap_or(next_value);
+                    next_value = effective_value.wrapping_add(1);
+                    if effective_value == *n {
+                        Some(v.name().to_string())
+                    } else {
+                        None
+                    }
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// DECY-262: Generate a statement with enum-aware type context, for callers
+    /// that know the discriminant of a switch is backed by a C `enum`.
+    ///
+    /// This mirrors [`CodeGenerator::generate_function_with_structs`] but for
+    /// the enum case: it seeds a fresh [`TypeContext`] with the given
+    /// variable-to-enum-type bindings before generating the statement.
+    pub fn generate_statement_with_enum_context(
+        &self,
+        stmt: &HirStatement,
+        enum_vars: &[(String, HirEnum)],
+    ) -> String {
+        let mut ctx = TypeContext::new();
+        for (var_name, hir_enum) in enum_vars {
+            ctx.add_variable(var_name.clone(), HirType::Enum(hir_enum.name().to_string()));
+            ctx.add_enum(hir_enum);
+        }
+        self.generate_statement_with_context(stmt, None, &mut ctx, None)
+    }
+
     fn generate_statement_with_context(
         &self,
         stmt: &HirStatement,
@@ -3057,10 +4000,32 @@ impl CodeGenerator {
                         HirType::Pointer(inner) if matches!(&**inner, HirType::Char)
                     );
 
+                    // DECY-276: int* arr = (int[]){...} → the array literal
+                    // decays to a borrowed slice, not a raw pointer, so the
+                    // binding's declared type has to track it.
+                    let array_literal_elem = match (var_type, initializer) {
+                        (
+                            HirType::Pointer(elem),
+                            Some(HirExpression::CompoundLiteral {
+                                literal_type: HirType::Array { .. },
+                                ..
+                            }),
+                        ) => Some(elem.as_ref().clone()),
+                        _ => None,
+                    };
+
                     if is_char_pointer && is_string_literal_init {
                         // char* s = "hello" → let s: &str = "hello"
                         ctx.add_variable(name.clone(), HirType::StringReference);
                         (HirType::StringReference, "&str".to_string())
+                    } else if let Some(elem) = array_literal_elem {
+                        let slice_ref_type = HirType::Reference {
+                            inner: Box::new(HirType::Vec(Box::new(elem))),
+                            mutable: false,
+                        };
+                        let type_str = Self::map_type(&slice_ref_type);
+                        ctx.add_variable(name.clone(), slice_ref_type.clone());
+                        (slice_ref_type, type_str)
                     } else {
                         ctx.add_variable(name.clone(), var_type.clone());
                         (var_type.clone(), Self::map_type(var_type))
@@ -3068,7 +4033,11 @@ impl CodeGenerator {
                 };
 
                 // DECY-088: For string literals, use immutable binding
-                let mutability = if matches!(_actual_type, HirType::StringReference) {
+                // DECY-276: Same for a hoisted array-literal slice reference.
+                let mutability = if matches!(
+                    _actual_type,
+                    HirType::StringReference | HirType::Reference { .. }
+                ) {
                     ""
                 } else {
                     "mut "
@@ -3326,6 +4295,34 @@ impl CodeGenerator {
             HirStatement::Break => "break;".to_string(),
             HirStatement::Continue => "continue;".to_string(),
             HirStatement::Assignment { target, value } => {
+                // DECY-264: An atomic-lowered global's writes become fetch_add/fetch_sub/store
+                // instead of a plain `target = value;`, which would not compile against a
+                // `static COUNTER: AtomicI32` in the first place.
+                if ctx.is_atomic_global(target) {
+                    return atomic_global_transform::self_rmw_delta(target, value).map_or_else(
+                        || {
+                            format!(
+                                "{}.store({}, std::sync::atomic::Ordering::SeqCst);",
+                                target,
+                                self.generate_expression_with_context(value, ctx)
+                            )
+                        },
+                        |delta| {
+                            if delta >= 0 {
+                                format!(
+                                    "{}.fetch_add({}, std::sync::atomic::Ordering::SeqCst);",
+                                    target, delta
+                                )
+                            } else {
+                                format!(
+                                    "{}.fetch_sub({}, std::sync::atomic::Ordering::SeqCst);",
+                                    target, -delta
+                                )
+                            }
+                        },
+                    );
+                }
+
                 // Special handling for realloc() → Vec::resize/truncate/clear
                 if let HirExpression::Realloc { pointer, new_size } = value {
                     // target is a String (variable name) in Assignment statements
@@ -3478,63 +4475,14 @@ impl CodeGenerator {
                 condition,
                 cases,
                 default_case,
-            } => {
-                let mut code = String::new();
-
-                // Generate match expression
-                code.push_str(&format!(
-                    "match {} {{\n",
-                    self.generate_expression_with_context(condition, ctx)
-                ));
-
-                // Generate each case
-                for case in cases {
-                    if let Some(value_expr) = &case.value {
-                        // Generate case pattern
-                        code.push_str(&format!(
-                            "    {} => {{\n",
-                            self.generate_expression_with_context(value_expr, ctx)
-                        ));
-
-                        // Generate case body (filter out Break statements)
-                        for stmt in &case.body {
-                            if !matches!(stmt, HirStatement::Break) {
-                                code.push_str("        ");
-                                code.push_str(&self.generate_statement_with_context(
-                                    stmt,
-                                    function_name,
-                                    ctx,
-                                    return_type,
-                                ));
-                                code.push('\n');
-                            }
-                        }
-
-                        code.push_str("    },\n");
-                    }
-                }
-
-                // Generate default case (or empty default if not present)
-                code.push_str("    _ => {\n");
-                if let Some(default_stmts) = default_case {
-                    for stmt in default_stmts {
-                        if !matches!(stmt, HirStatement::Break) {
-                            code.push_str("        ");
-                            code.push_str(&self.generate_statement_with_context(
-                                stmt,
-                                function_name,
-                                ctx,
-                                return_type,
-                            ));
-                            code.push('\n');
-                        }
-                    }
-                }
-                code.push_str("    },\n");
-
-                code.push('}');
-                code
-            }
+            } => self.generate_switch_statement(
+                condition,
+                cases,
+                default_case,
+                function_name,
+                ctx,
+                return_type,
+            ),
             HirStatement::DerefAssignment { target, value } => {
                 // DECY-185: Handle struct field access targets directly (no dereference needed)
                 // sb->capacity = value should generate (*sb).capacity = value, not *(*sb).capacity = value
@@ -3672,10 +4620,24 @@ impl CodeGenerator {
             } => {
                 // Look up field type for null pointer detection
                 let field_type = ctx.get_field_type(object, field);
-                let obj_code = self.generate_expression_with_context(object, ctx);
                 let value_code =
                     self.generate_expression_with_target_type(value, ctx, field_type.as_ref());
 
+                // DECY-266: A lone field assignment to a mutex-lowered struct
+                // global (not part of a multi-field critical section, which
+                // `generate_function` rewrites separately) still needs its
+                // own `.lock().unwrap()` rather than raw field access.
+                if let HirExpression::Variable(name) = object {
+                    if ctx.is_mutex_global(name) {
+                        return format!(
+                            "{}.lock().unwrap().{} = {};",
+                            name, field, value_code
+                        );
+                    }
+                }
+
+                let obj_code = self.generate_expression_with_context(object, ctx);
+
                 // DECY-119: Check if object is a raw pointer - need unsafe deref
                 let obj_type = if let HirExpression::Variable(name) = object {
                     ctx.get_type(name)
@@ -5076,10 +6038,21 @@ impl CodeGenerator {
     /// assert!(code.contains("}"));
     /// ```
     pub fn generate_function(&self, func: &HirFunction) -> String {
+        // DECY-265: A guarded check-then-act accessor for an opted-in global
+        // lowers to a compare-exchange retry loop instead of normal statement
+        // codegen - bypass the usual pipeline entirely when detected.
+        for global in &self.guarded_cas_globals {
+            if let Some(guarded) =
+                guarded_cas_transform::detect_guarded_decrement(global, func.body())
+            {
+                return self.generate_guarded_cas_function(func, &guarded);
+            }
+        }
+
         let mut code = String::new();
 
         // DECY-072 GREEN: Build mapping of length params -> array params for body transformation
-        use decy_ownership::dataflow::DataflowAnalyzer;
+        use decy_ownership::dataflow::{ArrayKind, DataflowAnalyzer};
         let analyzer = DataflowAnalyzer::new();
         let graph = analyzer.analyze(func);
 
@@ -5122,6 +6095,12 @@ impl CodeGenerator {
 
         // Initialize type context for tracking variable types across statements
         let mut ctx = TypeContext::from_function(func);
+        for name in &self.atomic_globals {
+            ctx.add_atomic_global(name.clone());
+        }
+        for name in &self.mutex_globals {
+            ctx.add_mutex_global(name.clone());
+        }
 
         // DECY-129/DECY-148: Update context to reflect pointer-to-reference transformations
         // When pointer params are transformed to &mut T in signature, context must match
@@ -5176,8 +6155,63 @@ impl CodeGenerator {
                 code.push('\n');
             }
         } else {
+            // DECY-079: A detected array parameter whose length is bound to
+            // a compile-time constant (rather than a sibling length
+            // parameter) loses that enforcement once lowered to a bare
+            // slice - the call site no longer passes a length to mismatch
+            // against. Assert it here instead, so a mis-sized slice panics
+            // in debug builds rather than silently reading/writing short.
+            for param in func.parameters() {
+                if let Some(true) = graph.is_array_parameter(param.name()) {
+                    if self.uses_pointer_arithmetic(func, param.name()) {
+                        continue;
+                    }
+                    if let Some(ArrayKind::LengthBoundByConstant(n)) =
+                        graph.array_kind(param.name())
+                    {
+                        code.push_str(&format!(
+                            "    debug_assert_eq!({}.len(), {});\n",
+                            param.name(),
+                            n
+                        ));
+                    }
+                }
+            }
+
+            // DECY-266: Locate multi-field critical sections for any opted-in
+            // struct global so they can be rewritten into a single locked
+            // scope instead of per-statement codegen.
+            let mutex_sections: Vec<mutex_global_transform::CriticalSection> = self
+                .mutex_globals
+                .iter()
+                .flat_map(|global| mutex_global_transform::find_critical_sections(global, func.body()))
+                .collect();
+
             // Generate actual body statements with persistent context
-            for stmt in func.body() {
+            let mut idx = 0;
+            while idx < func.body().len() {
+                if let Some(section) = mutex_sections.iter().find(|s| s.start == idx) {
+                    let global = match &func.body()[section.start] {
+                        HirStatement::FieldAssignment {
+                            object: HirExpression::Variable(name),
+                            ..
+                        } => name.clone(),
+                        _ => unreachable!("critical section must start with a FieldAssignment on a global"),
+                    };
+                    code.push_str("    {\n");
+                    code.push_str(&format!("        let mut g = {}.lock().unwrap();\n", global));
+                    for inner_stmt in &func.body()[section.start..section.end] {
+                        if let HirStatement::FieldAssignment { field, value, .. } = inner_stmt {
+                            let value_code = self.generate_expression_with_context(value, &ctx);
+                            code.push_str(&format!("        g.{} = {};\n", field, value_code));
+                        }
+                    }
+                    code.push_str("    }\n");
+                    idx = section.end;
+                    continue;
+                }
+
+                let stmt = &func.body()[idx];
                 code.push_str("    ");
                 let stmt_code = self.generate_statement_with_context(
                     stmt,
@@ -5190,6 +6224,7 @@ impl CodeGenerator {
                 let transformed = self.transform_length_refs(&stmt_code, &length_to_array);
                 code.push_str(&transformed);
                 code.push('\n');
+                idx += 1;
             }
         }
 
@@ -5197,6 +6232,51 @@ impl CodeGenerator {
         code
     }
 
+    /// DECY-265: Render a guarded check-then-act accessor as a
+    /// `compare_exchange_weak` retry loop instead of a plain load/branch/store.
+    ///
+    /// The guard predicate and the update are recomputed from the freshly
+    /// loaded value on every iteration; a failed `compare_exchange_weak`
+    /// (spurious or contended) simply re-loops with the value it returned.
+    fn generate_guarded_cas_function(
+        &self,
+        func: &HirFunction,
+        guarded: &guarded_cas_transform::GuardedDecrement,
+    ) -> String {
+        let op_str = Self::binary_operator_to_string(&guarded.op);
+        let update = if guarded.delta >= 0 {
+            format!("cur + {}", guarded.delta)
+        } else {
+            format!("cur - {}", -guarded.delta)
+        };
+
+        let mut code = String::new();
+        code.push_str(&self.generate_signature(func));
+        code.push_str(" {\n");
+        code.push_str("    loop {\n");
+        code.push_str(&format!(
+            "        let cur = {}.load(std::sync::atomic::Ordering::Acquire);\n",
+            guarded.global
+        ));
+        code.push_str(&format!(
+            "        if !(cur {} {}) {{\n            break {};\n        }}\n",
+            op_str, guarded.threshold, guarded.failure_value
+        ));
+        code.push_str(&format!(
+            "        match {}.compare_exchange_weak(cur, {}, std::sync::atomic::Ordering::AcqRel, std::sync::atomic::Ordering::Acquire) {{\n",
+            guarded.global, update
+        ));
+        code.push_str(&format!(
+            "            Ok(_) => break {},\n",
+            guarded.success_value
+        ));
+        code.push_str("            Err(_) => continue,\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n");
+        code.push('}');
+        code
+    }
+
     /// Generate a complete function from HIR with struct definitions for type inference.
     ///
     /// This is useful for testing when struct fields need proper type inference.
@@ -5214,6 +6294,12 @@ impl CodeGenerator {
 
         // Initialize type context with function parameters AND struct definitions
         let mut ctx = TypeContext::from_function(func);
+        for name in &self.atomic_globals {
+            ctx.add_atomic_global(name.clone());
+        }
+        for name in &self.mutex_globals {
+            ctx.add_mutex_global(name.clone());
+        }
 
         // DECY-165: Add struct definitions to context for field type lookup
         for struct_def in structs {
@@ -5363,6 +6449,12 @@ impl CodeGenerator {
 
         // DECY-041: Initialize type context with function parameters for pointer arithmetic
         let mut ctx = TypeContext::from_function(func);
+        for name in &self.atomic_globals {
+            ctx.add_atomic_global(name.clone());
+        }
+        for name in &self.mutex_globals {
+            ctx.add_mutex_global(name.clone());
+        }
 
         // DECY-134: Track string iteration params for index-based body generation
         let mut string_iter_index_decls = Vec::new();
@@ -5724,6 +6816,28 @@ impl CodeGenerator {
     /// Generates Rust struct code with automatic derives for Debug, Clone, PartialEq, Eq.
     /// Handles lifetimes automatically for structs with reference fields.
     pub fn generate_struct(&self, hir_struct: &decy_hir::HirStruct) -> String {
+        // DECY-268: A struct whose fields are *all* bitfields (`unsigned x : 20;`)
+        // packs them into minimal backing storage with mask/shift accessors
+        // instead of widening each member to its full declared type. Mixed
+        // bitfield/ordinary-field structs aren't handled yet and fall through
+        // to the field-by-field codegen below unchanged.
+        if !hir_struct.fields().is_empty()
+            && hir_struct.fields().iter().all(|f| f.bit_width().is_some())
+        {
+            let specs: Vec<packed_layout::BitFieldSpec> = hir_struct
+                .fields()
+                .iter()
+                .map(|f| {
+                    packed_layout::BitFieldSpec::new(
+                        f.name().to_string(),
+                        f.bit_width().expect("checked by the all() above"),
+                    )
+                })
+                .collect();
+            let layout = packed_layout::PackedLayout::new(hir_struct.name(), &specs);
+            return packed_layout::PackedLayoutGenerator::new().generate_struct(&layout);
+        }
+
         let mut code = String::new();
 
         // Check if struct needs lifetimes (has Reference fields)
@@ -6136,3 +7250,31 @@ mod switch_property_tests;
 #[cfg(test)]
 #[path = "global_variable_codegen_tests.rs"]
 mod global_variable_codegen_tests;
+
+#[cfg(test)]
+#[path = "packed_layout_tests.rs"]
+mod packed_layout_tests;
+
+#[cfg(test)]
+#[path = "ternary_side_effect_tests.rs"]
+mod ternary_side_effect_tests;
+
+#[cfg(test)]
+#[path = "ternary_usual_arithmetic_conversions_tests.rs"]
+mod ternary_usual_arithmetic_conversions_tests;
+
+#[cfg(test)]
+#[path = "ternary_else_if_ladder_tests.rs"]
+mod ternary_else_if_ladder_tests;
+
+#[cfg(test)]
+#[path = "ternary_elvis_tests.rs"]
+mod ternary_elvis_tests;
+
+#[cfg(test)]
+#[path = "ternary_macro_mode_tests.rs"]
+mod ternary_macro_mode_tests;
+
+#[cfg(test)]
+#[path = "size_expr_tests.rs"]
+mod size_expr_tests;
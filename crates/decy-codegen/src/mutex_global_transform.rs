@@ -0,0 +1,126 @@
+//! Mutex-wrapped struct global transformation module for critical sections.
+//!
+//! Transforms a file-scope struct global whose fields are written across a
+//! multi-field critical section (two or more consecutive field assignments
+//! in the same function) from a `static mut` requiring an `unsafe` touch per
+//! field into `static NAME: Mutex<StructName> = Mutex::new(...)`, with each
+//! critical section rewritten into a single locked scope.
+//!
+//! Part of DECY-266: Lower shared struct globals to `Mutex<T>`.
+
+use decy_hir::{HirExpression, HirFunction, HirStatement};
+
+/// A maximal run of two or more consecutive `FieldAssignment` statements
+/// against the same global, identified by the half-open `[start, end)` range
+/// of statement indices within the enclosing body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CriticalSection {
+    /// Index of the first field assignment in the run.
+    pub start: usize,
+    /// Index one past the last field assignment in the run.
+    pub end: usize,
+}
+
+/// Finds every maximal run of two or more consecutive field assignments to
+/// `global` within `body` — the multi-field critical sections this module
+/// lowers into a single locked scope.
+pub fn find_critical_sections(global: &str, body: &[HirStatement]) -> Vec<CriticalSection> {
+    let mut sections = Vec::new();
+    let mut run_start: Option<usize> = None;
+
+    for (idx, stmt) in body.iter().enumerate() {
+        let targets_global = matches!(
+            stmt,
+            HirStatement::FieldAssignment { object, .. }
+                if matches!(object, HirExpression::Variable(name) if name == global)
+        );
+
+        if targets_global {
+            run_start.get_or_insert(idx);
+        } else if let Some(start) = run_start.take() {
+            if idx - start >= 2 {
+                sections.push(CriticalSection { start, end: idx });
+            }
+        }
+    }
+    if let Some(start) = run_start {
+        if body.len() - start >= 2 {
+            sections.push(CriticalSection {
+                start,
+                end: body.len(),
+            });
+        }
+    }
+
+    sections
+}
+
+/// Returns `true` if `global` has a multi-field critical section in any
+/// function — i.e. it is a candidate for `Mutex<T>` lowering.
+pub fn has_critical_section(global: &str, functions: &[HirFunction]) -> bool {
+    functions
+        .iter()
+        .any(|func| !find_critical_sections(global, func.body()).is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use decy_hir::HirType;
+
+    fn field_assignment(field: &str, value: i32) -> HirStatement {
+        HirStatement::FieldAssignment {
+            object: HirExpression::Variable("shared".to_string()),
+            field: field.to_string(),
+            value: HirExpression::IntLiteral(value),
+        }
+    }
+
+    #[test]
+    fn test_finds_single_critical_section() {
+        let body = vec![
+            field_assignment("counter", 1),
+            field_assignment("flag", 1),
+            HirStatement::Return(Some(HirExpression::Variable("shared".to_string()))),
+        ];
+        let sections = find_critical_sections("shared", &body);
+        assert_eq!(sections, vec![CriticalSection { start: 0, end: 2 }]);
+    }
+
+    #[test]
+    fn test_ignores_single_field_touch() {
+        let body = vec![
+            field_assignment("counter", 1),
+            HirStatement::Return(Some(HirExpression::Variable("shared".to_string()))),
+        ];
+        assert!(find_critical_sections("shared", &body).is_empty());
+    }
+
+    #[test]
+    fn test_ignores_unrelated_global() {
+        let body = vec![field_assignment("counter", 1), field_assignment("flag", 1)];
+        assert!(find_critical_sections("other", &body).is_empty());
+    }
+
+    #[test]
+    fn test_has_critical_section_true() {
+        let func = HirFunction::new_with_body(
+            "main".to_string(),
+            HirType::Int,
+            vec![],
+            vec![field_assignment("counter", 1), field_assignment("flag", 1)],
+        );
+        assert!(has_critical_section("shared", &[func]));
+    }
+
+    #[test]
+    fn test_has_critical_section_false() {
+        let func = HirFunction::new_with_body(
+            "main".to_string(),
+            HirType::Int,
+            vec![],
+            vec![field_assignment("counter", 1)],
+        );
+        assert!(!has_critical_section("shared", &[func]));
+    }
+}
@@ -0,0 +1,263 @@
+//! Packed bitfield layout computation and accessor generation (DECY-268).
+//!
+//! C structs frequently pack sub-byte-width members via bitfields
+//! (`unsigned x : 20;`). `decy_hir::HirStructField::bit_width` and the
+//! parser's `StructField::bit_width` carry the width clang reports for each
+//! member; `CodeGenerator::generate_struct` calls into this module whenever
+//! every field of a struct is a bitfield. A struct mixing bitfield and
+//! ordinary fields isn't handled yet and still codegens field-by-field,
+//! silently taking each bitfield at its full declared type width - that
+//! narrower gap is follow-on work.
+//!
+//! Given a list of bitfield members, [`PackedLayout`] computes each member's
+//! bit offset and the minimal backing storage (`ceil(total_bits / 8)` bytes)
+//! without panicking when the total is not byte-aligned. [`PackedLayoutGenerator`]
+//! then emits a Rust struct with `field()`/`set_field()` accessors that mask
+//! and shift into that backing storage, plus a bit-addressed indexer for
+//! arrays whose element stride is not a multiple of 8.
+
+/// A single C bitfield member: a name and a width in bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitFieldSpec {
+    pub name: String,
+    pub bits: u32,
+}
+
+impl BitFieldSpec {
+    /// Create a new bitfield member spec.
+    pub fn new(name: impl Into<String>, bits: u32) -> Self {
+        Self {
+            name: name.into(),
+            bits,
+        }
+    }
+}
+
+/// A bitfield member with its computed bit offset within the packed layout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedField {
+    pub name: String,
+    pub bits: u32,
+    pub bit_offset: u32,
+}
+
+/// Computed layout of a packed (bitfield) struct.
+///
+/// Members are packed sequentially starting at bit offset 0, matching the
+/// common gcc/clang little-endian bitfield allocation order. Backing storage
+/// is sized to `ceil(total_bits / 8)` bytes, so a 20-bit record needs 3
+/// bytes and a non-byte-aligned total does not panic. The generated struct
+/// holds those bytes in the narrowest integer type that fits them (see
+/// [`PackedLayout::backing_rust_type`]), e.g. a `u32` for 3 bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackedLayout {
+    struct_name: String,
+    fields: Vec<PackedField>,
+    total_bits: u32,
+}
+
+impl PackedLayout {
+    /// Compute a packed layout from a sequence of bitfield specs.
+    pub fn new(struct_name: impl Into<String>, specs: &[BitFieldSpec]) -> Self {
+        let mut fields = Vec::with_capacity(specs.len());
+        let mut offset = 0u32;
+        for spec in specs {
+            fields.push(PackedField {
+                name: spec.name.clone(),
+                bits: spec.bits,
+                bit_offset: offset,
+            });
+            offset += spec.bits;
+        }
+        Self {
+            struct_name: struct_name.into(),
+            fields,
+            total_bits: offset,
+        }
+    }
+
+    /// Total number of bits occupied by all members.
+    pub fn total_bits(&self) -> u32 {
+        self.total_bits
+    }
+
+    /// Minimal backing storage size in bytes: `ceil(total_bits / 8)`.
+    pub fn backing_bytes(&self) -> u32 {
+        (self.total_bits + 7) / 8
+    }
+
+    /// The narrowest unsigned Rust integer type that holds `backing_bytes()`,
+    /// or `None` when the layout is wider than 16 bytes (u128's capacity) and
+    /// needs a byte-array backing store instead.
+    pub fn backing_rust_type(&self) -> Option<&'static str> {
+        match self.backing_bytes() {
+            0..=1 => Some("u8"),
+            2 => Some("u16"),
+            3..=4 => Some("u32"),
+            5..=8 => Some("u64"),
+            9..=16 => Some("u128"),
+            _ => None,
+        }
+    }
+
+    /// Look up a member's computed offset/width by name.
+    pub fn field(&self, name: &str) -> Option<&PackedField> {
+        self.fields.iter().find(|f| f.name == name)
+    }
+
+    /// All members in declaration order.
+    pub fn fields(&self) -> &[PackedField] {
+        &self.fields
+    }
+}
+
+/// Bit-addressed layout for an array whose element stride is not a multiple
+/// of 8 bits (e.g. an array of 20-bit records).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PackedArrayLayout {
+    stride_bits: u32,
+}
+
+impl PackedArrayLayout {
+    /// Create a layout for an array of elements each `stride_bits` wide.
+    pub fn new(stride_bits: u32) -> Self {
+        Self { stride_bits }
+    }
+
+    /// Bit offset of element `index` within the backing storage.
+    pub fn element_offset_bits(&self, index: usize) -> u64 {
+        index as u64 * self.stride_bits as u64
+    }
+
+    /// Minimal backing storage size in bytes for `count` elements.
+    pub fn backing_bytes(&self, count: usize) -> u64 {
+        (count as u64 * self.stride_bits as u64 + 7) / 8
+    }
+}
+
+/// Generates Rust struct + impl source for a [`PackedLayout`].
+pub struct PackedLayoutGenerator;
+
+impl PackedLayoutGenerator {
+    /// Create a new packed layout generator.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate a Rust struct with packed backing storage and `field()`/
+    /// `set_field()` accessors that mask and shift into it.
+    ///
+    /// Falls back to a `[u8; N]` backing store (accessed byte-by-byte) when
+    /// the layout is wider than 16 bytes and no single integer type fits.
+    pub fn generate_struct(&self, layout: &PackedLayout) -> String {
+        let mut result = String::new();
+        let backing_bytes = layout.backing_bytes();
+
+        result.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]\n");
+        result.push_str(&format!("pub struct {} {{\n", layout.struct_name));
+        match layout.backing_rust_type() {
+            Some(ty) => result.push_str(&format!("    bits: {},\n", ty)),
+            None => result.push_str(&format!("    bits: [u8; {}],\n", backing_bytes)),
+        }
+        result.push_str("}\n\n");
+
+        result.push_str(&format!("impl {} {{\n", layout.struct_name));
+        for field in layout.fields() {
+            result.push_str(&self.generate_getter(layout, field));
+            result.push_str(&self.generate_setter(layout, field));
+        }
+        result.push_str("}\n");
+
+        if layout.backing_rust_type().is_none() {
+            result.push('\n');
+            result.push_str(BIT_HELPERS_SOURCE);
+        }
+
+        result
+    }
+
+    fn generate_getter(&self, layout: &PackedLayout, field: &PackedField) -> String {
+        let mask = mask_for_bits(field.bits);
+        match layout.backing_rust_type() {
+            Some(ty) => format!(
+                "    pub fn {name}(&self) -> {ty} {{\n        (self.bits >> {offset}) & {mask:#x}\n    }}\n\n",
+                name = field.name,
+                ty = ty,
+                offset = field.bit_offset,
+                mask = mask,
+            ),
+            None => format!(
+                "    pub fn {name}(&self) -> u128 {{\n        read_bits(&self.bits, {offset}, {bits})\n    }}\n\n",
+                name = field.name,
+                offset = field.bit_offset,
+                bits = field.bits,
+            ),
+        }
+    }
+
+    fn generate_setter(&self, layout: &PackedLayout, field: &PackedField) -> String {
+        let mask = mask_for_bits(field.bits);
+        match layout.backing_rust_type() {
+            Some(ty) => format!(
+                "    pub fn set_{name}(&mut self, value: {ty}) {{\n        self.bits = (self.bits & !({mask:#x} << {offset})) | ((value & {mask:#x}) << {offset});\n    }}\n\n",
+                name = field.name,
+                ty = ty,
+                offset = field.bit_offset,
+                mask = mask,
+            ),
+            None => format!(
+                "    pub fn set_{name}(&mut self, value: u128) {{\n        write_bits(&mut self.bits, {offset}, {bits}, value);\n    }}\n\n",
+                name = field.name,
+                offset = field.bit_offset,
+                bits = field.bits,
+            ),
+        }
+    }
+
+    /// Generate a bit-addressed indexer for an array of sub-byte-stride
+    /// elements: `get(index)`/`set(index, value)` over a `Vec<u8>` backing
+    /// buffer, unpacking element `i` from bit offset `i * stride_bits`.
+    pub fn generate_array_indexer(&self, type_name: &str, array: &PackedArrayLayout) -> String {
+        format!(
+            "pub struct {type_name} {{\n    bits: Vec<u8>,\n    stride_bits: u32,\n}}\n\n\
+impl {type_name} {{\n\
+    pub fn new(count: usize) -> Self {{\n\
+        let stride_bits = {stride_bits};\n\
+        let backing_bytes = ((count as u64 * stride_bits as u64 + 7) / 8) as usize;\n\
+        Self {{ bits: vec![0u8; backing_bytes], stride_bits }}\n\
+    }}\n\n\
+    pub fn get(&self, index: usize) -> u128 {{\n\
+        let offset = index as u64 * self.stride_bits as u64;\n\
+        read_bits(&self.bits, offset, self.stride_bits)\n\
+    }}\n\n\
+    pub fn set(&mut self, index: usize, value: u128) {{\n\
+        let offset = index as u64 * self.stride_bits as u64;\n\
+        write_bits(&mut self.bits, offset, self.stride_bits, value);\n\
+    }}\n\
+}}\n\n{helpers}",
+            type_name = type_name,
+            stride_bits = array.stride_bits,
+            helpers = BIT_HELPERS_SOURCE,
+        )
+    }
+}
+
+impl Default for PackedLayoutGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Source of the `read_bits`/`write_bits` helpers emitted alongside any
+/// generated struct or indexer that backs its storage with a byte slice
+/// rather than a single integer (wide structs, and all arrays).
+const BIT_HELPERS_SOURCE: &str = "fn read_bits(bytes: &[u8], offset: u64, bits: u32) -> u128 {\n    let mut value: u128 = 0;\n    for i in 0..bits as u64 {\n        let bit_pos = offset + i;\n        let byte = bytes[(bit_pos / 8) as usize];\n        let bit = (byte >> (bit_pos % 8)) & 1;\n        value |= (bit as u128) << i;\n    }\n    value\n}\n\nfn write_bits(bytes: &mut [u8], offset: u64, bits: u32, value: u128) {\n    for i in 0..bits as u64 {\n        let bit_pos = offset + i;\n        let byte_index = (bit_pos / 8) as usize;\n        let bit_index = (bit_pos % 8) as u32;\n        let bit = ((value >> i) & 1) as u8;\n        bytes[byte_index] = (bytes[byte_index] & !(1 << bit_index)) | (bit << bit_index);\n    }\n}\n";
+
+/// Mask selecting the low `bits` bits (saturates to all-ones for `bits >= 128`).
+fn mask_for_bits(bits: u32) -> u128 {
+    if bits >= 128 {
+        u128::MAX
+    } else {
+        (1u128 << bits) - 1
+    }
+}
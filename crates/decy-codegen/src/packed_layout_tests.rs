@@ -0,0 +1,212 @@
+//! Tests for packed bitfield layout computation and generation (DECY-268).
+
+#[cfg(test)]
+mod tests {
+    use crate::packed_layout::{
+        BitFieldSpec, PackedArrayLayout, PackedLayout, PackedLayoutGenerator,
+    };
+
+    // ========================================================================
+    // PackedLayout offset/backing-size computation
+    // ========================================================================
+
+    #[test]
+    fn layout_assigns_sequential_bit_offsets() {
+        let specs = vec![
+            BitFieldSpec::new("a", 3),
+            BitFieldSpec::new("b", 5),
+            BitFieldSpec::new("c", 8),
+        ];
+        let layout = PackedLayout::new("Flags", &specs);
+
+        assert_eq!(layout.field("a").unwrap().bit_offset, 0);
+        assert_eq!(layout.field("b").unwrap().bit_offset, 3);
+        assert_eq!(layout.field("c").unwrap().bit_offset, 8);
+        assert_eq!(layout.total_bits(), 16);
+    }
+
+    #[test]
+    fn layout_unknown_field_returns_none() {
+        let specs = vec![BitFieldSpec::new("a", 3)];
+        let layout = PackedLayout::new("Flags", &specs);
+        assert!(layout.field("missing").is_none());
+    }
+
+    #[test]
+    fn layout_fields_preserves_declaration_order() {
+        let specs = vec![
+            BitFieldSpec::new("x", 1),
+            BitFieldSpec::new("y", 2),
+            BitFieldSpec::new("z", 3),
+        ];
+        let layout = PackedLayout::new("Rec", &specs);
+        let names: Vec<&str> = layout.fields().iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["x", "y", "z"]);
+    }
+
+    #[test]
+    fn backing_bytes_does_not_panic_on_non_byte_aligned_total() {
+        // A single 20-bit record: the failure mode called out in the request.
+        let specs = vec![BitFieldSpec::new("value", 20)];
+        let layout = PackedLayout::new("Record20", &specs);
+
+        assert_eq!(layout.total_bits(), 20);
+        assert_eq!(layout.backing_bytes(), 3); // ceil(20 / 8)
+    }
+
+    #[test]
+    fn backing_bytes_exact_multiple_of_eight() {
+        let specs = vec![BitFieldSpec::new("value", 16)];
+        let layout = PackedLayout::new("Record16", &specs);
+        assert_eq!(layout.backing_bytes(), 2);
+    }
+
+    #[test]
+    fn backing_bytes_single_bit_rounds_up_to_one_byte() {
+        let specs = vec![BitFieldSpec::new("flag", 1)];
+        let layout = PackedLayout::new("Flag", &specs);
+        assert_eq!(layout.backing_bytes(), 1);
+    }
+
+    #[test]
+    fn backing_rust_type_picks_narrowest_fit() {
+        assert_eq!(
+            PackedLayout::new("T", &[BitFieldSpec::new("f", 1)]).backing_rust_type(),
+            Some("u8")
+        );
+        assert_eq!(
+            PackedLayout::new("T", &[BitFieldSpec::new("f", 9)]).backing_rust_type(),
+            Some("u16")
+        );
+        assert_eq!(
+            PackedLayout::new("T", &[BitFieldSpec::new("f", 20)]).backing_rust_type(),
+            Some("u32")
+        );
+        assert_eq!(
+            PackedLayout::new("T", &[BitFieldSpec::new("f", 40)]).backing_rust_type(),
+            Some("u64")
+        );
+        assert_eq!(
+            PackedLayout::new("T", &[BitFieldSpec::new("f", 100)]).backing_rust_type(),
+            Some("u128")
+        );
+    }
+
+    #[test]
+    fn backing_rust_type_none_when_wider_than_sixteen_bytes() {
+        let layout = PackedLayout::new("Huge", &[BitFieldSpec::new("f", 200)]);
+        assert_eq!(layout.backing_bytes(), 25);
+        assert_eq!(layout.backing_rust_type(), None);
+    }
+
+    #[test]
+    fn empty_layout_has_zero_bits_and_zero_backing_bytes() {
+        let layout = PackedLayout::new("Empty", &[]);
+        assert_eq!(layout.total_bits(), 0);
+        assert_eq!(layout.backing_bytes(), 0);
+        assert!(layout.fields().is_empty());
+    }
+
+    // ========================================================================
+    // PackedArrayLayout: bit-addressed array indexing
+    // ========================================================================
+
+    #[test]
+    fn array_element_offset_scales_with_stride() {
+        let array = PackedArrayLayout::new(20);
+        assert_eq!(array.element_offset_bits(0), 0);
+        assert_eq!(array.element_offset_bits(1), 20);
+        assert_eq!(array.element_offset_bits(5), 100);
+    }
+
+    #[test]
+    fn array_backing_bytes_rounds_up_for_non_byte_stride() {
+        // 3 elements at 20 bits each = 60 bits = 7.5 bytes -> 8 bytes.
+        let array = PackedArrayLayout::new(20);
+        assert_eq!(array.backing_bytes(3), 8);
+    }
+
+    #[test]
+    fn array_backing_bytes_byte_aligned_stride() {
+        let array = PackedArrayLayout::new(8);
+        assert_eq!(array.backing_bytes(4), 4);
+    }
+
+    #[test]
+    fn array_backing_bytes_zero_elements() {
+        let array = PackedArrayLayout::new(20);
+        assert_eq!(array.backing_bytes(0), 0);
+    }
+
+    // ========================================================================
+    // PackedLayoutGenerator: struct + accessor generation
+    // ========================================================================
+
+    #[test]
+    fn generate_struct_uses_integer_backing_for_small_layout() {
+        let specs = vec![BitFieldSpec::new("flag1", 1), BitFieldSpec::new("flag2", 1)];
+        let layout = PackedLayout::new("Flags", &specs);
+        let gen = PackedLayoutGenerator::new();
+        let out = gen.generate_struct(&layout);
+
+        assert!(out.contains("pub struct Flags"));
+        assert!(out.contains("bits: u8,"));
+        assert!(out.contains("pub fn flag1(&self) -> u8"));
+        assert!(out.contains("pub fn set_flag1(&mut self, value: u8)"));
+        assert!(out.contains("pub fn flag2(&self) -> u8"));
+        assert!(!out.contains("read_bits"));
+    }
+
+    #[test]
+    fn generate_struct_emits_shift_by_bit_offset() {
+        let specs = vec![BitFieldSpec::new("a", 4), BitFieldSpec::new("b", 4)];
+        let layout = PackedLayout::new("Nibbles", &specs);
+        let gen = PackedLayoutGenerator::new();
+        let out = gen.generate_struct(&layout);
+
+        assert!(out.contains("(self.bits >> 0)"));
+        assert!(out.contains("(self.bits >> 4)"));
+    }
+
+    #[test]
+    fn generate_struct_falls_back_to_byte_array_backing_when_wide() {
+        let layout = PackedLayout::new("Wide", &[BitFieldSpec::new("payload", 200)]);
+        let gen = PackedLayoutGenerator::new();
+        let out = gen.generate_struct(&layout);
+
+        assert!(out.contains("bits: [u8; 25],"));
+        assert!(out.contains("fn read_bits("));
+        assert!(out.contains("fn write_bits("));
+        assert!(out.contains("pub fn payload(&self) -> u128"));
+    }
+
+    #[test]
+    fn generate_struct_includes_derive_and_default() {
+        let layout = PackedLayout::new("Small", &[BitFieldSpec::new("f", 3)]);
+        let gen = PackedLayoutGenerator::new();
+        let out = gen.generate_struct(&layout);
+        assert!(out.starts_with("#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]"));
+    }
+
+    #[test]
+    fn generate_array_indexer_includes_stride_and_helpers() {
+        let array = PackedArrayLayout::new(20);
+        let gen = PackedLayoutGenerator::new();
+        let out = gen.generate_array_indexer("Records20", &array);
+
+        assert!(out.contains("pub struct Records20"));
+        assert!(out.contains("let stride_bits = 20;"));
+        assert!(out.contains("pub fn get(&self, index: usize) -> u128"));
+        assert!(out.contains("pub fn set(&mut self, index: usize, value: u128)"));
+        assert!(out.contains("fn read_bits("));
+        assert!(out.contains("fn write_bits("));
+    }
+
+    #[test]
+    fn generator_default_matches_new() {
+        let a = PackedLayoutGenerator::new();
+        let b = PackedLayoutGenerator::default();
+        let layout = PackedLayout::new("T", &[BitFieldSpec::new("f", 3)]);
+        assert_eq!(a.generate_struct(&layout), b.generate_struct(&layout));
+    }
+}
@@ -0,0 +1,181 @@
+//! Symbolic, target-width-dependent size expressions (DECY-269).
+//!
+//! C array and struct sizes occasionally depend on the platform's pointer
+//! width (`sizeof(void*)`, `sizeof(long)`, or a `#if __SIZEOF_POINTER__ == 8`
+//! branch picking between two hand-written constants). Folding such a
+//! dimension to a single literal at transpile time silently bakes in
+//! whichever width the transpiler happened to run on. [`SizeExpr`] keeps
+//! these dimensions symbolic instead, and [`SizeExprGenerator`] emits either
+//! a `core::mem::size_of::<usize>()`-based expression (when the original
+//! computation is a genuine multiple of the pointer width) or a pair of
+//! `#[cfg(target_pointer_width = "32"/"64")]` constants (when the original
+//! C hard-coded two platform-specific literals with no common formula).
+//!
+//! [`detect_pointer_width_size_expr`] is called from `CodeGenerator`'s
+//! `sizeof(T)` and `n * sizeof(T)` expression codegen, ahead of the default
+//! `map_sizeof_type`-based fold, so a recognized pointer-width type emits the
+//! symbolic form instead of a baked-in literal (or, for a type name like
+//! `long`/`void *` that doesn't map to a concrete Rust type, invalid code).
+//! `PlatformLiteral` (the `#if __SIZEOF_POINTER__ == 8` shape) has no HIR
+//! representation to detect from yet, so `emit_const` for it is unused by
+//! any call site today - follow-on work once that conditional-compilation
+//! shape is recognized during parsing.
+
+/// A size that may be a plain literal or one that depends on the target's
+/// pointer width, kept symbolic so code-gen can reproduce it faithfully
+/// rather than folding it to whatever width the transpiler ran on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SizeExpr {
+    /// A size with no platform dependence.
+    Literal(u64),
+    /// `sizeof(void*)` / `sizeof(long)`: the target's pointer width in bytes.
+    PointerWidthBytes,
+    /// `factor * sizeof(void*)`, e.g. `N * sizeof(long)`.
+    ScaledByPointerWidth(u64),
+    /// Two independently hard-coded platform literals with no common
+    /// formula relating them (e.g. a `#if __SIZEOF_POINTER__ == 8` branch
+    /// picking between `16` and `8`), requiring a `cfg`-gated pair.
+    PlatformLiteral { width32: u64, width64: u64 },
+}
+
+impl SizeExpr {
+    /// The concrete value this expression resolves to on a given pointer
+    /// width, or `None` for a width other than 32/64 (this crate only
+    /// targets those two).
+    pub fn resolve(&self, pointer_width_bits: u32) -> Option<u64> {
+        let pointer_width_bytes = match pointer_width_bits {
+            32 => 4,
+            64 => 8,
+            _ => return None,
+        };
+        match self {
+            SizeExpr::Literal(n) => Some(*n),
+            SizeExpr::PointerWidthBytes => Some(pointer_width_bytes),
+            SizeExpr::ScaledByPointerWidth(factor) => Some(factor * pointer_width_bytes),
+            SizeExpr::PlatformLiteral { width32, width64 } => match pointer_width_bits {
+                32 => Some(*width32),
+                64 => Some(*width64),
+                _ => None,
+            },
+        }
+    }
+
+    /// True when this expression's value varies with the target pointer
+    /// width (i.e. it is not a plain [`SizeExpr::Literal`]).
+    pub fn is_platform_dependent(&self) -> bool {
+        !matches!(self, SizeExpr::Literal(_))
+    }
+}
+
+/// Generates Rust source for a [`SizeExpr`].
+pub struct SizeExprGenerator;
+
+impl SizeExprGenerator {
+    /// Create a new size expression generator.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Emit a single Rust expression (no `cfg`) for sizes that can be
+    /// computed uniformly from `core::mem::size_of::<usize>()`. Returns
+    /// `None` for [`SizeExpr::PlatformLiteral`], which has no such formula
+    /// and must be emitted via [`Self::emit_const`] instead.
+    pub fn emit_expr(&self, expr: &SizeExpr) -> Option<String> {
+        match expr {
+            SizeExpr::Literal(n) => Some(n.to_string()),
+            SizeExpr::PointerWidthBytes => Some("core::mem::size_of::<usize>()".to_string()),
+            SizeExpr::ScaledByPointerWidth(1) => Some("core::mem::size_of::<usize>()".to_string()),
+            SizeExpr::ScaledByPointerWidth(factor) => {
+                Some(format!("{} * core::mem::size_of::<usize>()", factor))
+            }
+            SizeExpr::PlatformLiteral { .. } => None,
+        }
+    }
+
+    /// Emit a `pub const NAME: usize = ...;` declaration for `expr`.
+    ///
+    /// When `expr` has a uniform symbolic formula ([`emit_expr`] returns
+    /// `Some`), this is a single const. For [`SizeExpr::PlatformLiteral`],
+    /// which has no common formula between widths, this instead emits a
+    /// `#[cfg(target_pointer_width = "32")]` / `"64"` pair of consts with
+    /// the same name, matching how the original C conditional compilation
+    /// picked between the two hard-coded values.
+    ///
+    /// [`emit_expr`]: Self::emit_expr
+    pub fn emit_const(&self, name: &str, expr: &SizeExpr) -> String {
+        if let Some(rhs) = self.emit_expr(expr) {
+            return format!("pub const {}: usize = {};\n", name, rhs);
+        }
+
+        if let SizeExpr::PlatformLiteral { width32, width64 } = expr {
+            format!(
+                "#[cfg(target_pointer_width = \"32\")]\npub const {name}: usize = {width32};\n\
+#[cfg(target_pointer_width = \"64\")]\npub const {name}: usize = {width64};\n",
+                name = name,
+                width32 = width32,
+                width64 = width64,
+            )
+        } else {
+            unreachable!("emit_expr only returns None for PlatformLiteral")
+        }
+    }
+}
+
+impl Default for SizeExprGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// C type-name spellings whose `sizeof` matches the target's pointer width
+/// on essentially every platform this transpiler targets.
+const POINTER_WIDTH_TYPE_NAMES: &[&str] = &[
+    "void *",
+    "void*",
+    "long",
+    "unsigned long",
+    "size_t",
+    "intptr_t",
+    "uintptr_t",
+];
+
+/// Recognize a `sizeof(T)` or `n * sizeof(T)` HIR expression where `T` is a
+/// pointer-width-sized C type, and convert it to a symbolic [`SizeExpr`]
+/// instead of the literal `std::mem::size_of::<i32>()`-style fold codegen
+/// otherwise applies. Returns `None` for anything else, so callers can fall
+/// back to their existing literal-folding behavior unchanged.
+pub fn detect_pointer_width_size_expr(expr: &decy_hir::HirExpression) -> Option<SizeExpr> {
+    use decy_hir::{BinaryOperator, HirExpression};
+
+    match expr {
+        HirExpression::Sizeof { type_name } if is_pointer_width_type(type_name) => {
+            Some(SizeExpr::PointerWidthBytes)
+        }
+        HirExpression::BinaryOp {
+            op: BinaryOperator::Multiply,
+            left,
+            right,
+        } => {
+            if let HirExpression::Sizeof { type_name } = right.as_ref() {
+                if is_pointer_width_type(type_name) {
+                    if let HirExpression::IntLiteral(n) = left.as_ref() {
+                        return Some(SizeExpr::ScaledByPointerWidth(*n as u64));
+                    }
+                }
+            }
+            if let HirExpression::Sizeof { type_name } = left.as_ref() {
+                if is_pointer_width_type(type_name) {
+                    if let HirExpression::IntLiteral(n) = right.as_ref() {
+                        return Some(SizeExpr::ScaledByPointerWidth(*n as u64));
+                    }
+                }
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn is_pointer_width_type(type_name: &str) -> bool {
+    POINTER_WIDTH_TYPE_NAMES.contains(&type_name.trim())
+}
@@ -0,0 +1,217 @@
+//! Tests for symbolic, target-width-dependent size expressions (DECY-269).
+
+#[cfg(test)]
+mod tests {
+    use crate::size_expr::{detect_pointer_width_size_expr, SizeExpr, SizeExprGenerator};
+    use decy_hir::{BinaryOperator, HirExpression};
+
+    // ========================================================================
+    // SizeExpr::resolve / is_platform_dependent
+    // ========================================================================
+
+    #[test]
+    fn literal_resolves_the_same_on_both_widths() {
+        let expr = SizeExpr::Literal(16);
+        assert_eq!(expr.resolve(32), Some(16));
+        assert_eq!(expr.resolve(64), Some(16));
+        assert!(!expr.is_platform_dependent());
+    }
+
+    #[test]
+    fn pointer_width_bytes_resolves_per_target() {
+        let expr = SizeExpr::PointerWidthBytes;
+        assert_eq!(expr.resolve(32), Some(4));
+        assert_eq!(expr.resolve(64), Some(8));
+        assert!(expr.is_platform_dependent());
+    }
+
+    #[test]
+    fn scaled_by_pointer_width_resolves_per_target() {
+        let expr = SizeExpr::ScaledByPointerWidth(3);
+        assert_eq!(expr.resolve(32), Some(12));
+        assert_eq!(expr.resolve(64), Some(24));
+    }
+
+    #[test]
+    fn platform_literal_resolves_to_its_own_value_per_width() {
+        let expr = SizeExpr::PlatformLiteral {
+            width32: 8,
+            width64: 16,
+        };
+        assert_eq!(expr.resolve(32), Some(8));
+        assert_eq!(expr.resolve(64), Some(16));
+        assert!(expr.is_platform_dependent());
+    }
+
+    #[test]
+    fn resolve_returns_none_for_unsupported_width() {
+        assert_eq!(SizeExpr::PointerWidthBytes.resolve(16), None);
+    }
+
+    // ========================================================================
+    // SizeExprGenerator: expression / const emission
+    // ========================================================================
+
+    #[test]
+    fn emit_expr_literal() {
+        let gen = SizeExprGenerator::new();
+        assert_eq!(
+            gen.emit_expr(&SizeExpr::Literal(42)),
+            Some("42".to_string())
+        );
+    }
+
+    #[test]
+    fn emit_expr_pointer_width_bytes() {
+        let gen = SizeExprGenerator::new();
+        assert_eq!(
+            gen.emit_expr(&SizeExpr::PointerWidthBytes),
+            Some("core::mem::size_of::<usize>()".to_string())
+        );
+    }
+
+    #[test]
+    fn emit_expr_scaled_by_one_collapses_to_plain_size_of() {
+        let gen = SizeExprGenerator::new();
+        assert_eq!(
+            gen.emit_expr(&SizeExpr::ScaledByPointerWidth(1)),
+            Some("core::mem::size_of::<usize>()".to_string())
+        );
+    }
+
+    #[test]
+    fn emit_expr_scaled_by_factor() {
+        let gen = SizeExprGenerator::new();
+        assert_eq!(
+            gen.emit_expr(&SizeExpr::ScaledByPointerWidth(4)),
+            Some("4 * core::mem::size_of::<usize>()".to_string())
+        );
+    }
+
+    #[test]
+    fn emit_expr_platform_literal_has_no_uniform_formula() {
+        let gen = SizeExprGenerator::new();
+        let expr = SizeExpr::PlatformLiteral {
+            width32: 8,
+            width64: 16,
+        };
+        assert_eq!(gen.emit_expr(&expr), None);
+    }
+
+    #[test]
+    fn emit_const_for_symbolic_expr_is_a_single_const() {
+        let gen = SizeExprGenerator::new();
+        let out = gen.emit_const("BUF_LEN", &SizeExpr::ScaledByPointerWidth(4));
+        assert_eq!(
+            out,
+            "pub const BUF_LEN: usize = 4 * core::mem::size_of::<usize>();\n"
+        );
+    }
+
+    #[test]
+    fn emit_const_for_platform_literal_emits_cfg_gated_pair() {
+        let gen = SizeExprGenerator::new();
+        let expr = SizeExpr::PlatformLiteral {
+            width32: 8,
+            width64: 16,
+        };
+        let out = gen.emit_const("BUF_LEN", &expr);
+
+        assert!(out.contains("#[cfg(target_pointer_width = \"32\")]"));
+        assert!(out.contains("pub const BUF_LEN: usize = 8;"));
+        assert!(out.contains("#[cfg(target_pointer_width = \"64\")]"));
+        assert!(out.contains("pub const BUF_LEN: usize = 16;"));
+    }
+
+    #[test]
+    fn generator_default_matches_new() {
+        let a = SizeExprGenerator::new();
+        let b = SizeExprGenerator::default();
+        assert_eq!(
+            a.emit_const("X", &SizeExpr::Literal(3)),
+            b.emit_const("X", &SizeExpr::Literal(3))
+        );
+    }
+
+    // ========================================================================
+    // detect_pointer_width_size_expr
+    // ========================================================================
+
+    #[test]
+    fn detects_bare_sizeof_void_pointer() {
+        let expr = HirExpression::Sizeof {
+            type_name: "void *".to_string(),
+        };
+        assert_eq!(
+            detect_pointer_width_size_expr(&expr),
+            Some(SizeExpr::PointerWidthBytes)
+        );
+    }
+
+    #[test]
+    fn detects_bare_sizeof_long() {
+        let expr = HirExpression::Sizeof {
+            type_name: "long".to_string(),
+        };
+        assert_eq!(
+            detect_pointer_width_size_expr(&expr),
+            Some(SizeExpr::PointerWidthBytes)
+        );
+    }
+
+    #[test]
+    fn detects_n_times_sizeof_long() {
+        let expr = HirExpression::BinaryOp {
+            left: Box::new(HirExpression::IntLiteral(4)),
+            op: BinaryOperator::Multiply,
+            right: Box::new(HirExpression::Sizeof {
+                type_name: "long".to_string(),
+            }),
+        };
+        assert_eq!(
+            detect_pointer_width_size_expr(&expr),
+            Some(SizeExpr::ScaledByPointerWidth(4))
+        );
+    }
+
+    #[test]
+    fn detects_sizeof_size_t_times_n_with_sizeof_first() {
+        let expr = HirExpression::BinaryOp {
+            left: Box::new(HirExpression::Sizeof {
+                type_name: "size_t".to_string(),
+            }),
+            op: BinaryOperator::Multiply,
+            right: Box::new(HirExpression::IntLiteral(8)),
+        };
+        assert_eq!(
+            detect_pointer_width_size_expr(&expr),
+            Some(SizeExpr::ScaledByPointerWidth(8))
+        );
+    }
+
+    #[test]
+    fn ignores_sizeof_of_non_pointer_width_type() {
+        let expr = HirExpression::Sizeof {
+            type_name: "int".to_string(),
+        };
+        assert_eq!(detect_pointer_width_size_expr(&expr), None);
+    }
+
+    #[test]
+    fn ignores_multiply_with_no_sizeof_operand() {
+        let expr = HirExpression::BinaryOp {
+            left: Box::new(HirExpression::IntLiteral(4)),
+            op: BinaryOperator::Multiply,
+            right: Box::new(HirExpression::IntLiteral(8)),
+        };
+        assert_eq!(detect_pointer_width_size_expr(&expr), None);
+    }
+
+    #[test]
+    fn ignores_unrelated_expression() {
+        assert_eq!(
+            detect_pointer_width_size_expr(&HirExpression::IntLiteral(7)),
+            None
+        );
+    }
+}
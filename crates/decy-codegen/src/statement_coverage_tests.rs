@@ -749,6 +749,31 @@ fn test_var_decl_char_array_string_init() {
     assert!(code.contains("*b\"hello\\0\""));
 }
 
+#[test]
+fn test_var_decl_pointer_from_array_compound_literal_is_slice_ref() {
+    let codegen = CodeGenerator::new();
+    // int *arr = (int[]){1, 2, 3} → let arr: &[i32] = { let __cl = ...; &__cl[..] };
+    let func = make_func_with_statements(vec![HirStatement::VariableDeclaration {
+        name: "arr".to_string(),
+        var_type: HirType::Pointer(Box::new(HirType::Int)),
+        initializer: Some(HirExpression::CompoundLiteral {
+            literal_type: HirType::Array {
+                element_type: Box::new(HirType::Int),
+                size: None,
+            },
+            initializers: vec![
+                HirExpression::IntLiteral(1),
+                HirExpression::IntLiteral(2),
+                HirExpression::IntLiteral(3),
+            ],
+        }),
+    }]);
+    let code = codegen.generate_function(&func);
+    assert!(code.contains("&[i32]"), "got: {}", code);
+    assert!(code.contains("let __cl =") && code.contains("&__cl[..]"), "got: {}", code);
+    assert!(!code.contains("mut arr"), "slice-ref binding should not be mut, got: {}", code);
+}
+
 #[test]
 fn test_var_decl_reserved_keyword_name() {
     let codegen = CodeGenerator::new();
@@ -138,3 +138,43 @@ fn test_struct_with_reference_field() {
     assert!(code.contains("RefStruct<"));
     assert!(code.contains("data: &"));
 }
+
+#[test]
+fn test_generate_struct_routes_all_bitfields_through_packed_layout() {
+    let codegen = CodeGenerator::new();
+
+    // `struct Flags { unsigned ready : 1; unsigned mode : 3; };`
+    let fields = vec![
+        HirStructField::new("ready".to_string(), HirType::Int).with_bit_width(1),
+        HirStructField::new("mode".to_string(), HirType::Int).with_bit_width(3),
+    ];
+
+    let flags_struct = HirStruct::new("Flags".to_string(), fields);
+    let code = codegen.generate_struct(&flags_struct);
+
+    assert!(code.contains("struct Flags"));
+    assert!(code.contains("bits: u8"), "should pick the narrowest backing int: {code}");
+    assert!(code.contains("fn ready"));
+    assert!(code.contains("fn set_mode"));
+    // A pure-bitfield struct doesn't go through the ordinary field-by-field
+    // path at all, so it shouldn't get a plain `ready: i32` field.
+    assert!(!code.contains("ready: i32"));
+}
+
+#[test]
+fn test_generate_struct_with_mixed_fields_ignores_bit_width() {
+    let codegen = CodeGenerator::new();
+
+    // A struct with a bitfield alongside an ordinary field isn't packed yet
+    // - it still falls through to the regular field-by-field codegen.
+    let fields = vec![
+        HirStructField::new("flag".to_string(), HirType::Int).with_bit_width(1),
+        HirStructField::new("value".to_string(), HirType::Int),
+    ];
+
+    let mixed_struct = HirStruct::new("Mixed".to_string(), fields);
+    let code = codegen.generate_struct(&mixed_struct);
+
+    assert!(code.contains("flag: i32"));
+    assert!(code.contains("value: i32"));
+}
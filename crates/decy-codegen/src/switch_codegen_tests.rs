@@ -3,7 +3,7 @@
 //! Tests for generating Rust match expressions from C switch/case statements.
 
 use super::*;
-use decy_hir::{BinaryOperator, HirExpression, HirStatement, SwitchCase};
+use decy_hir::{BinaryOperator, HirEnum, HirEnumVariant, HirExpression, HirStatement, SwitchCase};
 
 #[test]
 fn test_generate_simple_switch() {
@@ -209,3 +209,307 @@ fn test_generate_switch_with_return() {
     assert!(code.contains("return 42"));
     assert!(code.contains("return 0"));
 }
+
+// ============================================================================
+// DECY-262: Enum-aware exhaustive match generation
+// ============================================================================
+
+#[test]
+fn test_enum_switch_drops_wildcard_when_exhaustive() {
+    // C: enum State { INIT, RUNNING, DONE };
+    //    switch (state) { case INIT: ...; case RUNNING: ...; case DONE: ...; }
+    let state_enum = HirEnum::new(
+        "State".to_string(),
+        vec![
+            HirEnumVariant::new("INIT".to_string(), None),
+            HirEnumVariant::new("RUNNING".to_string(), None),
+            HirEnumVariant::new("DONE".to_string(), None),
+        ],
+    );
+
+    let switch_stmt = HirStatement::Switch {
+        condition: HirExpression::Variable("state".to_string()),
+        cases: vec![
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(0)),
+                body: vec![HirStatement::Break],
+            },
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(1)),
+                body: vec![HirStatement::Break],
+            },
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(2)),
+                body: vec![HirStatement::Break],
+            },
+        ],
+        default_case: None,
+    };
+
+    let codegen = CodeGenerator::new();
+    let code = codegen.generate_statement_with_enum_context(
+        &switch_stmt,
+        &[("state".to_string(), state_enum)],
+    );
+
+    assert!(code.contains("State::INIT =>"));
+    assert!(code.contains("State::RUNNING =>"));
+    assert!(code.contains("State::DONE =>"));
+    // Every variant is covered, so the wildcard must be omitted entirely.
+    assert!(!code.contains("_ =>"));
+}
+
+#[test]
+fn test_enum_switch_auto_fills_missing_variants_without_default() {
+    // C: switch (state) { case INIT: ...; } -- RUNNING/DONE are uncovered, no default.
+    let state_enum = HirEnum::new(
+        "State".to_string(),
+        vec![
+            HirEnumVariant::new("INIT".to_string(), None),
+            HirEnumVariant::new("RUNNING".to_string(), None),
+            HirEnumVariant::new("DONE".to_string(), None),
+        ],
+    );
+
+    let switch_stmt = HirStatement::Switch {
+        condition: HirExpression::Variable("state".to_string()),
+        cases: vec![SwitchCase {
+            value: Some(HirExpression::IntLiteral(0)),
+            body: vec![HirStatement::Break],
+        }],
+        default_case: None,
+    };
+
+    let codegen = CodeGenerator::new();
+    let code = codegen.generate_statement_with_enum_context(
+        &switch_stmt,
+        &[("state".to_string(), state_enum)],
+    );
+
+    assert!(code.contains("State::INIT =>"));
+    assert!(code.contains("State::RUNNING => {}"));
+    assert!(code.contains("State::DONE => {}"));
+    assert!(code.contains("auto-filled"));
+    assert!(!code.contains("_ =>"));
+}
+
+#[test]
+fn test_enum_switch_keeps_wildcard_when_default_covers_remainder() {
+    // C: switch (state) { case INIT: ...; default: ...; } -- default still needed.
+    let state_enum = HirEnum::new(
+        "State".to_string(),
+        vec![
+            HirEnumVariant::new("INIT".to_string(), None),
+            HirEnumVariant::new("RUNNING".to_string(), None),
+        ],
+    );
+
+    let switch_stmt = HirStatement::Switch {
+        condition: HirExpression::Variable("state".to_string()),
+        cases: vec![SwitchCase {
+            value: Some(HirExpression::IntLiteral(0)),
+            body: vec![HirStatement::Break],
+        }],
+        default_case: Some(vec![HirStatement::Return(Some(HirExpression::IntLiteral(
+            -1,
+        )))]),
+    };
+
+    let codegen = CodeGenerator::new();
+    let code = codegen.generate_statement_with_enum_context(
+        &switch_stmt,
+        &[("state".to_string(), state_enum)],
+    );
+
+    assert!(code.contains("State::INIT =>"));
+    assert!(code.contains("_ =>"));
+    assert!(code.contains("return -1"));
+}
+
+// ============================================================================
+// DECY-263: Deduplicate overlapping/duplicate switch case labels
+// ============================================================================
+
+#[test]
+fn test_duplicate_case_labels_keep_first_occurrence() {
+    // C: switch (x) { case 5: return 1; case 5: return 2; default: return 0; }
+    let switch_stmt = HirStatement::Switch {
+        condition: HirExpression::Variable("x".to_string()),
+        cases: vec![
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(5)),
+                body: vec![HirStatement::Return(Some(HirExpression::IntLiteral(1)))],
+            },
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(5)),
+                body: vec![HirStatement::Return(Some(HirExpression::IntLiteral(2)))],
+            },
+        ],
+        default_case: Some(vec![HirStatement::Return(Some(HirExpression::IntLiteral(
+            0,
+        )))]),
+    };
+
+    let codegen = CodeGenerator::new();
+    let code = codegen.generate_statement(&switch_stmt);
+
+    // Only the first occurrence survives - the generated match must compile.
+    assert!(code.contains("return 1"));
+    assert!(!code.contains("return 2"));
+}
+
+#[test]
+fn test_duplicate_case_labels_emit_diagnostic_comment() {
+    let switch_stmt = HirStatement::Switch {
+        condition: HirExpression::Variable("x".to_string()),
+        cases: vec![
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(5)),
+                body: vec![HirStatement::Break],
+            },
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(5)),
+                body: vec![HirStatement::Break],
+            },
+        ],
+        default_case: None,
+    };
+
+    let codegen = CodeGenerator::new();
+    let code = codegen.generate_statement(&switch_stmt);
+
+    assert!(code.contains("// decy: warning: duplicate switch case `5`"));
+}
+
+#[test]
+fn test_dedup_switch_cases_returns_structured_diagnostic() {
+    // DECY-270: `dedup_switch_cases` reports dropped duplicates as a real
+    // `Diagnostic`, not a bare string - assert the structured fields
+    // directly rather than only the comment text it's later rendered into.
+    let cases = vec![
+        SwitchCase {
+            value: Some(HirExpression::IntLiteral(5)),
+            body: vec![HirStatement::Break],
+        },
+        SwitchCase {
+            value: Some(HirExpression::IntLiteral(5)),
+            body: vec![HirStatement::Break],
+        },
+    ];
+
+    let (deduped, diagnostics) = CodeGenerator::dedup_switch_cases(&cases);
+
+    assert_eq!(deduped.len(), 1);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].severity, decy_parser::diagnostic::Severity::Warning);
+    assert!(diagnostics[0].message.contains("duplicate switch case `5`"));
+    assert!(diagnostics[0].line.is_none());
+}
+
+#[test]
+fn test_non_duplicate_case_labels_are_unaffected() {
+    let switch_stmt = HirStatement::Switch {
+        condition: HirExpression::Variable("x".to_string()),
+        cases: vec![
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(1)),
+                body: vec![HirStatement::Break],
+            },
+            SwitchCase {
+                value: Some(HirExpression::IntLiteral(2)),
+                body: vec![HirStatement::Break],
+            },
+        ],
+        default_case: None,
+    };
+
+    let codegen = CodeGenerator::new();
+    let code = codegen.generate_statement(&switch_stmt);
+
+    assert!(!code.contains("duplicate switch case"));
+    assert!(code.contains("1 =>"));
+    assert!(code.contains("2 =>"));
+}
+
+// ============================================================================
+// DECY-263: Collapse contiguous switch fallthrough groups
+// ============================================================================
+
+fn contiguous_fallthrough_switch() -> HirStatement {
+    // C: switch (x) { case 10: case 11: ... case 16: y = 1; break; default: y = 0; break; }
+    let mut cases: Vec<SwitchCase> = (10..16)
+        .map(|n| SwitchCase {
+            value: Some(HirExpression::IntLiteral(n)),
+            body: vec![],
+        })
+        .collect();
+    cases.push(SwitchCase {
+        value: Some(HirExpression::IntLiteral(16)),
+        body: vec![
+            HirStatement::Assignment {
+                target: "y".to_string(),
+                value: HirExpression::IntLiteral(1),
+            },
+            HirStatement::Break,
+        ],
+    });
+
+    HirStatement::Switch {
+        condition: HirExpression::Variable("x".to_string()),
+        cases,
+        default_case: Some(vec![HirStatement::Assignment {
+            target: "y".to_string(),
+            value: HirExpression::IntLiteral(0),
+        }]),
+    }
+}
+
+#[test]
+fn test_contiguous_fallthrough_collapses_to_range_pattern_by_default() {
+    let switch_stmt = contiguous_fallthrough_switch();
+
+    let codegen = CodeGenerator::new();
+    let code = codegen.generate_statement(&switch_stmt);
+
+    assert!(code.contains("10..=16 =>"));
+    assert!(!code.contains("10 =>"));
+    assert!(code.contains("y = 1"));
+}
+
+#[test]
+fn test_contiguous_fallthrough_unrolls_when_requested() {
+    let switch_stmt = contiguous_fallthrough_switch();
+
+    let codegen = CodeGenerator::with_switch_lowering_mode(SwitchLoweringMode::Unrolled);
+    let code = codegen.generate_statement(&switch_stmt);
+
+    assert!(code.contains("10 | 11 | 12 | 13 | 14 | 15 | 16 =>"));
+    assert!(!code.contains("10..=16"));
+}
+
+#[test]
+fn test_wide_span_stays_range_pattern_even_when_unrolled() {
+    // A span wider than MAX_SWITCH_UNROLL_WIDTH must never unroll.
+    let mut cases: Vec<SwitchCase> = (0..20)
+        .map(|n| SwitchCase {
+            value: Some(HirExpression::IntLiteral(n)),
+            body: vec![],
+        })
+        .collect();
+    cases.push(SwitchCase {
+        value: Some(HirExpression::IntLiteral(20)),
+        body: vec![HirStatement::Break],
+    });
+
+    let switch_stmt = HirStatement::Switch {
+        condition: HirExpression::Variable("x".to_string()),
+        cases,
+        default_case: None,
+    };
+
+    let codegen = CodeGenerator::with_switch_lowering_mode(SwitchLoweringMode::Unrolled);
+    let code = codegen.generate_statement(&switch_stmt);
+
+    assert!(code.contains("0..=20 =>"));
+    assert!(!code.contains("0 | 1"));
+}
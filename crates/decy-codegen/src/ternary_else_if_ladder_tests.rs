@@ -0,0 +1,111 @@
+//! Tests for collapsing right-associative ternary chains into `else if`
+//! ladders (DECY-273), e.g. `c >= 100 ? "gas" : c < 0 ? "solid" : "liquid"`.
+
+#[cfg(test)]
+mod tests {
+    use crate::CodeGenerator;
+    use decy_hir::{HirExpression, HirFunction, HirParameter, HirStatement, HirType};
+
+    #[test]
+    fn chained_ternary_collapses_to_else_if_ladder() {
+        let codegen = CodeGenerator::new();
+        // c >= 100 ? 1 : (c < 0 ? 2 : 3)
+        let inner = HirExpression::Ternary {
+            condition: Box::new(HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::LessThan,
+                left: Box::new(HirExpression::Variable("c".to_string())),
+                right: Box::new(HirExpression::IntLiteral(0)),
+            }),
+            then_expr: Box::new(HirExpression::IntLiteral(2)),
+            else_expr: Box::new(HirExpression::IntLiteral(3)),
+        };
+        let func = HirFunction::new_with_body(
+            "classify".to_string(),
+            HirType::Int,
+            vec![HirParameter::new("c".to_string(), HirType::Int)],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::BinaryOp {
+                    op: decy_hir::BinaryOperator::GreaterEqual,
+                    left: Box::new(HirExpression::Variable("c".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(100)),
+                }),
+                then_expr: Box::new(HirExpression::IntLiteral(1)),
+                else_expr: Box::new(inner),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        // A true ladder has exactly one `else {` (closing the whole chain),
+        // not one per nesting level.
+        assert_eq!(code.matches("else {").count(), 1);
+        assert!(code.contains("} else if"));
+    }
+
+    #[test]
+    fn three_way_ternary_chain_flattens_fully() {
+        let codegen = CodeGenerator::new();
+        // a ? 1 : b ? 2 : c ? 3 : 4
+        let level3 = HirExpression::Ternary {
+            condition: Box::new(HirExpression::Variable("c".to_string())),
+            then_expr: Box::new(HirExpression::IntLiteral(3)),
+            else_expr: Box::new(HirExpression::IntLiteral(4)),
+        };
+        let level2 = HirExpression::Ternary {
+            condition: Box::new(HirExpression::Variable("b".to_string())),
+            then_expr: Box::new(HirExpression::IntLiteral(2)),
+            else_expr: Box::new(level3),
+        };
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("a".to_string(), HirType::Int),
+                HirParameter::new("b".to_string(), HirType::Int),
+                HirParameter::new("c".to_string(), HirType::Int),
+            ],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("a".to_string())),
+                then_expr: Box::new(HirExpression::IntLiteral(1)),
+                else_expr: Box::new(level2),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert_eq!(code.matches("else if").count(), 2);
+        assert_eq!(code.matches("else {").count(), 1);
+    }
+
+    #[test]
+    fn ternary_with_else_arm_wrapped_in_another_expression_keeps_nested_braces() {
+        let codegen = CodeGenerator::new();
+        // a ? 1 : -(b ? 2 : 3) - the inner ternary is wrapped in a unary
+        // negation, so it is not a *direct* else-arm and must not collapse.
+        let wrapped = HirExpression::UnaryOp {
+            op: decy_hir::UnaryOperator::Minus,
+            operand: Box::new(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("b".to_string())),
+                then_expr: Box::new(HirExpression::IntLiteral(2)),
+                else_expr: Box::new(HirExpression::IntLiteral(3)),
+            }),
+        };
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("a".to_string(), HirType::Int),
+                HirParameter::new("b".to_string(), HirType::Int),
+            ],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("a".to_string())),
+                then_expr: Box::new(HirExpression::IntLiteral(1)),
+                else_expr: Box::new(wrapped),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        // Both the outer and the wrapped inner ternary keep their own
+        // braces - two separate `if`/`else` blocks, not a collapsed ladder.
+        assert_eq!(code.matches("else {").count(), 2);
+        assert!(!code.contains("else if"));
+    }
+}
@@ -0,0 +1,96 @@
+//! Tests for the GNU omitted-middle `?:` elvis operator (DECY-274):
+//! `a ?: b` must evaluate `a` exactly once.
+
+#[cfg(test)]
+mod tests {
+    use crate::CodeGenerator;
+    use decy_hir::{HirExpression, HirFunction, HirParameter, HirStatement, HirType};
+
+    fn call(name: &str) -> HirExpression {
+        HirExpression::FunctionCall {
+            function: name.to_string(),
+            arguments: vec![],
+        }
+    }
+
+    #[test]
+    fn elvis_with_side_effecting_condition_evaluates_it_once() {
+        let codegen = CodeGenerator::new();
+        // get() ?: 0  →  get() ? get() : 0 in HIR (then-arm == condition)
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(call("get")),
+                then_expr: Box::new(call("get")),
+                else_expr: Box::new(HirExpression::IntLiteral(0)),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert_eq!(
+            code.matches("get()").count(),
+            1,
+            "get() must run exactly once: {code}"
+        );
+        assert!(code.contains("let "));
+    }
+
+    #[test]
+    fn elvis_on_integer_condition_tests_nonzero() {
+        let codegen = CodeGenerator::new();
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![HirParameter::new("x".to_string(), HirType::Int)],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("x".to_string())),
+                then_expr: Box::new(HirExpression::Variable("x".to_string())),
+                else_expr: Box::new(HirExpression::IntLiteral(0)),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert!(code.contains("!= 0"));
+        assert_eq!(code.matches("let __elvis = x").count(), 1);
+    }
+
+    #[test]
+    fn elvis_temp_name_avoids_colliding_with_existing_variable() {
+        let codegen = CodeGenerator::new();
+        // A parameter named `__elvis` is already in scope, so the fresh
+        // temporary must pick a different name.
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![HirParameter::new("__elvis".to_string(), HirType::Int)],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("x".to_string())),
+                then_expr: Box::new(HirExpression::Variable("x".to_string())),
+                else_expr: Box::new(HirExpression::IntLiteral(0)),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert!(code.contains("__elvis_1"));
+    }
+
+    #[test]
+    fn non_elvis_ternary_is_unaffected() {
+        let codegen = CodeGenerator::new();
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![HirParameter::new("cond".to_string(), HirType::Int)],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("cond".to_string())),
+                then_expr: Box::new(HirExpression::IntLiteral(1)),
+                else_expr: Box::new(HirExpression::IntLiteral(0)),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert!(!code.contains("__elvis"));
+    }
+}
@@ -0,0 +1,78 @@
+//! Tests for the `tern!` macro-emission ternary lowering mode (DECY-275).
+
+#[cfg(test)]
+mod tests {
+    use crate::{CodeGenerator, TernaryLoweringMode};
+    use decy_hir::{HirExpression, HirFunction, HirParameter, HirStatement, HirType};
+
+    #[test]
+    fn macro_mode_renders_tern_call_instead_of_if_else() {
+        let codegen = CodeGenerator::with_ternary_lowering_mode(TernaryLoweringMode::Macro);
+        let func = HirFunction::new_with_body(
+            "max".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("a".to_string(), HirType::Int),
+                HirParameter::new("b".to_string(), HirType::Int),
+            ],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::BinaryOp {
+                    op: decy_hir::BinaryOperator::GreaterThan,
+                    left: Box::new(HirExpression::Variable("a".to_string())),
+                    right: Box::new(HirExpression::Variable("b".to_string())),
+                }),
+                then_expr: Box::new(HirExpression::Variable("a".to_string())),
+                else_expr: Box::new(HirExpression::Variable("b".to_string())),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert!(code.contains("tern!(a > b, a, b)"));
+        assert!(!code.contains(" if "));
+    }
+
+    #[test]
+    fn macro_mode_nests_chained_ternary_as_nested_tern_calls() {
+        let codegen = CodeGenerator::with_ternary_lowering_mode(TernaryLoweringMode::Macro);
+        let inner = HirExpression::Ternary {
+            condition: Box::new(HirExpression::Variable("b".to_string())),
+            then_expr: Box::new(HirExpression::IntLiteral(2)),
+            else_expr: Box::new(HirExpression::IntLiteral(3)),
+        };
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("a".to_string(), HirType::Int),
+                HirParameter::new("b".to_string(), HirType::Int),
+            ],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("a".to_string())),
+                then_expr: Box::new(HirExpression::IntLiteral(1)),
+                else_expr: Box::new(inner),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert_eq!(code.matches("tern!(").count(), 2);
+    }
+
+    #[test]
+    fn inline_mode_is_still_the_default() {
+        let codegen = CodeGenerator::new();
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![HirParameter::new("cond".to_string(), HirType::Int)],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("cond".to_string())),
+                then_expr: Box::new(HirExpression::IntLiteral(1)),
+                else_expr: Box::new(HirExpression::IntLiteral(0)),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert!(!code.contains("tern!"));
+        assert!(code.contains("if"));
+    }
+}
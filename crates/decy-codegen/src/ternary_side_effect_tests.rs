@@ -0,0 +1,123 @@
+//! Tests for preserving C ternary single-evaluation semantics (DECY-271).
+//!
+//! `cond ? a : b` must evaluate exactly one of `a`/`b` just like C, even
+//! when an arm has a side effect (a function call, an assignment). These
+//! tests check that neither arm is ever hoisted into a temporary evaluated
+//! before the branch is chosen - which would force both arms to run.
+
+#[cfg(test)]
+mod tests {
+    use crate::CodeGenerator;
+    use decy_hir::{HirExpression, HirFunction, HirParameter, HirStatement, HirType};
+
+    fn call(name: &str) -> HirExpression {
+        HirExpression::FunctionCall {
+            function: name.to_string(),
+            arguments: vec![],
+        }
+    }
+
+    #[test]
+    fn ternary_with_call_arms_keeps_each_call_inside_its_own_branch() {
+        let codegen = CodeGenerator::new();
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![HirParameter::new("cond".to_string(), HirType::Int)],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("cond".to_string())),
+                then_expr: Box::new(call("read")),
+                else_expr: Box::new(call("write")),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert!(code.contains("if"));
+        assert!(code.contains("read()"));
+        assert!(code.contains("write()"));
+
+        // Neither call may appear before the `if` - that would mean it was
+        // hoisted into a shared temporary evaluated unconditionally.
+        let if_pos = code.find("if ").expect("ternary must lower to an if");
+        let before_branch = &code[..if_pos];
+        assert!(!before_branch.contains("read()"));
+        assert!(!before_branch.contains("write()"));
+    }
+
+    #[test]
+    fn assignment_from_ternary_with_call_arms_is_branch_local() {
+        let codegen = CodeGenerator::new();
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Void,
+            vec![
+                HirParameter::new("cond".to_string(), HirType::Int),
+                HirParameter::new("x".to_string(), HirType::Int),
+            ],
+            vec![HirStatement::Assignment {
+                target: "x".to_string(),
+                value: HirExpression::Ternary {
+                    condition: Box::new(HirExpression::Variable("cond".to_string())),
+                    then_expr: Box::new(call("read")),
+                    else_expr: Box::new(call("write")),
+                },
+            }],
+        );
+        let code = codegen.generate_function(&func);
+
+        assert!(code.contains("x ="));
+        let if_pos = code.find("if ").expect("ternary must lower to an if");
+        let before_branch = &code[..if_pos];
+        assert!(!before_branch.contains("read()"));
+        assert!(!before_branch.contains("write()"));
+    }
+
+    #[test]
+    fn ternary_with_pure_literal_arms_still_lowers_to_branch_form() {
+        let codegen = CodeGenerator::new();
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![HirParameter::new("x".to_string(), HirType::Int)],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("x".to_string())),
+                then_expr: Box::new(HirExpression::IntLiteral(1)),
+                else_expr: Box::new(HirExpression::IntLiteral(0)),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+        assert!(code.contains("if"));
+        assert!(code.contains('1'));
+        assert!(code.contains('0'));
+    }
+
+    #[test]
+    fn nested_ternary_side_effecting_arm_stays_branch_local() {
+        let codegen = CodeGenerator::new();
+        // cond1 ? read() : (cond2 ? write() : 0)
+        let inner = HirExpression::Ternary {
+            condition: Box::new(HirExpression::Variable("cond2".to_string())),
+            then_expr: Box::new(call("write")),
+            else_expr: Box::new(HirExpression::IntLiteral(0)),
+        };
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("cond1".to_string(), HirType::Int),
+                HirParameter::new("cond2".to_string(), HirType::Int),
+            ],
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(HirExpression::Variable("cond1".to_string())),
+                then_expr: Box::new(call("read")),
+                else_expr: Box::new(inner),
+            }))],
+        );
+        let code = codegen.generate_function(&func);
+
+        let outer_if_pos = code.find("if ").expect("outer ternary must lower to an if");
+        let before_outer_branch = &code[..outer_if_pos];
+        assert!(!before_outer_branch.contains("read()"));
+        assert!(!before_outer_branch.contains("write()"));
+    }
+}
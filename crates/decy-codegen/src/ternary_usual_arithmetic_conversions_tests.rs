@@ -0,0 +1,113 @@
+//! Tests for the usual arithmetic conversions applied to ternary arms
+//! (DECY-272), so mismatched-but-compatible numeric arm types still compile
+//! as a Rust `if`/`else` expression instead of tripping a type mismatch.
+
+#[cfg(test)]
+mod tests {
+    use crate::CodeGenerator;
+    use decy_hir::{HirExpression, HirFunction, HirParameter, HirStatement, HirType};
+
+    fn ternary_return(
+        return_type: HirType,
+        params: Vec<HirParameter>,
+        condition: HirExpression,
+        then_expr: HirExpression,
+        else_expr: HirExpression,
+    ) -> String {
+        let codegen = CodeGenerator::new();
+        let func = HirFunction::new_with_body(
+            "test".to_string(),
+            return_type,
+            params,
+            vec![HirStatement::Return(Some(HirExpression::Ternary {
+                condition: Box::new(condition),
+                then_expr: Box::new(then_expr),
+                else_expr: Box::new(else_expr),
+            }))],
+        );
+        codegen.generate_function(&func)
+    }
+
+    #[test]
+    fn int_literal_and_double_variable_arms_get_a_common_f64_cast() {
+        let code = ternary_return(
+            HirType::Double,
+            vec![
+                HirParameter::new("cond".to_string(), HirType::Int),
+                HirParameter::new("d".to_string(), HirType::Double),
+            ],
+            HirExpression::Variable("cond".to_string()),
+            HirExpression::IntLiteral(1),
+            HirExpression::Variable("d".to_string()),
+        );
+        // The int literal arm must be cast up to f64; the already-f64 arm
+        // (`d`) is left alone since it's already the common type.
+        assert!(code.contains("(1 as f64)"));
+        assert!(!code.contains("(d as f64)"));
+    }
+
+    #[test]
+    fn int_and_unsigned_int_variable_arms_get_a_common_u32_cast() {
+        let code = ternary_return(
+            HirType::UnsignedInt,
+            vec![
+                HirParameter::new("cond".to_string(), HirType::Int),
+                HirParameter::new("x".to_string(), HirType::Int),
+                HirParameter::new("u".to_string(), HirType::UnsignedInt),
+            ],
+            HirExpression::Variable("cond".to_string()),
+            HirExpression::Variable("x".to_string()),
+            HirExpression::Variable("u".to_string()),
+        );
+        assert!(code.contains("(x as u32)"));
+        assert!(!code.contains("(u as u32)"));
+    }
+
+    #[test]
+    fn char_arm_promotes_to_int_against_an_int_literal() {
+        let code = ternary_return(
+            HirType::Int,
+            vec![
+                HirParameter::new("cond".to_string(), HirType::Int),
+                HirParameter::new("c".to_string(), HirType::Char),
+            ],
+            HirExpression::Variable("cond".to_string()),
+            HirExpression::Variable("c".to_string()),
+            HirExpression::IntLiteral(0),
+        );
+        // `char` (mapped to u8) promotes to i32, matching the int literal arm.
+        assert!(code.contains("(c as i32)"));
+    }
+
+    #[test]
+    fn identical_arm_types_get_no_cast() {
+        let code = ternary_return(
+            HirType::Int,
+            vec![
+                HirParameter::new("cond".to_string(), HirType::Int),
+                HirParameter::new("x".to_string(), HirType::Int),
+                HirParameter::new("y".to_string(), HirType::Int),
+            ],
+            HirExpression::Variable("cond".to_string()),
+            HirExpression::Variable("x".to_string()),
+            HirExpression::Variable("y".to_string()),
+        );
+        assert!(!code.contains(" as "));
+    }
+
+    #[test]
+    fn null_arm_is_left_alone_instead_of_cast() {
+        let code = ternary_return(
+            HirType::Pointer(Box::new(HirType::Int)),
+            vec![
+                HirParameter::new("cond".to_string(), HirType::Int),
+                HirParameter::new("p".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            ],
+            HirExpression::Variable("cond".to_string()),
+            HirExpression::Variable("p".to_string()),
+            HirExpression::NullLiteral,
+        );
+        assert!(code.contains("None"));
+        assert!(!code.contains(" as "));
+    }
+}
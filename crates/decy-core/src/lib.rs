@@ -734,10 +734,16 @@ pub fn transpile_with_includes(c_code: &str, base_dir: Option<&Path>) -> Result<
                 .fields
                 .iter()
                 .map(|f| {
-                    decy_hir::HirStructField::new(
+                    let field = decy_hir::HirStructField::new(
                         f.name.clone(),
                         decy_hir::HirType::from_ast_type(&f.field_type),
-                    )
+                    );
+                    // DECY-268: Preserve bitfield width so codegen can pack
+                    // sub-byte-width members instead of silently widening them.
+                    match f.bit_width {
+                        Some(bits) => field.with_bit_width(bits),
+                        None => field,
+                    }
                 })
                 .collect();
             decy_hir::HirStruct::new(s.name.clone(), fields)
@@ -865,6 +871,651 @@ pub fn transpile_with_includes(c_code: &str, base_dir: Option<&Path>) -> Result<
     Ok(rust_code)
 }
 
+/// Transpile C code, reusing each function's dataflow analysis across calls
+/// via a caller-held [`decy_ownership::provenance::ProvenanceCache`] (DECY-270).
+///
+/// Re-running [`transpile`] after editing one function in a translation
+/// unit recomputes dataflow analysis for every function, even ones that
+/// didn't change. This variant looks up each function's
+/// [`decy_ownership::provenance::ProvenanceKey`] fingerprint in `cache`
+/// first, only calling [`DataflowAnalyzer::analyze`] on a miss, and writes
+/// the result back for the next call. Since the fingerprint is derived from
+/// the function's own name, signature, and body, unaffected functions hit
+/// the cache even after unrelated declarations are inserted or reordered
+/// elsewhere in the file.
+///
+/// # Examples
+///
+/// ```no_run
+/// use decy_core::transpile_with_provenance_cache;
+/// use decy_ownership::provenance::ProvenanceCache;
+///
+/// let c_code = "void fill(int* arr) { for (int i = 0; i < 16; i++) arr[i] = 0; }";
+/// let mut cache = ProvenanceCache::new();
+/// let first = transpile_with_provenance_cache(c_code, &mut cache)?;
+/// let second = transpile_with_provenance_cache(c_code, &mut cache)?;
+/// assert_eq!(first, second);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if C code parsing or HIR conversion fails.
+pub fn transpile_with_provenance_cache(
+    c_code: &str,
+    cache: &mut decy_ownership::provenance::ProvenanceCache<decy_ownership::dataflow::DataflowGraph>,
+) -> Result<String> {
+    use decy_ownership::provenance::{ProvenanceKey, SourceLocation};
+
+    // Step 1: Parse C code
+    let parser = CParser::new().context("Failed to create C parser")?;
+    let ast = parser.parse(c_code).context("Failed to parse C code")?;
+
+    // Step 2: Convert to HIR
+    let hir_functions: Vec<HirFunction> = ast
+        .functions()
+        .iter()
+        .map(HirFunction::from_ast_function)
+        .collect();
+
+    // Step 3: Analyze ownership and lifetimes, reusing cached dataflow
+    // graphs where the function's fingerprint hasn't changed.
+    let mut transformed_functions = Vec::new();
+
+    for func in hir_functions {
+        // HIR carries no byte offset/line info, so the location is only
+        // for display - cache lookups key on the fingerprint alone.
+        let key = ProvenanceKey::for_function(SourceLocation::new("<input>", 0, 0), &func);
+        let dataflow_graph = match cache.get(&key) {
+            Some(cached) => cached.clone(),
+            None => {
+                let graph = DataflowAnalyzer::new().analyze(&func);
+                cache.insert(&key, graph.clone());
+                graph
+            }
+        };
+
+        let ownership_inferencer = OwnershipInferencer::new();
+        let ownership_inferences = ownership_inferencer.infer(&dataflow_graph);
+
+        let borrow_generator = BorrowGenerator::new();
+        let func_with_borrows = borrow_generator.transform_function(&func, &ownership_inferences);
+
+        let array_transformer = ArrayParameterTransformer::new();
+        let func_with_slices = array_transformer.transform(&func_with_borrows, &dataflow_graph);
+
+        let lifetime_analyzer = LifetimeAnalyzer::new();
+        let scope_tree = lifetime_analyzer.build_scope_tree(&func_with_slices);
+        let _lifetimes = lifetime_analyzer.track_lifetimes(&func_with_slices, &scope_tree);
+
+        let lifetime_annotator = LifetimeAnnotator::new();
+        let annotated_signature = lifetime_annotator.annotate_function(&func_with_slices);
+
+        transformed_functions.push((func_with_slices, annotated_signature));
+    }
+
+    // Step 4: Generate Rust code with lifetime annotations
+    let code_generator = CodeGenerator::new();
+    let mut rust_code = String::new();
+
+    for (func, annotated_sig) in &transformed_functions {
+        let generated =
+            code_generator.generate_function_with_lifetimes(func, annotated_sig);
+        rust_code.push_str(&generated);
+        rust_code.push('\n');
+    }
+
+    Ok(rust_code)
+}
+
+/// Transpile with a configurable `switch` lowering mode.
+///
+/// This variant controls how contiguous `case` fallthrough groups (e.g.
+/// `case 10: case 11: ... case 16: body;`) are rendered once collapsed into
+/// a single Rust match arm: as a compact `LOW..=HIGH` range pattern
+/// ([`SwitchLoweringMode::RangePattern`], the default used by [`transpile`]),
+/// or, for spans no wider than [`decy_codegen::MAX_SWITCH_UNROLL_WIDTH`], as
+/// an explicit `LOW | ... | HIGH` OR-pattern ([`SwitchLoweringMode::Unrolled`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// use decy_core::transpile_with_switch_lowering_mode;
+/// use decy_codegen::SwitchLoweringMode;
+///
+/// let c_code = "int f(int x) { return x; }";
+/// let rust_code = transpile_with_switch_lowering_mode(c_code, SwitchLoweringMode::Unrolled)?;
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if C code parsing or HIR conversion fails.
+pub fn transpile_with_switch_lowering_mode(
+    c_code: &str,
+    mode: decy_codegen::SwitchLoweringMode,
+) -> Result<String> {
+    // Step 1: Parse C code
+    let parser = CParser::new().context("Failed to create C parser")?;
+    let ast = parser.parse(c_code).context("Failed to parse C code")?;
+
+    // Step 2: Convert to HIR
+    let hir_functions: Vec<HirFunction> = ast
+        .functions()
+        .iter()
+        .map(HirFunction::from_ast_function)
+        .collect();
+
+    // Step 3: Generate Rust code with the requested switch lowering mode
+    let code_generator = CodeGenerator::with_switch_lowering_mode(mode);
+    let mut rust_code = String::new();
+
+    for func in &hir_functions {
+        let generated = code_generator.generate_function(func);
+        rust_code.push_str(&generated);
+        rust_code.push('\n');
+    }
+
+    Ok(rust_code)
+}
+
+/// Transpile with ternary/conditional expressions rendered as calls to a
+/// generated `tern!` helper macro instead of inlined `if`/`else`.
+///
+/// This gives a one-to-one line correspondence with the original C `?:`
+/// call sites, which can help when porting a large C codebase and comparing
+/// the transpiled output against the source. The `tern!` macro definition
+/// (see [`decy_codegen::TERN_MACRO_SOURCE`]) is emitted once, ahead of the
+/// transpiled functions. The default used by [`transpile`] stays the
+/// inlined `if`/`else` form ([`decy_codegen::TernaryLoweringMode::Inline`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// use decy_core::transpile_with_ternary_macro_mode;
+///
+/// let c_code = "int max(int a, int b) { return a > b ? a : b; }";
+/// let rust_code = transpile_with_ternary_macro_mode(c_code)?;
+/// assert!(rust_code.contains("macro_rules! tern"));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if C code parsing or HIR conversion fails.
+pub fn transpile_with_ternary_macro_mode(c_code: &str) -> Result<String> {
+    // Step 1: Parse C code
+    let parser = CParser::new().context("Failed to create C parser")?;
+    let ast = parser.parse(c_code).context("Failed to parse C code")?;
+
+    // Step 2: Convert to HIR
+    let hir_functions: Vec<HirFunction> = ast
+        .functions()
+        .iter()
+        .map(HirFunction::from_ast_function)
+        .collect();
+
+    // Step 3: Generate Rust code with the tern! macro prelude emitted once
+    let code_generator =
+        CodeGenerator::with_ternary_lowering_mode(decy_codegen::TernaryLoweringMode::Macro);
+    let mut rust_code = String::new();
+    rust_code.push_str(decy_codegen::TERN_MACRO_SOURCE);
+    rust_code.push('\n');
+
+    for func in &hir_functions {
+        let generated = code_generator.generate_function(func);
+        rust_code.push_str(&generated);
+        rust_code.push('\n');
+    }
+
+    Ok(rust_code)
+}
+
+/// Transpile C code, lowering global scalar counters to `AtomicI32` instead
+/// of `static mut` where it is safe to do so.
+///
+/// A global qualifies when every function in the translation unit only ever
+/// reads its whole value or writes to it via a plain store or a
+/// self-referencing add/subtract-by-constant (`counter = counter + 1;`,
+/// `counter = counter - 1;`, etc.) - see
+/// [`decy_codegen::atomic_global_transform::is_atomic_candidate`]. Qualifying
+/// globals are emitted as `static NAME: AtomicI32 = AtomicI32::new(init);`
+/// and every access becomes `.load(Ordering::SeqCst)` /
+/// `.fetch_add(k, Ordering::SeqCst)` / `.fetch_sub(k, Ordering::SeqCst)` /
+/// `.store(v, Ordering::SeqCst)`, eliminating the `unsafe` blocks a `static
+/// mut` access would otherwise require. Non-qualifying globals fall back to
+/// the same `static mut` lowering used by [`transpile`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use decy_core::transpile_with_atomic_globals;
+///
+/// let c_code = r#"
+///     int counter = 0;
+///
+///     void increment() {
+///         counter = counter + 1;
+///     }
+/// "#;
+/// let rust_code = transpile_with_atomic_globals(c_code)?;
+/// assert!(rust_code.contains("AtomicI32"));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if C code parsing or HIR conversion fails.
+pub fn transpile_with_atomic_globals(c_code: &str) -> Result<String> {
+    // Step 1: Parse C code
+    let parser = CParser::new().context("Failed to create C parser")?;
+    let ast = parser.parse(c_code).context("Failed to parse C code")?;
+
+    // Step 2: Convert to HIR
+    let hir_functions: Vec<HirFunction> = ast
+        .functions()
+        .iter()
+        .map(HirFunction::from_ast_function)
+        .collect();
+
+    // Convert global variables to HIR (mirrors transpile_with_includes)
+    let hir_variables: Vec<decy_hir::HirStatement> = ast
+        .variables()
+        .iter()
+        .map(|v| decy_hir::HirStatement::VariableDeclaration {
+            name: v.name().to_string(),
+            var_type: decy_hir::HirType::from_ast_type(v.var_type()),
+            initializer: v
+                .initializer()
+                .map(decy_hir::HirExpression::from_ast_expression),
+        })
+        .collect();
+
+    // Step 3: Determine which scalar int globals are safe to lower to AtomicI32
+    let atomic_globals: std::collections::HashSet<String> = hir_variables
+        .iter()
+        .filter_map(|var_stmt| {
+            let decy_hir::HirStatement::VariableDeclaration { name, var_type, .. } = var_stmt
+            else {
+                return None;
+            };
+            let is_scalar_int = matches!(var_type, decy_hir::HirType::Int);
+            if is_scalar_int
+                && decy_codegen::atomic_global_transform::is_atomic_candidate(name, &hir_functions)
+            {
+                Some(name.clone())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    // Step 4: Generate Rust code, seeding the code generator with the globals
+    // determined to be safe for atomic lowering
+    let code_generator = CodeGenerator::with_atomic_globals(atomic_globals.clone());
+    let mut rust_code = String::new();
+
+    for var_stmt in &hir_variables {
+        if let decy_hir::HirStatement::VariableDeclaration {
+            name,
+            var_type,
+            initializer,
+        } = var_stmt
+        {
+            if atomic_globals.contains(name) {
+                let init_code = initializer
+                    .as_ref()
+                    .map(|init| code_generator.generate_expression(init))
+                    .unwrap_or_else(|| "0".to_string());
+                rust_code.push_str(&format!(
+                    "static {}: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new({});\n",
+                    name, init_code
+                ));
+                continue;
+            }
+
+            let type_str = CodeGenerator::map_type(var_type);
+            if let Some(init_expr) = initializer {
+                let init_code = code_generator.generate_expression(init_expr);
+                rust_code.push_str(&format!(
+                    "static mut {}: {} = {};\n",
+                    name, type_str, init_code
+                ));
+            } else {
+                rust_code.push_str(&format!(
+                    "static mut {}: Option<{}> = None;\n",
+                    name, type_str
+                ));
+            }
+        }
+    }
+    if !hir_variables.is_empty() {
+        rust_code.push('\n');
+    }
+
+    for func in &hir_functions {
+        let generated = code_generator.generate_function(func);
+        rust_code.push_str(&generated);
+        rust_code.push('\n');
+    }
+
+    Ok(rust_code)
+}
+
+/// Transpile C code, additionally lowering check-then-act guarded
+/// read-modify-write accessors on atomic globals into a
+/// `compare_exchange_weak` retry loop.
+///
+/// Atomic globals are determined the same way as
+/// [`transpile_with_atomic_globals`]. Among those, any function whose body
+/// matches the classic `if (g > 0) { g = g - 1; return 1; } return 0;`
+/// check-then-act idiom (see
+/// [`decy_codegen::guarded_cas_transform::detect_guarded_decrement`]) is
+/// rewritten as a loop that reloads `g`, re-checks the guard, and retries the
+/// compare-exchange on contention - eliminating the torn read-modify-write a
+/// plain load/branch/store would leave in place.
+///
+/// # Examples
+///
+/// ```no_run
+/// use decy_core::transpile_with_guarded_cas;
+///
+/// let c_code = r#"
+///     int resource_count = 10;
+///
+///     int allocate_resource() {
+///         if (resource_count > 0) {
+///             resource_count = resource_count - 1;
+///             return 1;
+///         }
+///         return 0;
+///     }
+/// "#;
+/// let rust_code = transpile_with_guarded_cas(c_code)?;
+/// assert!(rust_code.contains("compare_exchange"));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if C code parsing or HIR conversion fails.
+pub fn transpile_with_guarded_cas(c_code: &str) -> Result<String> {
+    // Step 1: Parse C code
+    let parser = CParser::new().context("Failed to create C parser")?;
+    let ast = parser.parse(c_code).context("Failed to parse C code")?;
+
+    // Step 2: Convert to HIR
+    let hir_functions: Vec<HirFunction> = ast
+        .functions()
+        .iter()
+        .map(HirFunction::from_ast_function)
+        .collect();
+
+    // Convert global variables to HIR (mirrors transpile_with_includes)
+    let hir_variables: Vec<decy_hir::HirStatement> = ast
+        .variables()
+        .iter()
+        .map(|v| decy_hir::HirStatement::VariableDeclaration {
+            name: v.name().to_string(),
+            var_type: decy_hir::HirType::from_ast_type(v.var_type()),
+            initializer: v
+                .initializer()
+                .map(decy_hir::HirExpression::from_ast_expression),
+        })
+        .collect();
+
+    // Step 3: Determine which scalar int globals are safe to lower to AtomicI32
+    let atomic_globals: std::collections::HashSet<String> = hir_variables
+        .iter()
+        .filter_map(|var_stmt| {
+            let decy_hir::HirStatement::VariableDeclaration { name, var_type, .. } = var_stmt
+            else {
+                return None;
+            };
+            let is_scalar_int = matches!(var_type, decy_hir::HirType::Int);
+            if is_scalar_int
+                && decy_codegen::atomic_global_transform::is_atomic_candidate(name, &hir_functions)
+            {
+                Some(name.clone())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    // Step 4: Among the atomic globals, find those with a check-then-act
+    // guarded accessor function and opt them into CAS-loop lowering
+    let guarded_cas_globals: std::collections::HashSet<String> = atomic_globals
+        .iter()
+        .filter(|name| {
+            hir_functions.iter().any(|func| {
+                decy_codegen::guarded_cas_transform::detect_guarded_decrement(name, func.body())
+                    .is_some()
+            })
+        })
+        .cloned()
+        .collect();
+
+    // Step 5: Generate Rust code
+    let code_generator =
+        CodeGenerator::with_guarded_cas_globals(atomic_globals.clone(), guarded_cas_globals);
+    let mut rust_code = String::new();
+
+    for var_stmt in &hir_variables {
+        if let decy_hir::HirStatement::VariableDeclaration {
+            name,
+            var_type,
+            initializer,
+        } = var_stmt
+        {
+            if atomic_globals.contains(name) {
+                let init_code = initializer
+                    .as_ref()
+                    .map(|init| code_generator.generate_expression(init))
+                    .unwrap_or_else(|| "0".to_string());
+                rust_code.push_str(&format!(
+                    "static {}: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new({});\n",
+                    name, init_code
+                ));
+                continue;
+            }
+
+            let type_str = CodeGenerator::map_type(var_type);
+            if let Some(init_expr) = initializer {
+                let init_code = code_generator.generate_expression(init_expr);
+                rust_code.push_str(&format!(
+                    "static mut {}: {} = {};\n",
+                    name, type_str, init_code
+                ));
+            } else {
+                rust_code.push_str(&format!(
+                    "static mut {}: Option<{}> = None;\n",
+                    name, type_str
+                ));
+            }
+        }
+    }
+    if !hir_variables.is_empty() {
+        rust_code.push('\n');
+    }
+
+    for func in &hir_functions {
+        let generated = code_generator.generate_function(func);
+        rust_code.push_str(&generated);
+        rust_code.push('\n');
+    }
+
+    Ok(rust_code)
+}
+
+/// Transpile C code, additionally wrapping struct-typed globals whose fields
+/// are written across a multi-field critical section in `Mutex<T>` instead
+/// of a `static mut` requiring an `unsafe` touch per field.
+///
+/// A struct-typed global qualifies when some function contains two or more
+/// consecutive field-assignment statements against it (see
+/// [`decy_codegen::mutex_global_transform::find_critical_sections`]); each
+/// such cluster is rewritten into a single `{ let mut g = NAME.lock().unwrap(); ... }`
+/// scope so the whole cluster is one atomic critical section rather than
+/// per-field unsafe.
+///
+/// # Examples
+///
+/// ```no_run
+/// use decy_core::transpile_with_struct_mutex;
+///
+/// let c_code = r#"
+///     struct SharedData {
+///         int counter;
+///         int flag;
+///     };
+///
+///     struct SharedData shared;
+///
+///     int main() {
+///         shared.counter = 1;
+///         shared.flag = 1;
+///         return shared.counter;
+///     }
+/// "#;
+/// let rust_code = transpile_with_struct_mutex(c_code)?;
+/// assert!(rust_code.contains("Mutex"));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if C code parsing or HIR conversion fails.
+pub fn transpile_with_struct_mutex(c_code: &str) -> Result<String> {
+    // Step 1: Parse C code
+    let parser = CParser::new().context("Failed to create C parser")?;
+    let ast = parser.parse(c_code).context("Failed to parse C code")?;
+
+    // Step 2: Convert to HIR
+    let hir_functions: Vec<HirFunction> = ast
+        .functions()
+        .iter()
+        .map(HirFunction::from_ast_function)
+        .collect();
+
+    let hir_structs: Vec<decy_hir::HirStruct> = ast
+        .structs()
+        .iter()
+        .map(|s| {
+            let fields = s
+                .fields
+                .iter()
+                .map(|f| {
+                    let field = decy_hir::HirStructField::new(
+                        f.name.clone(),
+                        decy_hir::HirType::from_ast_type(&f.field_type),
+                    );
+                    // DECY-268: Preserve bitfield width so codegen can pack
+                    // sub-byte-width members instead of silently widening them.
+                    match f.bit_width {
+                        Some(bits) => field.with_bit_width(bits),
+                        None => field,
+                    }
+                })
+                .collect();
+            decy_hir::HirStruct::new(s.name.clone(), fields)
+        })
+        .collect();
+
+    let hir_variables: Vec<decy_hir::HirStatement> = ast
+        .variables()
+        .iter()
+        .map(|v| decy_hir::HirStatement::VariableDeclaration {
+            name: v.name().to_string(),
+            var_type: decy_hir::HirType::from_ast_type(v.var_type()),
+            initializer: v
+                .initializer()
+                .map(decy_hir::HirExpression::from_ast_expression),
+        })
+        .collect();
+
+    // Step 3: Determine which struct-typed globals have a multi-field
+    // critical section and so qualify for Mutex<T> lowering
+    let mutex_globals: std::collections::HashSet<String> = hir_variables
+        .iter()
+        .filter_map(|var_stmt| {
+            let decy_hir::HirStatement::VariableDeclaration { name, var_type, .. } = var_stmt
+            else {
+                return None;
+            };
+            let is_struct = matches!(var_type, decy_hir::HirType::Struct(_));
+            if is_struct
+                && decy_codegen::mutex_global_transform::has_critical_section(name, &hir_functions)
+            {
+                Some(name.clone())
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    // Step 4: Generate Rust code, seeding the code generator with the
+    // globals determined to need Mutex<T> lowering
+    let code_generator = CodeGenerator::with_mutex_globals(mutex_globals.clone());
+    let mut rust_code = String::new();
+
+    for hir_struct in &hir_structs {
+        let struct_code = code_generator.generate_struct(hir_struct);
+        rust_code.push_str(&struct_code);
+        rust_code.push('\n');
+    }
+
+    for var_stmt in &hir_variables {
+        if let decy_hir::HirStatement::VariableDeclaration {
+            name,
+            var_type,
+            initializer,
+        } = var_stmt
+        {
+            if mutex_globals.contains(name) {
+                let struct_name = match var_type {
+                    decy_hir::HirType::Struct(s) => s.clone(),
+                    _ => unreachable!("mutex_globals only contains struct-typed names"),
+                };
+                let init_code = initializer
+                    .as_ref()
+                    .map(|init| code_generator.generate_expression(init))
+                    .unwrap_or_else(|| format!("{}::default()", struct_name));
+                rust_code.push_str(&format!(
+                    "static {}: std::sync::Mutex<{}> = std::sync::Mutex::new({});\n",
+                    name, struct_name, init_code
+                ));
+                continue;
+            }
+
+            let type_str = CodeGenerator::map_type(var_type);
+            if let Some(init_expr) = initializer {
+                let init_code = code_generator.generate_expression(init_expr);
+                rust_code.push_str(&format!(
+                    "static mut {}: {} = {};\n",
+                    name, type_str, init_code
+                ));
+            } else {
+                rust_code.push_str(&format!(
+                    "static mut {}: Option<{}> = None;\n",
+                    name, type_str
+                ));
+            }
+        }
+    }
+    if !hir_variables.is_empty() {
+        rust_code.push('\n');
+    }
+
+    for func in &hir_functions {
+        let generated = code_generator.generate_function(func);
+        rust_code.push_str(&generated);
+        rust_code.push('\n');
+    }
+
+    Ok(rust_code)
+}
+
 /// Transpile with Box transformation enabled.
 ///
 /// This variant applies Box pattern detection to transform malloc/free
@@ -915,6 +1566,136 @@ pub fn transpile_with_box_transform(c_code: &str) -> Result<String> {
     Ok(rust_code)
 }
 
+/// Transpile C code and also run the unsynchronized-global race analysis.
+///
+/// Returns the generated Rust code alongside a [`decy_analyzer::race_analysis::RaceDiagnostic`]
+/// for every global mutated from more than one function, guarded by a
+/// check-then-act accessor, or published via a flag without a
+/// release/acquire fence (see [`decy_analyzer::race_analysis::RaceAnalyzer`]).
+/// The generated code itself is unchanged from [`transpile`] - this is a
+/// diagnostic pass, not a lowering - so callers can use the diagnostics to
+/// decide whether to opt into [`transpile_with_atomic_globals`],
+/// [`transpile_with_guarded_cas`], or [`transpile_with_struct_mutex`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use decy_core::transpile_with_race_diagnostics;
+///
+/// let c_code = r#"
+///     int counter = 0;
+///
+///     void increment() {
+///         counter = counter + 1;
+///     }
+///
+///     void decrement() {
+///         counter = counter - 1;
+///     }
+/// "#;
+/// let (rust_code, diagnostics) = transpile_with_race_diagnostics(c_code)?;
+/// assert!(!rust_code.is_empty());
+/// assert_eq!(diagnostics.len(), 1);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if C code parsing or HIR conversion fails.
+pub fn transpile_with_race_diagnostics(
+    c_code: &str,
+) -> Result<(String, Vec<decy_analyzer::race_analysis::RaceDiagnostic>)> {
+    // Step 1: Parse C code
+    let parser = CParser::new().context("Failed to create C parser")?;
+    let ast = parser.parse(c_code).context("Failed to parse C code")?;
+
+    // Step 2: Convert to HIR
+    let hir_functions: Vec<HirFunction> = ast
+        .functions()
+        .iter()
+        .map(HirFunction::from_ast_function)
+        .collect();
+
+    // Step 3: Run the race analysis over the HIR, before any lowering
+    let diagnostics = decy_analyzer::race_analysis::RaceAnalyzer::new().analyze(&hir_functions);
+
+    // Step 4: Generate Rust code (unchanged from `transpile`)
+    let rust_code = transpile(c_code)?;
+
+    Ok((rust_code, diagnostics))
+}
+
+/// Transpile C code, lowering functions built entirely out of C's
+/// check-then-return error idiom (`if (fp == NULL) return -1;`, `if (n < 0)
+/// return n;`) to `Result<T, i32>` + early `return Err(..)` (DECY-279).
+///
+/// `table` registers the error sentinel for callees whose convention isn't
+/// "null pointer" or "negative int" - see
+/// [`decy_codegen::error_result_transform::SentinelTable`]. A function
+/// lowers only when [`decy_codegen::error_result_transform::lower_function_to_result`]
+/// can account for every statement in its body; any function with so much
+/// as one statement outside that shape is emitted by plain
+/// [`CodeGenerator::generate_function`], without the borrow and lifetime
+/// passes that [`transpile`] runs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use decy_core::transpile_with_error_result_lowering;
+/// use decy_codegen::error_result_transform::SentinelTable;
+///
+/// let c_code = r#"
+///     int read_exactly(int fd) {
+///         int n = read_chunk(fd);
+///         if (n < 0) {
+///             return n;
+///         }
+///         return 0;
+///     }
+/// "#;
+/// let rust_code = transpile_with_error_result_lowering(c_code, &SentinelTable::new())?;
+/// assert!(rust_code.contains("Result<i32, i32>"));
+/// assert!(rust_code.contains("return Err(n)"));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if C code parsing or HIR conversion fails.
+pub fn transpile_with_error_result_lowering(
+    c_code: &str,
+    table: &decy_codegen::error_result_transform::SentinelTable,
+) -> Result<String> {
+    use decy_codegen::error_result_transform::{generate_result_lowered_function, lower_function_to_result};
+
+    // Step 1: Parse C code
+    let parser = CParser::new().context("Failed to create C parser")?;
+    let ast = parser.parse(c_code).context("Failed to parse C code")?;
+
+    // Step 2: Convert to HIR
+    let hir_functions: Vec<HirFunction> = ast
+        .functions()
+        .iter()
+        .map(HirFunction::from_ast_function)
+        .collect();
+
+    // Step 3: Generate Rust code, lowering each function that fully matches
+    // the checked-call idiom and falling back to ordinary codegen otherwise.
+    let code_generator = CodeGenerator::new();
+    let mut rust_code = String::new();
+
+    for func in &hir_functions {
+        let generated = match lower_function_to_result(func, table) {
+            Some(lowering) => generate_result_lowered_function(func, &lowering),
+            None => code_generator.generate_function(func),
+        };
+        rust_code.push_str(&generated);
+        rust_code.push('\n');
+    }
+
+    Ok(rust_code)
+}
+
 /// Transpile a single C file with project context.
 ///
 /// This enables file-by-file transpilation for incremental C→Rust migration.
@@ -1141,4 +1922,61 @@ mod tests {
         // When references are present, lifetime annotations would appear
         // Future: Add a test with actual C pointer parameters to verify '<'a> syntax
     }
+
+    #[test]
+    fn test_transpile_with_provenance_cache_matches_transpile() {
+        let c_code = "int add(int a, int b) { return a + b; }";
+        let mut cache = decy_ownership::provenance::ProvenanceCache::new();
+        let result = transpile_with_provenance_cache(c_code, &mut cache);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), transpile(c_code).unwrap());
+    }
+
+    #[test]
+    fn test_transpile_with_provenance_cache_reuses_entry_across_calls() {
+        let c_code = "void fill(int* arr) { for (int i = 0; i < 16; i++) arr[i] = 0; }";
+        let mut cache = decy_ownership::provenance::ProvenanceCache::new();
+
+        let first = transpile_with_provenance_cache(c_code, &mut cache).unwrap();
+        assert_eq!(cache.len(), 1);
+
+        // A second call for the same unchanged source should hit the
+        // existing cache entry rather than growing it, and produce
+        // identical output.
+        let second = transpile_with_provenance_cache(c_code, &mut cache).unwrap();
+        assert_eq!(cache.len(), 1);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_transpile_with_error_result_lowering_rewrites_matching_function() {
+        let c_code = r#"
+            int read_exactly(int fd) {
+                int n = read_chunk(fd);
+                if (n < 0) {
+                    return n;
+                }
+                return 0;
+            }
+        "#;
+        let table = decy_codegen::error_result_transform::SentinelTable::new();
+        let result = transpile_with_error_result_lowering(c_code, &table);
+        assert!(result.is_ok());
+
+        let rust_code = result.unwrap();
+        assert!(rust_code.contains("Result<i32, i32>"));
+        assert!(rust_code.contains("return Err(n)"));
+        assert!(rust_code.contains("Ok(0)"));
+    }
+
+    #[test]
+    fn test_transpile_with_error_result_lowering_falls_back_for_non_matching_function() {
+        let c_code = "int add(int a, int b) { return a + b; }";
+        let table = decy_codegen::error_result_transform::SentinelTable::new();
+        let result = transpile_with_error_result_lowering(c_code, &table);
+        assert!(result.is_ok());
+
+        let rust_code = result.unwrap();
+        assert_eq!(rust_code, transpile(c_code).unwrap());
+    }
 }
@@ -0,0 +1,49 @@
+//! Integration tests for end-to-end C bitfield struct transpilation (DECY-268).
+
+#[test]
+fn test_transpile_struct_with_only_bitfield_members_packs_them() {
+    let c_code = r#"
+        struct Flags {
+            unsigned int ready : 1;
+            unsigned int mode : 3;
+            unsigned int reserved : 28;
+        };
+    "#;
+
+    let rust_code = decy_core::transpile(c_code).expect("Should transpile bitfield struct");
+
+    assert!(
+        rust_code.contains("struct Flags"),
+        "Should generate the Flags struct: {rust_code}"
+    );
+    assert!(
+        rust_code.contains("bits: u32"),
+        "32 packed bits should pick a u32 backing store: {rust_code}"
+    );
+    assert!(rust_code.contains("fn ready"), "Should generate a ready() getter: {rust_code}");
+    assert!(
+        rust_code.contains("fn set_mode"),
+        "Should generate a set_mode() setter: {rust_code}"
+    );
+    assert!(
+        !rust_code.contains("ready: u32") && !rust_code.contains("ready: i32"),
+        "Bitfield members should not be widened to their full declared type: {rust_code}"
+    );
+}
+
+#[test]
+fn test_transpile_struct_without_bitfields_is_unaffected() {
+    let c_code = r#"
+        struct Point {
+            int x;
+            int y;
+        };
+    "#;
+
+    let rust_code = decy_core::transpile(c_code).expect("Should transpile plain struct");
+
+    assert!(rust_code.contains("struct Point"));
+    assert!(rust_code.contains("x: i32"));
+    assert!(rust_code.contains("y: i32"));
+    assert!(!rust_code.contains("bits:"));
+}
@@ -0,0 +1,57 @@
+//! Integration tests for platform-pointer-width-dependent `sizeof` codegen (DECY-269).
+
+#[test]
+fn test_transpile_sizeof_long_emits_symbolic_pointer_width() {
+    let c_code = r#"
+        long get_pointer_size(void) {
+            return sizeof(long);
+        }
+    "#;
+
+    let rust_code = decy_core::transpile(c_code).expect("Should transpile sizeof(long)");
+
+    assert!(
+        rust_code.contains("core::mem::size_of::<usize>()"),
+        "Should emit the symbolic pointer-width form: {rust_code}"
+    );
+    assert!(
+        !rust_code.contains("size_of::<long"),
+        "Should not emit invalid Rust for the unmapped C type name: {rust_code}"
+    );
+}
+
+#[test]
+fn test_transpile_n_times_sizeof_long_emits_symbolic_pointer_width() {
+    let c_code = r#"
+        long *allocate(int n) {
+            return malloc(n * sizeof(long));
+        }
+    "#;
+
+    let rust_code = decy_core::transpile(c_code).expect("Should transpile n * sizeof(long)");
+
+    assert!(
+        rust_code.contains("core::mem::size_of::<usize>()"),
+        "Should emit the symbolic pointer-width form rather than a baked-in literal: {rust_code}"
+    );
+    assert!(
+        !rust_code.contains("size_of::<long"),
+        "Should not emit invalid Rust for the unmapped C type name: {rust_code}"
+    );
+}
+
+#[test]
+fn test_transpile_sizeof_int_is_unaffected() {
+    let c_code = r#"
+        int get_int_size(void) {
+            return sizeof(int);
+        }
+    "#;
+
+    let rust_code = decy_core::transpile(c_code).expect("Should transpile sizeof(int)");
+
+    assert!(
+        !rust_code.contains("core::mem::size_of::<usize>()"),
+        "sizeof(int) is not pointer-width-dependent and should not route through the symbolic form: {rust_code}"
+    );
+}
@@ -9,7 +9,11 @@
 //! **Coverage**: 11 properties × 256 cases = 2,816+ test executions
 //! **Goal**: Prove race condition safety holds for all valid inputs
 
-use decy_core::transpile;
+use decy_analyzer::race_analysis::RaceClass;
+use decy_core::{
+    transpile, transpile_with_atomic_globals, transpile_with_guarded_cas,
+    transpile_with_race_diagnostics, transpile_with_struct_mutex,
+};
 use proptest::prelude::*;
 
 // ============================================================================
@@ -434,3 +438,374 @@ proptest! {
         );
     }
 }
+
+// ============================================================================
+// Property 13: Atomic-lowered counter globals generate no unsafe blocks
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_atomic_counter_has_no_unsafe(
+        initial in -1000i32..=1000
+    ) {
+        let c_code = format!(
+            r#"
+            int counter = {};
+
+            void increment() {{
+                counter = counter + 1;
+            }}
+
+            void decrement() {{
+                counter = counter - 1;
+            }}
+
+            int main() {{
+                increment();
+                decrement();
+                return counter;
+            }}
+            "#,
+            initial
+        );
+
+        let result = transpile_with_atomic_globals(&c_code).expect("Should transpile");
+
+        prop_assert!(result.contains("AtomicI32"), "Should lower counter to AtomicI32: {}", result);
+        prop_assert!(result.contains("fetch_add"), "Increment should use fetch_add: {}", result);
+        prop_assert!(result.contains("fetch_sub"), "Decrement should use fetch_sub: {}", result);
+        prop_assert!(!result.contains("unsafe"), "Atomic lowering should need no unsafe: {}", result);
+    }
+}
+
+// ============================================================================
+// Property 14: Producer-consumer counters stay atomic-safe
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_atomic_producer_consumer_has_no_unsafe(
+        initial in 0i32..=1000
+    ) {
+        let c_code = format!(
+            r#"
+            int items_produced = {};
+            int items_consumed = 0;
+
+            void produce() {{
+                items_produced = items_produced + 1;
+            }}
+
+            void consume() {{
+                items_consumed = items_consumed + 1;
+            }}
+
+            int main() {{
+                produce();
+                consume();
+                return items_produced - items_consumed;
+            }}
+            "#,
+            initial
+        );
+
+        let result = transpile_with_atomic_globals(&c_code).expect("Should transpile");
+
+        prop_assert!(result.contains("AtomicI32"), "Should lower counters to AtomicI32: {}", result);
+        prop_assert!(result.contains("fetch_add"), "Produce/consume should use fetch_add: {}", result);
+        prop_assert!(!result.contains("unsafe"), "Atomic lowering should need no unsafe: {}", result);
+    }
+}
+
+// ============================================================================
+// Property 15: Guarded check-then-act decrement lowers to a CAS retry loop
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_check_then_act_lowers_to_cas(
+        resource_count in 0i32..=100
+    ) {
+        let c_code = format!(
+            r#"
+            int resource_count = {};
+
+            int allocate_resource() {{
+                if (resource_count > 0) {{
+                    resource_count = resource_count - 1;
+                    return 1;
+                }}
+                return 0;
+            }}
+
+            int main() {{
+                int result = allocate_resource();
+                return result;
+            }}
+            "#,
+            resource_count
+        );
+
+        let result = transpile_with_guarded_cas(&c_code).expect("Should transpile");
+
+        prop_assert!(result.contains("AtomicI32"), "Should lower resource_count to AtomicI32: {}", result);
+        prop_assert!(result.contains("compare_exchange"), "Guarded decrement should use compare_exchange: {}", result);
+        prop_assert!(!result.contains("unsafe"), "Guarded CAS lowering should need no unsafe: {}", result);
+    }
+}
+
+// ============================================================================
+// Property 16: CAS-lowered guarded decrement keeps the guard-failed path intact
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_check_then_act_cas_preserves_failure_path(
+        resource_count in 0i32..=100
+    ) {
+        let c_code = format!(
+            r#"
+            int resource_count = {};
+
+            int allocate_resource() {{
+                if (resource_count > 0) {{
+                    resource_count = resource_count - 1;
+                    return 1;
+                }}
+                return 0;
+            }}
+
+            int main() {{
+                int result = allocate_resource();
+                return result;
+            }}
+            "#,
+            resource_count
+        );
+
+        let result = transpile_with_guarded_cas(&c_code).expect("Should transpile");
+
+        // The retry loop must recompute the guard from a freshly-loaded value
+        // on every attempt rather than trusting a stale read, so there is no
+        // window for a torn read-modify-write between the check and the act.
+        prop_assert!(result.contains("Ordering::Acquire"), "Should reload under Acquire ordering: {}", result);
+        prop_assert!(result.contains("loop"), "Should retry via a loop: {}", result);
+    }
+}
+
+// ============================================================================
+// Property 17: Struct globals with a multi-field critical section lower to Mutex
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_struct_critical_section_lowers_to_mutex(
+        counter_val in -1000i32..=1000,
+        flag_val in 0i32..=1
+    ) {
+        let c_code = format!(
+            r#"
+            struct SharedData {{
+                int counter;
+                int flag;
+            }};
+
+            struct SharedData shared;
+
+            int main() {{
+                shared.counter = {};
+                shared.flag = {};
+                return shared.counter;
+            }}
+            "#,
+            counter_val, flag_val
+        );
+
+        let result = transpile_with_struct_mutex(&c_code).expect("Should transpile");
+
+        prop_assert!(result.contains("Mutex"), "Should lower shared to Mutex<T>: {}", result);
+        prop_assert!(result.contains("lock()"), "Critical section should take the lock: {}", result);
+        prop_assert!(!result.contains("unsafe"), "Mutex-guarded struct global should need no unsafe: {}", result);
+    }
+}
+
+// ============================================================================
+// Property 18: Read-modify-write from two functions is flagged as lost update
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_read_modify_write_flagged_as_lost_update(
+        initial in -1000i32..=1000
+    ) {
+        let c_code = format!(
+            r#"
+            int counter = {};
+
+            void increment() {{
+                counter = counter + 1;
+            }}
+
+            void decrement() {{
+                counter = counter - 1;
+            }}
+
+            int main() {{
+                increment();
+                decrement();
+                return counter;
+            }}
+            "#,
+            initial
+        );
+
+        let (_, diagnostics) = transpile_with_race_diagnostics(&c_code).expect("Should transpile");
+
+        prop_assert!(
+            diagnostics.iter().any(|d| d.global == "counter" && d.race_class == RaceClass::LostUpdate),
+            "Should flag counter as a lost-update race: {:?}", diagnostics
+        );
+    }
+}
+
+// ============================================================================
+// Property 19: Check-then-act accessor is flagged as check-then-act
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_check_then_act_flagged_as_check_then_act(
+        resource_count in 0i32..=100
+    ) {
+        let c_code = format!(
+            r#"
+            int resource_count = {};
+
+            int allocate_resource() {{
+                if (resource_count > 0) {{
+                    resource_count = resource_count - 1;
+                    return 1;
+                }}
+                return 0;
+            }}
+
+            int main() {{
+                int result = allocate_resource();
+                return result;
+            }}
+            "#,
+            resource_count
+        );
+
+        let (_, diagnostics) = transpile_with_race_diagnostics(&c_code).expect("Should transpile");
+
+        prop_assert!(
+            diagnostics.iter().any(|d| d.global == "resource_count" && d.race_class == RaceClass::CheckThenAct),
+            "Should flag resource_count as a check-then-act race: {:?}", diagnostics
+        );
+    }
+}
+
+// ============================================================================
+// Property 20: Flag-based sync is flagged as publication without fence
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_flag_based_sync_flagged_as_publication_without_fence(
+        data_value in -1000i32..=1000
+    ) {
+        let c_code = format!(
+            r#"
+            int data_ready = 0;
+            int shared_data = 0;
+
+            void producer() {{
+                shared_data = {};
+                data_ready = 1;
+            }}
+
+            int consumer() {{
+                if (data_ready == 1) {{
+                    return shared_data;
+                }}
+                return 0;
+            }}
+
+            int main() {{
+                producer();
+                int result = consumer();
+                return result;
+            }}
+            "#,
+            data_value
+        );
+
+        let (_, diagnostics) = transpile_with_race_diagnostics(&c_code).expect("Should transpile");
+
+        prop_assert!(
+            diagnostics.iter().any(|d| {
+                d.global == "shared_data"
+                    && d.flag.as_deref() == Some("data_ready")
+                    && d.race_class == RaceClass::PublicationWithoutFence
+            }),
+            "Should flag shared_data/data_ready as a publication-without-fence race: {:?}", diagnostics
+        );
+    }
+}
+
+// ============================================================================
+// Property 21: Lone field assignments and reads of a Mutex-lowered struct
+// global still route through the lock, not just multi-field clusters
+// ============================================================================
+
+proptest! {
+    #[test]
+    fn prop_single_field_assignment_on_mutex_global_takes_the_lock(
+        counter_val in -1000i32..=1000,
+        flag_val in 0i32..=1
+    ) {
+        // `init_both`'s two consecutive field assignments are what qualify
+        // `shared` for Mutex<T> lowering in the first place. `bump_counter`
+        // then touches it with a single, un-clustered field assignment, and
+        // `peek` only reads a field - neither has a sibling statement to
+        // cluster with, but both must still take the lock.
+        let c_code = format!(
+            r#"
+            struct SharedData {{
+                int counter;
+                int flag;
+            }};
+
+            struct SharedData shared;
+
+            void init_both() {{
+                shared.counter = {};
+                shared.flag = {};
+            }}
+
+            void bump_counter() {{
+                shared.counter = {};
+            }}
+
+            int peek() {{
+                return shared.counter;
+            }}
+            "#,
+            counter_val, flag_val, counter_val
+        );
+
+        let result = transpile_with_struct_mutex(&c_code).expect("Should transpile");
+
+        prop_assert!(result.contains("Mutex"), "Should lower shared to Mutex<T>: {}", result);
+        prop_assert!(
+            result.contains("shared.lock().unwrap().counter = "),
+            "Lone field assignment on a Mutex-lowered global should take the lock: {}", result
+        );
+        prop_assert!(
+            result.contains("shared.lock().unwrap().counter;"),
+            "Field read of a Mutex-lowered global should take the lock: {}", result
+        );
+        prop_assert!(!result.contains("unsafe"), "Mutex-guarded struct global should need no unsafe: {}", result);
+    }
+}
@@ -0,0 +1,228 @@
+//! ThreadSanitizer-backed race-detection harness for transpiled concurrent code.
+//!
+//! `race_condition_property_tests.rs` and `race_condition_safety_integration_test.rs`
+//! only assert that transpilation *succeeds* and is well-formed; neither
+//! verifies that the *generated* Rust is actually race-free when the shared
+//! state is touched from multiple threads. This harness compiles each
+//! race-pattern fixture's transpiled output with `-Z sanitizer=thread` on
+//! nightly, spawns the generated accessor functions across several
+//! `std::thread` handles in a loop, and fails if ThreadSanitizer reports a
+//! data race - turning the safety *claim* in the atomic/Mutex lowering
+//! modules into an empirically checked invariant.
+//!
+//! Requires a nightly `rustc` on `PATH` with the `thread` sanitizer support
+//! for the host target. Gated behind the `tsan` feature since it shells out
+//! to the compiler and takes much longer than the rest of the suite.
+
+#![cfg(feature = "tsan")]
+
+use decy_core::{transpile, transpile_with_atomic_globals, transpile_with_struct_mutex};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const THREAD_COUNT: usize = 8;
+const ITERATIONS_PER_THREAD: usize = 1000;
+
+fn tsan_suppressions_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/tsan_suppressions.txt")
+}
+
+/// Wraps transpiled function/global definitions in a `fn main` that spawns
+/// `THREAD_COUNT` threads, each running `spawn_body` in a loop, then joins
+/// them all before returning.
+fn build_tsan_harness(transpiled: &str, spawn_body: &str) -> String {
+    format!(
+        r#"{transpiled}
+fn main() {{
+    let mut handles = Vec::new();
+    for _ in 0..{THREAD_COUNT} {{
+        handles.push(std::thread::spawn(|| {{
+            for _ in 0..{ITERATIONS_PER_THREAD} {{
+                {spawn_body}
+            }}
+        }}));
+    }}
+    for handle in handles {{
+        handle.join().unwrap();
+    }}
+}}
+"#
+    )
+}
+
+/// Compiles `source` under ThreadSanitizer and runs the resulting binary,
+/// returning its output. Returns `Err` if `rustc` itself fails (a bug in the
+/// harness or the transpiled code, not a race finding).
+fn compile_and_run_under_tsan(source: &str) -> Result<Output, String> {
+    let unique_id = std::process::id();
+    let temp_dir = std::env::temp_dir();
+    let src_path = temp_dir.join(format!("decy_tsan_{}.rs", unique_id));
+    let bin_path = temp_dir.join(format!("decy_tsan_{}", unique_id));
+
+    std::fs::write(&src_path, source).map_err(|e| format!("Failed to write temp file: {e}"))?;
+
+    // `-Z sanitizer=thread` requires the nightly feature gate; RUSTC_BOOTSTRAP
+    // lets a stable-labeled toolchain built from nightly sources accept it,
+    // matching how sanitizer CI jobs are typically invoked.
+    let compile_output = Command::new("rustc")
+        .env("RUSTC_BOOTSTRAP", "1")
+        .arg("--edition=2021")
+        .arg("-Z")
+        .arg("sanitizer=thread")
+        .arg("-o")
+        .arg(&bin_path)
+        .arg(&src_path)
+        .output()
+        .map_err(|e| format!("Failed to invoke rustc: {e}"))?;
+
+    let cleanup = || {
+        let _ = std::fs::remove_file(&src_path);
+        let _ = std::fs::remove_file(&bin_path);
+    };
+
+    if !compile_output.status.success() {
+        cleanup();
+        return Err(format!(
+            "rustc failed to compile the TSAN harness:\n{}",
+            String::from_utf8_lossy(&compile_output.stderr)
+        ));
+    }
+
+    let run_output = Command::new(&bin_path)
+        .env(
+            "TSAN_OPTIONS",
+            format!(
+                "suppressions={} halt_on_error=1",
+                tsan_suppressions_path().display()
+            ),
+        )
+        .output()
+        .map_err(|e| format!("Failed to run the compiled binary: {e}"));
+
+    cleanup();
+    run_output
+}
+
+fn assert_no_data_race(output: &Output) {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("ThreadSanitizer: data race"),
+        "ThreadSanitizer reported a data race:\n{stderr}"
+    );
+}
+
+#[test]
+fn test_atomic_counter_is_race_free_under_tsan() {
+    let c_code = r#"
+        int counter = 0;
+
+        void increment() {
+            counter = counter + 1;
+        }
+
+        void decrement() {
+            counter = counter - 1;
+        }
+
+        int run_once() {
+            increment();
+            decrement();
+            return counter;
+        }
+    "#;
+
+    let transpiled = transpile_with_atomic_globals(c_code).expect("Should transpile");
+    let harness = build_tsan_harness(&transpiled, "increment(); decrement();");
+
+    let output = compile_and_run_under_tsan(&harness)
+        .expect("Should compile and run the atomic counter harness under ThreadSanitizer");
+    assert_no_data_race(&output);
+}
+
+#[test]
+fn test_atomic_producer_consumer_is_race_free_under_tsan() {
+    let c_code = r#"
+        int items_produced = 0;
+        int items_consumed = 0;
+
+        void produce() {
+            items_produced = items_produced + 1;
+        }
+
+        void consume() {
+            items_consumed = items_consumed + 1;
+        }
+
+        int run_once() {
+            produce();
+            consume();
+            return items_produced - items_consumed;
+        }
+    "#;
+
+    let transpiled = transpile_with_atomic_globals(c_code).expect("Should transpile");
+    let harness = build_tsan_harness(&transpiled, "produce(); consume();");
+
+    let output = compile_and_run_under_tsan(&harness)
+        .expect("Should compile and run the producer/consumer harness under ThreadSanitizer");
+    assert_no_data_race(&output);
+}
+
+#[test]
+fn test_mutex_struct_global_is_race_free_under_tsan() {
+    let c_code = r#"
+        struct SharedData {
+            int counter;
+            int flag;
+        };
+
+        struct SharedData shared;
+
+        int run_once() {
+            shared.counter = 1;
+            shared.flag = 1;
+            return shared.counter;
+        }
+    "#;
+
+    let transpiled = transpile_with_struct_mutex(c_code).expect("Should transpile");
+    let harness = build_tsan_harness(
+        &transpiled,
+        "{ let mut g = shared.lock().unwrap(); g.counter += 1; g.flag = 1; }",
+    );
+
+    let output = compile_and_run_under_tsan(&harness)
+        .expect("Should compile and run the Mutex struct global harness under ThreadSanitizer");
+    assert_no_data_race(&output);
+}
+
+/// Sanity check on the harness itself: the *unlowered* transpile of the same
+/// counter fixture uses a plain `static mut` with no synchronization, so
+/// ThreadSanitizer must actually flag it. If this test stops failing, the
+/// harness has stopped detecting races and the tests above are vacuous.
+#[test]
+fn test_unlowered_counter_is_flagged_by_tsan() {
+    let c_code = r#"
+        int counter = 0;
+
+        void increment() {
+            counter = counter + 1;
+        }
+
+        int run_once() {
+            increment();
+            return counter;
+        }
+    "#;
+
+    let transpiled = transpile(c_code).expect("Should transpile");
+    let harness = build_tsan_harness(&transpiled, "increment();");
+
+    let output = compile_and_run_under_tsan(&harness)
+        .expect("Should compile and run the unlowered counter harness under ThreadSanitizer");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("ThreadSanitizer: data race"),
+        "Expected ThreadSanitizer to flag the unsynchronized static mut counter, got:\n{stderr}"
+    );
+}
@@ -108,12 +108,25 @@ impl HirType {
 pub struct HirStructField {
     name: String,
     field_type: HirType,
+    /// Bit-width for a C bitfield member (`unsigned x : 20;`), or `None` for
+    /// an ordinary, byte-addressed field.
+    bit_width: Option<u32>,
 }
 
 impl HirStructField {
-    /// Create a new struct field.
+    /// Create a new (non-bitfield) struct field.
     pub fn new(name: String, field_type: HirType) -> Self {
-        Self { name, field_type }
+        Self {
+            name,
+            field_type,
+            bit_width: None,
+        }
+    }
+
+    /// Mark this field as a bitfield of the given width.
+    pub fn with_bit_width(mut self, bits: u32) -> Self {
+        self.bit_width = Some(bits);
+        self
     }
 
     /// Get the field name.
@@ -125,6 +138,11 @@ impl HirStructField {
     pub fn field_type(&self) -> &HirType {
         &self.field_type
     }
+
+    /// Get the bitfield width, if this field is a C bitfield member.
+    pub fn bit_width(&self) -> Option<u32> {
+        self.bit_width
+    }
 }
 
 /// Represents a struct definition in HIR.
@@ -541,6 +559,15 @@ pub enum HirExpression {
         /// New size expression (typically n * sizeof(T))
         new_size: Box<HirExpression>,
     },
+    /// Ternary/conditional expression (cond ? then : else → if cond { then } else { else })
+    Ternary {
+        /// Condition expression
+        condition: Box<HirExpression>,
+        /// Expression evaluated when the condition is true
+        then_expr: Box<HirExpression>,
+        /// Expression evaluated when the condition is false
+        else_expr: Box<HirExpression>,
+    },
 }
 
 /// Represents a single case in a switch statement.
@@ -820,6 +847,15 @@ impl HirExpression {
             Expression::Sizeof { type_name } => HirExpression::Sizeof {
                 type_name: type_name.clone(),
             },
+            Expression::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+            } => HirExpression::Ternary {
+                condition: Box::new(HirExpression::from_ast_expression(condition)),
+                then_expr: Box::new(HirExpression::from_ast_expression(then_expr)),
+                else_expr: Box::new(HirExpression::from_ast_expression(else_expr)),
+            },
         }
     }
 }
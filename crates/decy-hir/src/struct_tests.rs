@@ -122,3 +122,17 @@ fn test_enum_type_variant() {
         _ => panic!("Expected Enum type"),
     }
 }
+
+#[test]
+fn test_hir_struct_field_with_bit_width() {
+    let field = HirStructField::new("flag".to_string(), HirType::Int).with_bit_width(1);
+
+    assert_eq!(field.bit_width(), Some(1));
+}
+
+#[test]
+fn test_hir_struct_field_defaults_to_no_bit_width() {
+    let field = HirStructField::new("value".to_string(), HirType::Int);
+
+    assert_eq!(field.bit_width(), None);
+}
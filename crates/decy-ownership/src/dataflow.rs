@@ -3,7 +3,7 @@
 //! This module builds a dataflow graph that tracks how pointers flow through
 //! functions, enabling detection of ownership patterns and use-after-free issues.
 
-use decy_hir::{HirExpression, HirFunction, HirStatement, HirType};
+use decy_hir::{BinaryOperator, HirExpression, HirFunction, HirStatement, HirType};
 use std::collections::{HashMap, HashSet};
 
 /// Represents a node in the dataflow graph (a pointer variable or operation).
@@ -43,6 +43,154 @@ pub enum NodeKind {
     },
 }
 
+/// Mutability classification for an inferred array/slice parameter.
+/// DECY-073: Lets the emitter choose `&[T]` vs `&mut [T]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mutability {
+    /// The parameter is only read from; safe to emit as `&[T]`.
+    Shared,
+    /// The parameter is written to somewhere in the body; must emit as `&mut [T]`.
+    Mut,
+}
+
+/// Ownership-transfer classification for an inferred array/slice parameter.
+/// DECY-075: Lets the emitter choose a borrowed `&[T]`/`&mut [T]` vs an
+/// owned `Vec<T>`/`Box<[T]>` moved into the function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ownership {
+    /// The parameter is only used within the function; safe to emit as a borrow.
+    Borrowed,
+    /// The body frees the parameter, reallocates it, or returns it to the
+    /// caller; the function takes ownership, so it must be emitted as an
+    /// owned `Vec<T>`/`Box<[T]>` rather than a borrow.
+    OwnedConsumed,
+}
+
+/// Confidence breakdown for a candidate array parameter, returned by
+/// [`DataflowGraph::array_parameter_confidence`].
+///
+/// DECY-076: Carries the individual signals `is_array_parameter` collapses
+/// into a single bool, so callers can explain *why* a parameter was (or
+/// wasn't) detected as an array, or apply their own threshold over `score`.
+///
+/// DECY-080: Also scores the `f(int count, T* buf)` ordering (count-before-
+/// pointer), not just the more common `f(T* buf, int len)` ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ArrayConfidence {
+    /// Total weighted score; the sum of the individual signal weights below.
+    pub score: i32,
+    /// Number of signals (positive or negative) that fired.
+    pub signal_count: u32,
+    /// Parameter name matches a common array name (`arr`, `buf`, `data`, `items`).
+    pub common_array_name: bool,
+    /// Immediately followed by an integer-typed parameter (likely a length).
+    pub followed_by_int_length: bool,
+    /// The following parameter's name matches a common length name
+    /// (`len`, `size`, `count`, `num`).
+    pub common_length_name: bool,
+    /// Immediately preceded by an integer-typed parameter (the
+    /// `f(int count, T* buf)` ordering).
+    pub preceded_by_int_length: bool,
+    /// The preceding parameter's name matches a common length name
+    /// (`len`, `size`, `count`, `num`).
+    pub preceded_by_common_length_name: bool,
+    /// Body evidence of indexed access: `arr[i]`, `arr[i] = v`, or `*(arr + i)`.
+    pub body_indexing_evidence: bool,
+    /// Body evidence of pointer arithmetic that is not indexed access
+    /// (negative signal - suggests non-array pointer usage).
+    pub pointer_arithmetic_evidence: bool,
+    /// The parameter's pointee type is a plausible array element type
+    /// (not a struct pointer, which is ambiguous without more context).
+    pub element_type_plausible: bool,
+}
+
+impl ArrayConfidence {
+    /// Whether this confidence clears the detector's default threshold:
+    /// at least 2 contributing signals and a score of at least 3.
+    /// This is exactly the threshold `is_array_parameter` has always used.
+    pub fn is_likely_array(&self) -> bool {
+        self.signal_count >= 2 && self.score >= 3
+    }
+}
+
+/// Role classification for a non-array pointer parameter, returned by
+/// [`DataflowGraph::pointer_role`].
+///
+/// DECY-077: Keeps opaque handles and write-only out-params from being
+/// mis-lowered as slices (or lumped together as a plain `Some(false)` from
+/// [`DataflowGraph::is_array_parameter`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PointerRole {
+    /// Detected as an array parameter; see [`DataflowGraph::is_array_parameter`].
+    Slice,
+    /// Written through (`*p = ...` / `p->field = ...`) but never read; the
+    /// emitter can hoist this into a return value instead of an out-param.
+    OutParam,
+    /// A `void*`/unknown-struct pointer with no dereference or field-access
+    /// evidence; lower to `*mut c_void` rather than guessing a pointee type.
+    Opaque,
+    /// Dereferenced or field-accessed for reading (with or without also
+    /// being written to), but not detected as an array; a single `&T`/`&mut T`.
+    SingleRef,
+}
+
+/// The length parameter paired with a detected array parameter, returned by
+/// [`DataflowGraph::array_length_binding`].
+///
+/// DECY-078: Exposes the detected array/length pairing so the emitter can
+/// drop the redundant length argument from the generated signature and
+/// rewrite body reads of it to `slice.len()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LengthBinding {
+    /// Name of the paired length parameter.
+    pub length_param: String,
+    /// Index of the length parameter in the function's parameter list.
+    pub length_param_index: usize,
+    /// Whether the body reads the length parameter's value anywhere (so that
+    /// use can be rewritten to `slice.len()`).
+    pub length_is_read: bool,
+}
+
+/// Identifier of a struct field, used by [`ArrayKind::LengthBoundByStructField`].
+pub type FieldId = String;
+
+/// Which source (if any) carries the element count for a detected array
+/// parameter, returned by [`DataflowGraph::array_kind`].
+///
+/// DECY-079: Turns the plain bool from [`DataflowGraph::is_array_parameter`]
+/// into a usable signal for idiomatic slice generation - code-gen can emit a
+/// real `&[T]`/`&mut [T]` with an `assert_eq!` derived from the bound source
+/// instead of a raw `*const T`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArrayKind {
+    /// Length is carried by another parameter, at this index in the
+    /// function's parameter list. See [`DataflowGraph::array_length_binding`].
+    LengthBoundByParam(usize),
+    /// Length is a fixed compile-time constant.
+    LengthBoundByConstant(u64),
+    /// Length is read from a field on a struct parameter.
+    LengthBoundByStructField(FieldId),
+    /// Not detected as an array parameter, or detected but with no bound
+    /// source that could be resolved.
+    Unknown,
+}
+
+/// How a pointer parameter with no detected length parameter determines
+/// where its data ends, returned by [`DataflowGraph::termination_style`].
+///
+/// DECY-081: Distinguishes sentinel-terminated buffers from the opaque/
+/// single-ref pointers [`DataflowGraph::pointer_role`] would otherwise
+/// classify them as, so the emitter can scan to the terminator instead of
+/// guessing a length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminationStyle {
+    /// Scanned until a NUL byte, the C string convention; emit `&CStr`/`&str`.
+    NulTerminated,
+    /// Scanned until a fixed-size trailing sentinel block, measured in bytes
+    /// of the pointee's element type.
+    SentinelTerminated(usize),
+}
+
 /// Dataflow graph tracking pointer dependencies.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct DataflowGraph {
@@ -107,34 +255,51 @@ impl DataflowGraph {
     /// Check if a parameter is an array pointer (has associated length parameter).
     /// DECY-071 GREEN: Proper implementation with multiple heuristics
     /// Detects the pattern: fn(int* arr, int len) where pointer param followed by int param
+    ///
+    /// DECY-076: Thin wrapper around [`Self::array_parameter_confidence`] that
+    /// applies the detector's default threshold. Use the confidence variant
+    /// directly to see the individual signals or to apply a different cutoff.
     pub fn is_array_parameter(&self, var: &str) -> Option<bool> {
+        self.array_parameter_confidence(var)
+            .map(|confidence| confidence.is_likely_array())
+    }
+
+    /// Compute the full array-parameter confidence breakdown for a parameter.
+    /// DECY-076 GREEN: Exposes the individual signals that `is_array_parameter`
+    /// collapses into a single bool, so downstream tooling can explain *why*
+    /// a parameter was (or wasn't) treated as an array, and can apply a
+    /// different threshold than [`ArrayConfidence::is_likely_array`]'s default.
+    pub fn array_parameter_confidence(&self, var: &str) -> Option<ArrayConfidence> {
         // Find the parameter in the parameter list
         let param_index = self.parameters.iter().position(|p| p.name() == var)?;
         let param = &self.parameters[param_index];
 
         // Only check pointer parameters
         if !matches!(param.param_type(), HirType::Pointer(_)) {
-            return Some(false);
+            return Some(ArrayConfidence::default());
         }
 
         // Conservative: Don't detect struct pointers as arrays
         // Struct arrays are ambiguous without more context
         if let HirType::Pointer(inner) = param.param_type() {
             if matches!(**inner, HirType::Struct(_)) {
-                return Some(false);
+                return Some(ArrayConfidence::default());
             }
         }
 
-        let mut confidence = 0;
-        let mut signals = 0;
+        let mut confidence = ArrayConfidence {
+            element_type_plausible: true,
+            ..ArrayConfidence::default()
+        };
 
         // Heuristic 1: Check if followed by an integer parameter (length param)
         // Pattern: (T* arr, int len) or (T* arr, size_t size)
         if param_index + 1 < self.parameters.len() {
             let next_param = &self.parameters[param_index + 1];
             if matches!(next_param.param_type(), HirType::Int) {
-                confidence += 3; // Strong signal
-                signals += 1;
+                confidence.followed_by_int_length = true;
+                confidence.score += 3; // Strong signal
+                confidence.signal_count += 1;
             }
         }
 
@@ -147,8 +312,9 @@ impl DataflowGraph {
             || param_name == "data"
             || param_name == "items"
         {
-            confidence += 2; // Moderate signal
-            signals += 1;
+            confidence.common_array_name = true;
+            confidence.score += 2; // Moderate signal
+            confidence.signal_count += 1;
         }
 
         // Check if next param has length-like name
@@ -159,28 +325,588 @@ impl DataflowGraph {
                 || next_name.contains("count")
                 || next_name.contains("num")
             {
-                confidence += 2; // Moderate signal
-                signals += 1;
+                confidence.common_length_name = true;
+                confidence.score += 2; // Moderate signal
+                confidence.signal_count += 1;
+            }
+        }
+
+        // Heuristic 1b (DECY-080): Check if preceded by an integer parameter
+        // (length param). Pattern: (int count, T* buf) - real C APIs use this
+        // ordering as often as the trailing-length one above.
+        if param_index > 0 {
+            let prev_param = &self.parameters[param_index - 1];
+            if matches!(prev_param.param_type(), HirType::Int) {
+                confidence.preceded_by_int_length = true;
+                confidence.score += 3; // Strong signal
+                confidence.signal_count += 1;
+            }
+
+            let prev_name = prev_param.name().to_lowercase();
+            if prev_name.contains("len")
+                || prev_name.contains("size")
+                || prev_name.contains("count")
+                || prev_name.contains("num")
+            {
+                confidence.preceded_by_common_length_name = true;
+                confidence.score += 2; // Moderate signal
+                confidence.signal_count += 1;
             }
         }
 
-        // Heuristic 3: Check for array indexing usage in function body
-        if self.has_array_indexing(var) {
-            confidence += 3; // Strong signal
-            signals += 1;
+        // Heuristic 3: Check for array indexing usage in function body.
+        // DECY-074: Also counts `*(p + i)` / `*(p - i)` pointer-offset
+        // dereferences, the shape real decompiled HIR uses for
+        // moving-pointer iteration instead of an explicit index variable.
+        if self.has_array_indexing(var) || self.has_indexed_dereference(var) {
+            confidence.body_indexing_evidence = true;
+            confidence.score += 3; // Strong signal
+            confidence.signal_count += 1;
         }
 
         // Heuristic 4: Check for pointer arithmetic (negative signal)
         if self.has_pointer_arithmetic(var) {
-            confidence -= 2; // Pointer arithmetic suggests non-array usage
-            signals += 1;
+            confidence.pointer_arithmetic_evidence = true;
+            confidence.score -= 2; // Pointer arithmetic suggests non-array usage
+            confidence.signal_count += 1;
         }
 
-        // Decision: require at least 2 signals and positive confidence
-        if signals >= 2 && confidence >= 3 {
-            Some(true)
+        Some(confidence)
+    }
+
+    /// Classify the mutability of a detected array parameter.
+    /// DECY-073 GREEN: Walks the body for writes through the parameter -
+    /// either `arr[i] = value` or `*(p + i) = value` - and marks `Mut` if
+    /// any are found; otherwise defaults to `Shared`.
+    ///
+    /// Returns `None` if `var` is not a detected array parameter (see
+    /// [`Self::is_array_parameter`]).
+    pub fn array_mutability(&self, var: &str) -> Option<Mutability> {
+        if !matches!(self.is_array_parameter(var), Some(true)) {
+            return None;
+        }
+
+        if self.has_array_indexing(var) || self.has_pointer_arithmetic_write(var) {
+            Some(Mutability::Mut)
         } else {
-            Some(false)
+            Some(Mutability::Shared)
+        }
+    }
+
+    /// Check if a variable is written to via pointer arithmetic (`*(p + i) = value`)
+    /// in the function body.
+    /// DECY-073 GREEN: Helper for array mutability classification
+    fn has_pointer_arithmetic_write(&self, var: &str) -> bool {
+        for stmt in &self.body {
+            if self.statement_has_pointer_arithmetic_write(stmt, var) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Recursively check if a statement writes through pointer arithmetic for a variable.
+    fn statement_has_pointer_arithmetic_write(&self, stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::DerefAssignment { target, .. } => {
+                // `target` is the pointer written through; a target wrapped
+                // in `Dereference` is accepted as the same write.
+                let pointer = match target {
+                    HirExpression::Dereference(inner) => &**inner,
+                    other => other,
+                };
+                self.expression_has_pointer_arithmetic(pointer, var)
+            }
+            HirStatement::If {
+                then_block,
+                else_block,
+                ..
+            } => {
+                then_block
+                    .iter()
+                    .any(|s| self.statement_has_pointer_arithmetic_write(s, var))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter()
+                            .any(|s| self.statement_has_pointer_arithmetic_write(s, var))
+                    })
+            }
+            HirStatement::While { body, .. } | HirStatement::For { body, .. } => body
+                .iter()
+                .any(|s| self.statement_has_pointer_arithmetic_write(s, var)),
+            _ => false,
+        }
+    }
+
+    /// Classify the ownership-transfer status of a detected array parameter.
+    /// DECY-075 GREEN: Walks the body for operations that consume the
+    /// parameter - `free(p)`, `realloc(p, ...)`, or `return p;` - and marks
+    /// `OwnedConsumed` if any are found; otherwise defaults to `Borrowed`.
+    ///
+    /// Returns `None` if `var` is not a detected array parameter (see
+    /// [`Self::is_array_parameter`]).
+    pub fn array_ownership(&self, var: &str) -> Option<Ownership> {
+        if !matches!(self.is_array_parameter(var), Some(true)) {
+            return None;
+        }
+
+        if self.is_consumed(var) {
+            Some(Ownership::OwnedConsumed)
+        } else {
+            Some(Ownership::Borrowed)
+        }
+    }
+
+    /// Check if a variable is consumed (freed, reallocated, or returned) in the function body.
+    /// DECY-075 GREEN: Helper for ownership-transfer classification
+    fn is_consumed(&self, var: &str) -> bool {
+        self.body.iter().any(|stmt| self.statement_consumes(stmt, var))
+    }
+
+    /// Recursively check if a statement consumes a variable.
+    fn statement_consumes(&self, stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::Free { pointer } => Self::expr_is_var(pointer, var),
+            HirStatement::Return(Some(expr)) => Self::expr_is_var(expr, var),
+            HirStatement::VariableDeclaration {
+                initializer: Some(expr),
+                ..
+            } => Self::expression_reallocates(expr, var),
+            HirStatement::Assignment { value, .. } => Self::expression_reallocates(value, var),
+            HirStatement::If {
+                then_block,
+                else_block,
+                ..
+            } => {
+                then_block.iter().any(|s| self.statement_consumes(s, var))
+                    || else_block
+                        .as_ref()
+                        .is_some_and(|blk| blk.iter().any(|s| self.statement_consumes(s, var)))
+            }
+            HirStatement::While { body, .. } | HirStatement::For { body, .. } => {
+                body.iter().any(|s| self.statement_consumes(s, var))
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether `expr` is `realloc(var, ...)`, which consumes `var`.
+    fn expression_reallocates(expr: &HirExpression, var: &str) -> bool {
+        matches!(expr, HirExpression::Realloc { pointer, .. } if Self::expr_is_var(pointer, var))
+    }
+
+    /// Check whether `expr` is exactly the variable `var`.
+    fn expr_is_var(expr: &HirExpression, var: &str) -> bool {
+        matches!(expr, HirExpression::Variable(name) if name == var)
+    }
+
+    /// Classify the role of a non-array pointer parameter.
+    /// DECY-077 GREEN: Distinguishes opaque handles and write-only
+    /// out-params from array parameters, which previously all collapsed
+    /// to `Some(false)` under [`Self::is_array_parameter`].
+    ///
+    /// Returns `None` if `var` is not a pointer parameter.
+    pub fn pointer_role(&self, var: &str) -> Option<PointerRole> {
+        let param = self.parameters.iter().find(|p| p.name() == var)?;
+        let HirType::Pointer(inner) = param.param_type() else {
+            return None;
+        };
+
+        if matches!(self.is_array_parameter(var), Some(true)) {
+            return Some(PointerRole::Slice);
+        }
+
+        let is_void = matches!(**inner, HirType::Void);
+        let writes = self.has_pointer_write(var);
+        let reads = self.has_pointer_read(var);
+
+        if writes && !reads {
+            return Some(PointerRole::OutParam);
+        }
+
+        if is_void || (!writes && !reads) {
+            return Some(PointerRole::Opaque);
+        }
+
+        Some(PointerRole::SingleRef)
+    }
+
+    /// Check if a variable is written through (`*p = value` or
+    /// `p->field = value`) anywhere in the function body.
+    /// DECY-077 GREEN: Helper for pointer role classification
+    fn has_pointer_write(&self, var: &str) -> bool {
+        self.body
+            .iter()
+            .any(|stmt| self.statement_has_pointer_write(stmt, var))
+    }
+
+    /// Recursively check if a statement writes through a pointer variable.
+    fn statement_has_pointer_write(&self, stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::DerefAssignment { target, .. } => {
+                Self::expr_is_var(target, var)
+                    || matches!(target, HirExpression::Dereference(inner) if Self::expr_is_var(inner, var))
+            }
+            HirStatement::FieldAssignment { object, .. } => Self::expr_is_var(object, var),
+            HirStatement::If {
+                then_block,
+                else_block,
+                ..
+            } => {
+                then_block
+                    .iter()
+                    .any(|s| self.statement_has_pointer_write(s, var))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter().any(|s| self.statement_has_pointer_write(s, var))
+                    })
+            }
+            HirStatement::While { body, .. } | HirStatement::For { body, .. } => {
+                body.iter().any(|s| self.statement_has_pointer_write(s, var))
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if a variable is dereferenced or field-accessed for reading
+    /// anywhere in the function body.
+    /// DECY-077 GREEN: Helper for pointer role classification
+    fn has_pointer_read(&self, var: &str) -> bool {
+        self.body
+            .iter()
+            .any(|stmt| self.statement_has_pointer_read(stmt, var))
+    }
+
+    /// Recursively check if a statement reads through a pointer variable.
+    /// The target of a `DerefAssignment` is excluded (that is a write, not a
+    /// read), but its value expression and every other statement's
+    /// expressions are scanned.
+    fn statement_has_pointer_read(&self, stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::VariableDeclaration {
+                initializer: Some(expr),
+                ..
+            } => Self::expression_has_pointer_read(expr, var),
+            HirStatement::VariableDeclaration {
+                initializer: None, ..
+            } => false,
+            HirStatement::Assignment { value, .. } => {
+                Self::expression_has_pointer_read(value, var)
+            }
+            HirStatement::DerefAssignment { value, .. } => {
+                Self::expression_has_pointer_read(value, var)
+            }
+            HirStatement::ArrayIndexAssignment {
+                array,
+                index,
+                value,
+            } => {
+                Self::expression_has_pointer_read(array, var)
+                    || Self::expression_has_pointer_read(index, var)
+                    || Self::expression_has_pointer_read(value, var)
+            }
+            HirStatement::FieldAssignment { value, .. } => {
+                Self::expression_has_pointer_read(value, var)
+            }
+            HirStatement::If {
+                condition,
+                then_block,
+                else_block,
+            } => {
+                Self::expression_has_pointer_read(condition, var)
+                    || then_block
+                        .iter()
+                        .any(|s| self.statement_has_pointer_read(s, var))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter().any(|s| self.statement_has_pointer_read(s, var))
+                    })
+            }
+            HirStatement::While { condition, body } => {
+                Self::expression_has_pointer_read(condition, var)
+                    || body.iter().any(|s| self.statement_has_pointer_read(s, var))
+            }
+            HirStatement::For {
+                init,
+                condition,
+                increment,
+                body,
+            } => {
+                init.as_deref()
+                    .is_some_and(|s| self.statement_has_pointer_read(s, var))
+                    || Self::expression_has_pointer_read(condition, var)
+                    || increment
+                        .as_deref()
+                        .is_some_and(|s| self.statement_has_pointer_read(s, var))
+                    || body.iter().any(|s| self.statement_has_pointer_read(s, var))
+            }
+            HirStatement::Return(Some(expr)) => Self::expression_has_pointer_read(expr, var),
+            HirStatement::Return(None) | HirStatement::Break | HirStatement::Continue => false,
+            HirStatement::Switch {
+                condition,
+                cases,
+                default_case,
+            } => {
+                Self::expression_has_pointer_read(condition, var)
+                    || cases.iter().any(|c| {
+                        c.body
+                            .iter()
+                            .any(|s| self.statement_has_pointer_read(s, var))
+                    })
+                    || default_case.as_ref().is_some_and(|stmts| {
+                        stmts.iter().any(|s| self.statement_has_pointer_read(s, var))
+                    })
+            }
+            HirStatement::Free { pointer } => Self::expression_has_pointer_read(pointer, var),
+            HirStatement::Expression(expr) => Self::expression_has_pointer_read(expr, var),
+        }
+    }
+
+    /// Recursively check if an expression dereferences or field-accesses a
+    /// pointer variable for reading.
+    fn expression_has_pointer_read(expr: &HirExpression, var: &str) -> bool {
+        match expr {
+            HirExpression::Dereference(inner) => {
+                Self::expr_is_var(inner, var) || Self::expression_has_pointer_read(inner, var)
+            }
+            HirExpression::PointerFieldAccess { pointer, .. } => {
+                Self::expr_is_var(pointer, var) || Self::expression_has_pointer_read(pointer, var)
+            }
+            HirExpression::AddressOf(inner) | HirExpression::IsNotNull(inner) => {
+                Self::expression_has_pointer_read(inner, var)
+            }
+            HirExpression::UnaryOp { operand, .. } => {
+                Self::expression_has_pointer_read(operand, var)
+            }
+            HirExpression::BinaryOp { left, right, .. } => {
+                Self::expression_has_pointer_read(left, var)
+                    || Self::expression_has_pointer_read(right, var)
+            }
+            HirExpression::FunctionCall { arguments, .. } => arguments
+                .iter()
+                .any(|a| Self::expression_has_pointer_read(a, var)),
+            HirExpression::FieldAccess { object, .. } => {
+                Self::expression_has_pointer_read(object, var)
+            }
+            HirExpression::ArrayIndex { array, index } => {
+                Self::expression_has_pointer_read(array, var)
+                    || Self::expression_has_pointer_read(index, var)
+            }
+            HirExpression::Calloc { count, .. } => Self::expression_has_pointer_read(count, var),
+            HirExpression::Malloc { size } => Self::expression_has_pointer_read(size, var),
+            HirExpression::Realloc { pointer, new_size } => {
+                Self::expression_has_pointer_read(pointer, var)
+                    || Self::expression_has_pointer_read(new_size, var)
+            }
+            _ => false,
+        }
+    }
+
+    /// Classify a pointer parameter that has no detected length parameter as
+    /// NUL-terminated or sentinel-terminated, based on how the body reads it.
+    /// DECY-081 GREEN: A pointer already paired with a length parameter
+    /// doesn't need a terminator, so this only looks at parameters where
+    /// [`Self::is_array_parameter`] is `Some(false)`.
+    ///
+    /// Returns `None` if `var` is not a pointer parameter, if it's already
+    /// length-bound, or if no termination evidence is found.
+    pub fn termination_style(&self, var: &str) -> Option<TerminationStyle> {
+        let param = self.parameters.iter().find(|p| p.name() == var)?;
+        let HirType::Pointer(inner) = param.param_type() else {
+            return None;
+        };
+
+        if matches!(self.is_array_parameter(var), Some(true)) {
+            return None;
+        }
+
+        if !self.has_strlen_family_call(var) && !self.has_zero_terminator_check(var) {
+            return None;
+        }
+
+        if matches!(**inner, HirType::Char) {
+            return Some(TerminationStyle::NulTerminated);
+        }
+
+        Self::element_byte_size(inner).map(TerminationStyle::SentinelTerminated)
+    }
+
+    /// The size, in bytes, of a primitive pointee type - used to size a
+    /// sentinel block for [`Self::termination_style`].
+    fn element_byte_size(elem_type: &HirType) -> Option<usize> {
+        match elem_type {
+            HirType::Char => Some(1),
+            HirType::Int => Some(4),
+            HirType::Float => Some(4),
+            HirType::Double => Some(8),
+            _ => None,
+        }
+    }
+
+    /// Check if a variable is passed to a `strlen`-family call anywhere in
+    /// the function body - a strong signal that it's a NUL-terminated
+    /// C string.
+    /// DECY-081 GREEN: Helper for termination-style classification
+    fn has_strlen_family_call(&self, var: &str) -> bool {
+        const STRLEN_FAMILY: &[&str] = &[
+            "strlen", "strcpy", "strncpy", "strcmp", "strncmp", "strcat", "strncat", "strchr",
+            "strrchr", "strdup",
+        ];
+
+        self.body.iter().any(|stmt| {
+            Self::statement_has_call_with_arg(stmt, var, &|name| STRLEN_FAMILY.contains(&name))
+        })
+    }
+
+    /// Check if a variable's pointee is compared against zero anywhere in a
+    /// loop condition (`while (*p) { ... }` or `while (*p != 0) { ... }`) -
+    /// the sentinel-scan idiom.
+    /// DECY-081 GREEN: Helper for termination-style classification
+    fn has_zero_terminator_check(&self, var: &str) -> bool {
+        self.body
+            .iter()
+            .any(|stmt| self.statement_has_zero_terminator_check(stmt, var))
+    }
+
+    /// Recursively check if a statement contains a loop whose condition
+    /// compares `*var` against zero.
+    fn statement_has_zero_terminator_check(&self, stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::While { condition, body } => {
+                Self::expression_is_zero_terminator_check(condition, var)
+                    || body
+                        .iter()
+                        .any(|s| self.statement_has_zero_terminator_check(s, var))
+            }
+            HirStatement::For {
+                condition, body, ..
+            } => {
+                Self::expression_is_zero_terminator_check(condition, var)
+                    || body
+                        .iter()
+                        .any(|s| self.statement_has_zero_terminator_check(s, var))
+            }
+            HirStatement::If {
+                then_block,
+                else_block,
+                ..
+            } => {
+                then_block
+                    .iter()
+                    .any(|s| self.statement_has_zero_terminator_check(s, var))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter()
+                            .any(|s| self.statement_has_zero_terminator_check(s, var))
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether `expr` is `*var == 0`, `*var != 0`, or bare `*var` used
+    /// as a boolean condition (all equivalent to "scan until NUL").
+    fn expression_is_zero_terminator_check(expr: &HirExpression, var: &str) -> bool {
+        match expr {
+            HirExpression::Dereference(inner) => Self::expr_is_var(inner, var),
+            HirExpression::UnaryOp {
+                op: decy_hir::UnaryOperator::LogicalNot,
+                operand,
+            } => Self::expression_is_zero_terminator_check(operand, var),
+            HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::Equal | decy_hir::BinaryOperator::NotEqual,
+                left,
+                right,
+            } => {
+                let deref_matches = |e: &HirExpression| {
+                    matches!(e, HirExpression::Dereference(inner) if Self::expr_is_var(inner, var))
+                };
+                let is_zero = |e: &HirExpression| matches!(e, HirExpression::IntLiteral(0));
+
+                (deref_matches(left) && is_zero(right)) || (deref_matches(right) && is_zero(left))
+            }
+            _ => false,
+        }
+    }
+
+    /// Recursively check if a statement contains a function call whose
+    /// argument list includes `var`, where the function name passes `pred`.
+    fn statement_has_call_with_arg(
+        stmt: &HirStatement,
+        var: &str,
+        pred: &dyn Fn(&str) -> bool,
+    ) -> bool {
+        match stmt {
+            HirStatement::VariableDeclaration {
+                initializer: Some(expr),
+                ..
+            } => Self::expression_has_call_with_arg(expr, var, pred),
+            HirStatement::Assignment { value, .. } => {
+                Self::expression_has_call_with_arg(value, var, pred)
+            }
+            HirStatement::Expression(expr) => Self::expression_has_call_with_arg(expr, var, pred),
+            HirStatement::If {
+                condition,
+                then_block,
+                else_block,
+            } => {
+                Self::expression_has_call_with_arg(condition, var, pred)
+                    || then_block
+                        .iter()
+                        .any(|s| Self::statement_has_call_with_arg(s, var, pred))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter()
+                            .any(|s| Self::statement_has_call_with_arg(s, var, pred))
+                    })
+            }
+            HirStatement::While { condition, body } => {
+                Self::expression_has_call_with_arg(condition, var, pred)
+                    || body
+                        .iter()
+                        .any(|s| Self::statement_has_call_with_arg(s, var, pred))
+            }
+            HirStatement::For {
+                init,
+                condition,
+                increment,
+                body,
+            } => {
+                init.as_deref()
+                    .is_some_and(|s| Self::statement_has_call_with_arg(s, var, pred))
+                    || Self::expression_has_call_with_arg(condition, var, pred)
+                    || increment
+                        .as_deref()
+                        .is_some_and(|s| Self::statement_has_call_with_arg(s, var, pred))
+                    || body
+                        .iter()
+                        .any(|s| Self::statement_has_call_with_arg(s, var, pred))
+            }
+            HirStatement::Return(Some(expr)) => Self::expression_has_call_with_arg(expr, var, pred),
+            _ => false,
+        }
+    }
+
+    /// Recursively check if an expression contains a function call whose
+    /// argument list includes `var`, where the function name passes `pred`.
+    fn expression_has_call_with_arg(
+        expr: &HirExpression,
+        var: &str,
+        pred: &dyn Fn(&str) -> bool,
+    ) -> bool {
+        match expr {
+            HirExpression::FunctionCall { function, arguments } => {
+                (pred(function) && arguments.iter().any(|a| Self::expr_is_var(a, var)))
+                    || arguments
+                        .iter()
+                        .any(|a| Self::expression_has_call_with_arg(a, var, pred))
+            }
+            HirExpression::BinaryOp { left, right, .. } => {
+                Self::expression_has_call_with_arg(left, var, pred)
+                    || Self::expression_has_call_with_arg(right, var, pred)
+            }
+            HirExpression::UnaryOp { operand, .. } => {
+                Self::expression_has_call_with_arg(operand, var, pred)
+            }
+            HirExpression::Dereference(inner) | HirExpression::AddressOf(inner) => {
+                Self::expression_has_call_with_arg(inner, var, pred)
+            }
+            _ => false,
         }
     }
 
@@ -228,6 +954,398 @@ impl DataflowGraph {
         array_params
     }
 
+    /// Resolve the length parameter paired with a detected array parameter.
+    /// DECY-078 GREEN: Picks the best-scoring integer (or pointer-to-integer
+    /// out-param) parameter within two positions of the array parameter, on
+    /// either side, and rejects the pairing if the body reassigns a by-value
+    /// length parameter - a mutated length can desync from the slice it's
+    /// paired with, so the binding would be unsound to fold into
+    /// `slice.len()`. The chosen length can differ from the one
+    /// [`Self::get_array_parameters`] reports, which only looks at the next
+    /// parameter.
+    ///
+    /// Returns `None` if `var` is not a detected array parameter, if no
+    /// integer or pointer-to-integer parameter lies within that window, or
+    /// if the chosen by-value length parameter is reassigned in the body.
+    pub fn array_length_binding(&self, var: &str) -> Option<LengthBinding> {
+        if !matches!(self.is_array_parameter(var), Some(true)) {
+            return None;
+        }
+
+        let array_index = self.parameters.iter().position(|p| p.name() == var)?;
+
+        // DECY-080: Score every other parameter within a small window as a
+        // length candidate, instead of only looking at param_index + 1. This
+        // picks up count-before-pointer (`f(int count, T* buf)`) and lengths
+        // interleaved a position or two away from the array parameter. Ties
+        // prefer the later-appearing candidate, matching the historical
+        // "follows the pointer" default.
+        let (_, _, length_index) = (0..self.parameters.len())
+            .filter(|&i| i != array_index)
+            .filter_map(|i| {
+                self.length_candidate_score(array_index, i)
+                    .map(|score| (score, i > array_index, i))
+            })
+            .max_by_key(|&(score, after, _)| (score, after))?;
+
+        let length_param = &self.parameters[length_index];
+        let length_name = length_param.name().to_string();
+
+        // An out-param-style length (`size_t *out_len`) is expected to be
+        // written by this function - that's its entire purpose - so the
+        // mutation-soundness check below only applies to by-value lengths.
+        if matches!(length_param.param_type(), HirType::Pointer(_)) {
+            let length_is_read = self.has_pointer_read(&length_name);
+            return Some(LengthBinding {
+                length_param: length_name,
+                length_param_index: length_index,
+                length_is_read,
+            });
+        }
+
+        if self.is_scalar_mutated(&length_name) {
+            return None;
+        }
+
+        let length_is_read = self
+            .body
+            .iter()
+            .any(|stmt| self.statement_references_var(stmt, &length_name));
+
+        Some(LengthBinding {
+            length_param: length_name,
+            length_param_index: length_index,
+            length_is_read,
+        })
+    }
+
+    /// Score a candidate length parameter against an array parameter's
+    /// position. Returns `None` if the candidate's type rules it out
+    /// entirely (not an integer, and not a pointer-to-integer out-param).
+    /// DECY-080 GREEN: Helper for `array_length_binding`'s window search.
+    fn length_candidate_score(&self, array_index: usize, candidate_index: usize) -> Option<i32> {
+        let candidate = &self.parameters[candidate_index];
+
+        let is_by_value_length = matches!(candidate.param_type(), HirType::Int);
+        let is_out_param_length = matches!(
+            candidate.param_type(),
+            HirType::Pointer(inner) if matches!(**inner, HirType::Int)
+        );
+        if !is_by_value_length && !is_out_param_length {
+            return None;
+        }
+
+        let distance = candidate_index.abs_diff(array_index);
+        if distance == 0 || distance > 2 {
+            return None;
+        }
+
+        let mut score = 6 - 2 * distance as i32; // adjacent = 4, two away = 2
+        let name = candidate.name().to_lowercase();
+        if name.contains("len") || name.contains("size") || name.contains("count") || name.contains("num")
+        {
+            score += 2;
+        }
+
+        Some(score)
+    }
+
+    /// Resolve which source (if any) carries the element count for a
+    /// parameter, as a richer alternative to [`Self::is_array_parameter`].
+    /// DECY-079 GREEN: Resolves [`ArrayKind::LengthBoundByParam`] via
+    /// [`Self::array_length_binding`] first (an explicit length parameter is
+    /// the strongest signal); when there's no paired length parameter, falls
+    /// back to scanning the body for a `for` loop that indexes `var` with a
+    /// bound compared against a constant or a struct field, via
+    /// [`Self::loop_bound_for`].
+    ///
+    /// Returns `None` if `var` is not a parameter at all.
+    pub fn array_kind(&self, var: &str) -> Option<ArrayKind> {
+        if !self.is_array_parameter(var)? {
+            return Some(ArrayKind::Unknown);
+        }
+
+        if let Some(binding) = self.array_length_binding(var) {
+            return Some(ArrayKind::LengthBoundByParam(binding.length_param_index));
+        }
+
+        Some(self.loop_bound_for(var).unwrap_or(ArrayKind::Unknown))
+    }
+
+    /// Scan the body for a `for` loop that indexes `var` (`var[i]`) whose
+    /// condition upper-bounds the index variable against either a constant
+    /// or a struct field read, and classify that bound.
+    /// DECY-079 GREEN: Covers the two `ArrayKind` variants `array_kind`
+    /// previously left unreachable; only the simple `for (i = 0; i < BOUND;
+    /// i++)` shape is recognized, matching the other heuristics in this file
+    /// that favor the common case over exhaustive control-flow analysis.
+    fn loop_bound_for(&self, var: &str) -> Option<ArrayKind> {
+        self.body.iter().find_map(|stmt| Self::loop_bound_in_statement(stmt, var))
+    }
+
+    fn loop_bound_in_statement(stmt: &HirStatement, var: &str) -> Option<ArrayKind> {
+        match stmt {
+            HirStatement::For {
+                condition, body, ..
+            } => {
+                if body.iter().any(|s| Self::statement_indexes_array(s, var)) {
+                    if let Some(kind) = Self::bound_from_condition(condition) {
+                        return Some(kind);
+                    }
+                }
+                body.iter().find_map(|s| Self::loop_bound_in_statement(s, var))
+            }
+            HirStatement::While { body, .. } => {
+                body.iter().find_map(|s| Self::loop_bound_in_statement(s, var))
+            }
+            HirStatement::If {
+                then_block,
+                else_block,
+                ..
+            } => then_block
+                .iter()
+                .find_map(|s| Self::loop_bound_in_statement(s, var))
+                .or_else(|| {
+                    else_block.as_ref().and_then(|blk| {
+                        blk.iter().find_map(|s| Self::loop_bound_in_statement(s, var))
+                    })
+                }),
+            _ => None,
+        }
+    }
+
+    /// True when `stmt` reads or assigns `var[_]` (the evidence that a loop's
+    /// condition is actually bounding iteration over `var`, not some
+    /// unrelated counter).
+    fn statement_indexes_array(stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::Expression(expr)
+            | HirStatement::Return(Some(expr))
+            | HirStatement::VariableDeclaration {
+                initializer: Some(expr),
+                ..
+            } => Self::expression_indexes_array(expr, var),
+            HirStatement::ArrayIndexAssignment { array, .. } => Self::expr_is_var(array, var),
+            HirStatement::If {
+                condition,
+                then_block,
+                else_block,
+            } => {
+                Self::expression_indexes_array(condition, var)
+                    || then_block.iter().any(|s| Self::statement_indexes_array(s, var))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter().any(|s| Self::statement_indexes_array(s, var))
+                    })
+            }
+            _ => false,
+        }
+    }
+
+    fn expression_indexes_array(expr: &HirExpression, var: &str) -> bool {
+        match expr {
+            HirExpression::ArrayIndex { array, .. } => Self::expr_is_var(array, var),
+            HirExpression::BinaryOp { left, right, .. } => {
+                Self::expression_indexes_array(left, var)
+                    || Self::expression_indexes_array(right, var)
+            }
+            HirExpression::UnaryOp { operand, .. }
+            | HirExpression::Dereference(operand)
+            | HirExpression::AddressOf(operand) => Self::expression_indexes_array(operand, var),
+            HirExpression::FunctionCall { arguments, .. } => {
+                arguments.iter().any(|a| Self::expression_indexes_array(a, var))
+            }
+            _ => false,
+        }
+    }
+
+    /// Classify a `for` loop condition's upper bound: a literal constant, or
+    /// a struct field read (`s.f` / `p->f`). Only a top-level
+    /// `_ < bound`/`_ <= bound` shape is recognized.
+    fn bound_from_condition(condition: &HirExpression) -> Option<ArrayKind> {
+        let HirExpression::BinaryOp { op, right, .. } = condition else {
+            return None;
+        };
+        // `i < n` visits n values (0..n); `i <= n` visits n+1 (0..=n), so the
+        // `<=` bound needs a +1 to land on the same "count of valid indices"
+        // meaning as the `<` case.
+        let inclusive = match op {
+            BinaryOperator::LessThan => false,
+            BinaryOperator::LessEqual => true,
+            _ => return None,
+        };
+
+        match right.as_ref() {
+            HirExpression::IntLiteral(n) if *n >= 0 => {
+                let bound = *n as u64 + u64::from(inclusive);
+                Some(ArrayKind::LengthBoundByConstant(bound))
+            }
+            HirExpression::FieldAccess { field, .. } | HirExpression::PointerFieldAccess { field, .. } => {
+                Some(ArrayKind::LengthBoundByStructField(field.clone()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Check if a scalar variable is reassigned (`var = ...`) anywhere in the
+    /// function body.
+    /// DECY-078 GREEN: Helper for length-binding soundness check
+    fn is_scalar_mutated(&self, var: &str) -> bool {
+        self.body
+            .iter()
+            .any(|stmt| self.statement_mutates_scalar(stmt, var))
+    }
+
+    /// Recursively check if a statement reassigns a scalar variable.
+    fn statement_mutates_scalar(&self, stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::Assignment { target, .. } => target == var,
+            HirStatement::If {
+                then_block,
+                else_block,
+                ..
+            } => {
+                then_block
+                    .iter()
+                    .any(|s| self.statement_mutates_scalar(s, var))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter().any(|s| self.statement_mutates_scalar(s, var))
+                    })
+            }
+            HirStatement::While { body, .. } => {
+                body.iter().any(|s| self.statement_mutates_scalar(s, var))
+            }
+            HirStatement::For {
+                increment, body, ..
+            } => {
+                increment
+                    .as_deref()
+                    .is_some_and(|s| self.statement_mutates_scalar(s, var))
+                    || body.iter().any(|s| self.statement_mutates_scalar(s, var))
+            }
+            _ => false,
+        }
+    }
+
+    /// Recursively check if a statement reads the value of a variable
+    /// anywhere - a condition, an assigned value, a function argument, etc.
+    /// DECY-078 GREEN: Helper for length-binding read detection
+    fn statement_references_var(&self, stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::VariableDeclaration {
+                initializer: Some(expr),
+                ..
+            } => Self::expression_references_var(expr, var),
+            HirStatement::VariableDeclaration {
+                initializer: None, ..
+            } => false,
+            HirStatement::Assignment { value, .. } => Self::expression_references_var(value, var),
+            HirStatement::DerefAssignment { target, value } => {
+                Self::expression_references_var(target, var)
+                    || Self::expression_references_var(value, var)
+            }
+            HirStatement::ArrayIndexAssignment {
+                array,
+                index,
+                value,
+            } => {
+                Self::expression_references_var(array, var)
+                    || Self::expression_references_var(index, var)
+                    || Self::expression_references_var(value, var)
+            }
+            HirStatement::FieldAssignment { object, value, .. } => {
+                Self::expression_references_var(object, var)
+                    || Self::expression_references_var(value, var)
+            }
+            HirStatement::If {
+                condition,
+                then_block,
+                else_block,
+            } => {
+                Self::expression_references_var(condition, var)
+                    || then_block
+                        .iter()
+                        .any(|s| self.statement_references_var(s, var))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter().any(|s| self.statement_references_var(s, var))
+                    })
+            }
+            HirStatement::While { condition, body } => {
+                Self::expression_references_var(condition, var)
+                    || body.iter().any(|s| self.statement_references_var(s, var))
+            }
+            HirStatement::For {
+                init,
+                condition,
+                increment,
+                body,
+            } => {
+                init.as_deref()
+                    .is_some_and(|s| self.statement_references_var(s, var))
+                    || Self::expression_references_var(condition, var)
+                    || increment
+                        .as_deref()
+                        .is_some_and(|s| self.statement_references_var(s, var))
+                    || body.iter().any(|s| self.statement_references_var(s, var))
+            }
+            HirStatement::Return(Some(expr)) => Self::expression_references_var(expr, var),
+            HirStatement::Return(None) | HirStatement::Break | HirStatement::Continue => false,
+            HirStatement::Switch {
+                condition,
+                cases,
+                default_case,
+            } => {
+                Self::expression_references_var(condition, var)
+                    || cases.iter().any(|c| {
+                        c.body
+                            .iter()
+                            .any(|s| self.statement_references_var(s, var))
+                    })
+                    || default_case.as_ref().is_some_and(|stmts| {
+                        stmts.iter().any(|s| self.statement_references_var(s, var))
+                    })
+            }
+            HirStatement::Free { pointer } => Self::expression_references_var(pointer, var),
+            HirStatement::Expression(expr) => Self::expression_references_var(expr, var),
+        }
+    }
+
+    /// Recursively check if an expression reads the value of a variable.
+    fn expression_references_var(expr: &HirExpression, var: &str) -> bool {
+        match expr {
+            HirExpression::Variable(name) => name == var,
+            HirExpression::Dereference(inner)
+            | HirExpression::AddressOf(inner)
+            | HirExpression::IsNotNull(inner) => Self::expression_references_var(inner, var),
+            HirExpression::UnaryOp { operand, .. } => {
+                Self::expression_references_var(operand, var)
+            }
+            HirExpression::PointerFieldAccess { pointer, .. } => {
+                Self::expression_references_var(pointer, var)
+            }
+            HirExpression::FieldAccess { object, .. } => {
+                Self::expression_references_var(object, var)
+            }
+            HirExpression::BinaryOp { left, right, .. } => {
+                Self::expression_references_var(left, var)
+                    || Self::expression_references_var(right, var)
+            }
+            HirExpression::FunctionCall { arguments, .. } => arguments
+                .iter()
+                .any(|a| Self::expression_references_var(a, var)),
+            HirExpression::ArrayIndex { array, index } => {
+                Self::expression_references_var(array, var)
+                    || Self::expression_references_var(index, var)
+            }
+            HirExpression::Calloc { count, .. } => Self::expression_references_var(count, var),
+            HirExpression::Malloc { size } => Self::expression_references_var(size, var),
+            HirExpression::Realloc { pointer, new_size } => {
+                Self::expression_references_var(pointer, var)
+                    || Self::expression_references_var(new_size, var)
+            }
+            _ => false,
+        }
+    }
+
     /// Check if a variable is used with array indexing in the function body.
     /// DECY-071 GREEN: Helper for array detection
     fn has_array_indexing(&self, var: &str) -> bool {
@@ -269,6 +1387,170 @@ impl DataflowGraph {
         }
     }
 
+    /// Check if a variable is dereferenced through a pointer-offset expression
+    /// (`*(p + i)` / `*(p - i)`) anywhere in the function body.
+    /// DECY-074 GREEN: Helper for array detection (positive signal, covers
+    /// moving-pointer iteration as well as explicit `ArrayIndexAssignment`)
+    fn has_indexed_dereference(&self, var: &str) -> bool {
+        self.body
+            .iter()
+            .any(|stmt| self.statement_has_indexed_dereference(stmt, var))
+    }
+
+    /// Recursively check if a statement contains a pointer-offset dereference for a variable.
+    fn statement_has_indexed_dereference(&self, stmt: &HirStatement, var: &str) -> bool {
+        match stmt {
+            HirStatement::VariableDeclaration {
+                initializer: Some(expr),
+                ..
+            } => self.expression_has_indexed_dereference(expr, var),
+            HirStatement::VariableDeclaration {
+                initializer: None, ..
+            } => false,
+            HirStatement::Assignment { value, .. } => {
+                self.expression_has_indexed_dereference(value, var)
+            }
+            HirStatement::DerefAssignment { target, value } => {
+                // `*(p + i) = v` carries `p + i` as its target.
+                Self::is_additive_offset_of(target, var)
+                    || self.expression_has_indexed_dereference(target, var)
+                    || self.expression_has_indexed_dereference(value, var)
+            }
+            HirStatement::ArrayIndexAssignment {
+                array,
+                index,
+                value,
+            } => {
+                self.expression_has_indexed_dereference(array, var)
+                    || self.expression_has_indexed_dereference(index, var)
+                    || self.expression_has_indexed_dereference(value, var)
+            }
+            HirStatement::FieldAssignment { object, value, .. } => {
+                self.expression_has_indexed_dereference(object, var)
+                    || self.expression_has_indexed_dereference(value, var)
+            }
+            HirStatement::If {
+                condition,
+                then_block,
+                else_block,
+            } => {
+                self.expression_has_indexed_dereference(condition, var)
+                    || then_block
+                        .iter()
+                        .any(|s| self.statement_has_indexed_dereference(s, var))
+                    || else_block.as_ref().is_some_and(|blk| {
+                        blk.iter()
+                            .any(|s| self.statement_has_indexed_dereference(s, var))
+                    })
+            }
+            HirStatement::While { condition, body } => {
+                self.expression_has_indexed_dereference(condition, var)
+                    || body
+                        .iter()
+                        .any(|s| self.statement_has_indexed_dereference(s, var))
+            }
+            HirStatement::For {
+                init,
+                condition,
+                increment,
+                body,
+            } => {
+                init.as_deref()
+                    .is_some_and(|s| self.statement_has_indexed_dereference(s, var))
+                    || self.expression_has_indexed_dereference(condition, var)
+                    || increment
+                        .as_deref()
+                        .is_some_and(|s| self.statement_has_indexed_dereference(s, var))
+                    || body
+                        .iter()
+                        .any(|s| self.statement_has_indexed_dereference(s, var))
+            }
+            HirStatement::Return(Some(expr)) => self.expression_has_indexed_dereference(expr, var),
+            HirStatement::Return(None) | HirStatement::Break | HirStatement::Continue => false,
+            HirStatement::Switch {
+                condition,
+                cases,
+                default_case,
+            } => {
+                self.expression_has_indexed_dereference(condition, var)
+                    || cases.iter().any(|c| {
+                        c.body
+                            .iter()
+                            .any(|s| self.statement_has_indexed_dereference(s, var))
+                    })
+                    || default_case.as_ref().is_some_and(|stmts| {
+                        stmts
+                            .iter()
+                            .any(|s| self.statement_has_indexed_dereference(s, var))
+                    })
+            }
+            HirStatement::Free { pointer } => self.expression_has_indexed_dereference(pointer, var),
+            HirStatement::Expression(expr) => self.expression_has_indexed_dereference(expr, var),
+        }
+    }
+
+    /// Recursively check if an expression contains a pointer-offset dereference for a variable.
+    fn expression_has_indexed_dereference(&self, expr: &HirExpression, var: &str) -> bool {
+        if let HirExpression::Dereference(inner) = expr {
+            if Self::is_additive_offset_of(inner, var) {
+                return true;
+            }
+        }
+
+        match expr {
+            HirExpression::Dereference(inner)
+            | HirExpression::AddressOf(inner)
+            | HirExpression::IsNotNull(inner) => {
+                self.expression_has_indexed_dereference(inner, var)
+            }
+            HirExpression::UnaryOp { operand, .. } => {
+                self.expression_has_indexed_dereference(operand, var)
+            }
+            HirExpression::BinaryOp { left, right, .. } => {
+                self.expression_has_indexed_dereference(left, var)
+                    || self.expression_has_indexed_dereference(right, var)
+            }
+            HirExpression::FunctionCall { arguments, .. } => arguments
+                .iter()
+                .any(|a| self.expression_has_indexed_dereference(a, var)),
+            HirExpression::FieldAccess { object, .. } => {
+                self.expression_has_indexed_dereference(object, var)
+            }
+            HirExpression::PointerFieldAccess { pointer, .. } => {
+                self.expression_has_indexed_dereference(pointer, var)
+            }
+            HirExpression::ArrayIndex { array, index } => {
+                self.expression_has_indexed_dereference(array, var)
+                    || self.expression_has_indexed_dereference(index, var)
+            }
+            HirExpression::Calloc { count, .. } => {
+                self.expression_has_indexed_dereference(count, var)
+            }
+            HirExpression::Malloc { size } => self.expression_has_indexed_dereference(size, var),
+            HirExpression::Realloc { pointer, new_size } => {
+                self.expression_has_indexed_dereference(pointer, var)
+                    || self.expression_has_indexed_dereference(new_size, var)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check whether `expr` is `var + <expr>` or `<expr> + var` (or with `-`),
+    /// the pointer-offset shape used to detect moving-pointer array access.
+    fn is_additive_offset_of(expr: &HirExpression, var: &str) -> bool {
+        let HirExpression::BinaryOp { op, left, right } = expr else {
+            return false;
+        };
+        if !matches!(
+            op,
+            decy_hir::BinaryOperator::Add | decy_hir::BinaryOperator::Subtract
+        ) {
+            return false;
+        }
+        matches!(&**left, HirExpression::Variable(name) if name == var)
+            || matches!(&**right, HirExpression::Variable(name) if name == var)
+    }
+
     /// Check if a variable is used with pointer arithmetic in the function body.
     /// DECY-071 GREEN: Helper for array detection (negative signal)
     fn has_pointer_arithmetic(&self, var: &str) -> bool {
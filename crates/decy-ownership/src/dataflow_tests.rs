@@ -826,3 +826,1259 @@ fn test_unnamed_pointer_parameter() {
         "Unnamed parameter should default to false (conservative)"
     );
 }
+
+// ============================================================================
+// DECY-073 Tests: Array Parameter Mutability Classification
+// ============================================================================
+
+/// Test that a parameter indexed only on the RHS is classified as Shared.
+#[test]
+fn test_array_mutability_read_only_is_shared() {
+    // C: int sum(int* arr, int len) { return arr[0]; }
+    let func = HirFunction::new_with_body(
+        "sum".to_string(),
+        HirType::Int,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::Return(Some(HirExpression::ArrayIndex {
+            array: Box::new(HirExpression::Variable("arr".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+        }))],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_mutability("arr"),
+        Some(Mutability::Shared),
+        "Read-only array parameter should be classified as Shared"
+    );
+}
+
+/// Test that a parameter written via `arr[i] = value` is classified as Mut.
+#[test]
+fn test_array_mutability_indexed_write_is_mut() {
+    // C: void zero(int* arr, int len) { arr[0] = 0; }
+    let func = HirFunction::new_with_body(
+        "zero".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::ArrayIndexAssignment {
+            array: Box::new(HirExpression::Variable("arr".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+            value: HirExpression::IntLiteral(0),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_mutability("arr"),
+        Some(Mutability::Mut),
+        "Array parameter written via arr[i] = value should be classified as Mut"
+    );
+}
+
+/// Test that a parameter written via `*(p + i) = value` is classified as Mut.
+#[test]
+fn test_array_mutability_pointer_arithmetic_write_is_mut() {
+    // C: void zero(int* arr, int len) { *(arr + 0) = 0; }
+    let func = HirFunction::new_with_body(
+        "zero".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::DerefAssignment {
+            target: HirExpression::Dereference(Box::new(HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::Add,
+                left: Box::new(HirExpression::Variable("arr".to_string())),
+                right: Box::new(HirExpression::IntLiteral(0)),
+            })),
+            value: HirExpression::IntLiteral(0),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_mutability("arr"),
+        Some(Mutability::Mut),
+        "Array parameter written via *(arr + i) = value should be classified as Mut"
+    );
+}
+
+/// Test that a non-array parameter has no mutability classification.
+#[test]
+fn test_array_mutability_none_for_non_array_parameter() {
+    // C: void process(struct Foo* foo) { }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "foo".to_string(),
+            HirType::Pointer(Box::new(HirType::Struct("Foo".to_string()))),
+        )],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_mutability("foo"),
+        None,
+        "Non-array parameter should have no mutability classification"
+    );
+}
+
+// ============================================================================
+// DECY-074 Tests: Pointer-Offset Dereference as Array Indexing Signal
+// ============================================================================
+
+/// Test that `*(p + i)` read through a moving pointer is detected as array
+/// indexing, even with no explicit `ArrayIndexAssignment` in the body.
+#[test]
+fn test_detect_array_parameter_via_pointer_offset_read() {
+    // C: int first(int* p, int len) { return *(p + 0); }
+    let func = HirFunction::new_with_body(
+        "first".to_string(),
+        HirType::Int,
+        vec![
+            HirParameter::new("p".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::Return(Some(HirExpression::Dereference(
+            Box::new(HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::Add,
+                left: Box::new(HirExpression::Variable("p".to_string())),
+                right: Box::new(HirExpression::IntLiteral(0)),
+            }),
+        )))],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.is_array_parameter("p"),
+        Some(true),
+        "*(p + i) read should be detected as array indexing usage"
+    );
+}
+
+/// Test that `*(p + i) = value` written through a moving pointer is detected
+/// as array indexing, matching the confidence of an explicit write.
+#[test]
+fn test_detect_array_parameter_via_pointer_offset_write() {
+    // C: void zero(int* p, int len) { *(p + 0) = 0; }
+    let func = HirFunction::new_with_body(
+        "zero".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("p".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::DerefAssignment {
+            target: HirExpression::Dereference(Box::new(HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::Add,
+                left: Box::new(HirExpression::Variable("p".to_string())),
+                right: Box::new(HirExpression::IntLiteral(0)),
+            })),
+            value: HirExpression::IntLiteral(0),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.is_array_parameter("p"),
+        Some(true),
+        "*(p + i) = value write should be detected as array indexing usage"
+    );
+}
+
+// ============================================================================
+// DECY-075 Tests: Array Parameter Ownership-Transfer Classification
+// ============================================================================
+
+/// Test that an array parameter only read from is classified as Borrowed.
+#[test]
+fn test_array_ownership_read_only_is_borrowed() {
+    // C: int sum(int* arr, int len) { return arr[0]; }
+    let func = HirFunction::new_with_body(
+        "sum".to_string(),
+        HirType::Int,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::Return(Some(HirExpression::ArrayIndex {
+            array: Box::new(HirExpression::Variable("arr".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+        }))],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_ownership("arr"),
+        Some(Ownership::Borrowed),
+        "Read-only array parameter should be classified as Borrowed"
+    );
+}
+
+/// Test that an array parameter passed to `free` is classified as OwnedConsumed.
+#[test]
+fn test_array_ownership_freed_is_owned_consumed() {
+    // C: void release(int* arr, int len) { free(arr); }
+    let func = HirFunction::new_with_body(
+        "release".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::Free {
+            pointer: HirExpression::Variable("arr".to_string()),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_ownership("arr"),
+        Some(Ownership::OwnedConsumed),
+        "Array parameter passed to free() should be classified as OwnedConsumed"
+    );
+}
+
+/// Test that an array parameter passed to `realloc` is classified as OwnedConsumed.
+#[test]
+fn test_array_ownership_reallocated_is_owned_consumed() {
+    // C: int* grow(int* arr, int len) { int* bigger = realloc(arr, len * 2); return bigger; }
+    let func = HirFunction::new_with_body(
+        "grow".to_string(),
+        HirType::Pointer(Box::new(HirType::Int)),
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::VariableDeclaration {
+            name: "bigger".to_string(),
+            var_type: HirType::Pointer(Box::new(HirType::Int)),
+            initializer: Some(HirExpression::Realloc {
+                pointer: Box::new(HirExpression::Variable("arr".to_string())),
+                new_size: Box::new(HirExpression::IntLiteral(8)),
+            }),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_ownership("arr"),
+        Some(Ownership::OwnedConsumed),
+        "Array parameter passed to realloc() should be classified as OwnedConsumed"
+    );
+}
+
+/// Test that an array parameter returned to the caller is classified as
+/// OwnedConsumed, even if it is also written through.
+#[test]
+fn test_array_ownership_written_and_returned_is_owned_consumed() {
+    // C: int* fill(int* arr, int len) { arr[0] = 1; return arr; }
+    let func = HirFunction::new_with_body(
+        "fill".to_string(),
+        HirType::Pointer(Box::new(HirType::Int)),
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![
+            HirStatement::ArrayIndexAssignment {
+                array: Box::new(HirExpression::Variable("arr".to_string())),
+                index: Box::new(HirExpression::IntLiteral(0)),
+                value: HirExpression::IntLiteral(1),
+            },
+            HirStatement::Return(Some(HirExpression::Variable("arr".to_string()))),
+        ],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_ownership("arr"),
+        Some(Ownership::OwnedConsumed),
+        "Array parameter written through and returned should be OwnedConsumed"
+    );
+    assert_eq!(
+        graph.array_mutability("arr"),
+        Some(Mutability::Mut),
+        "The same parameter should also be classified as Mut"
+    );
+}
+
+/// Test that a non-array parameter has no ownership classification.
+#[test]
+fn test_array_ownership_none_for_non_array_parameter() {
+    // C: void process(struct Foo* foo) { }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "foo".to_string(),
+            HirType::Pointer(Box::new(HirType::Struct("Foo".to_string()))),
+        )],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_ownership("foo"),
+        None,
+        "Non-array parameter should have no ownership classification"
+    );
+}
+
+// ============================================================================
+// DECY-076 Tests: Array Parameter Confidence Scoring and Signal Breakdown
+// ============================================================================
+
+/// Test that the confidence breakdown reports every contributing signal for
+/// a clear-cut array parameter.
+#[test]
+fn test_array_parameter_confidence_reports_all_signals() {
+    // C: void process(int* arr, int len) { arr[0] = 1; }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::ArrayIndexAssignment {
+            array: Box::new(HirExpression::Variable("arr".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+            value: HirExpression::IntLiteral(1),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let confidence = graph
+        .array_parameter_confidence("arr")
+        .expect("arr is a parameter");
+
+    assert!(confidence.common_array_name);
+    assert!(confidence.followed_by_int_length);
+    assert!(confidence.common_length_name);
+    assert!(confidence.body_indexing_evidence);
+    assert!(!confidence.pointer_arithmetic_evidence);
+    assert!(confidence.element_type_plausible);
+    assert_eq!(confidence.signal_count, 4);
+    assert_eq!(confidence.score, 10);
+    assert!(confidence.is_likely_array());
+    assert_eq!(graph.is_array_parameter("arr"), Some(true));
+}
+
+/// Test that `is_array_parameter` and `array_parameter_confidence` agree on
+/// the threshold for a struct pointer, which is excluded from both.
+#[test]
+fn test_array_parameter_confidence_matches_is_array_parameter_for_struct_pointer() {
+    // C: void process(struct Foo* foo) { }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "foo".to_string(),
+            HirType::Pointer(Box::new(HirType::Struct("Foo".to_string()))),
+        )],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let confidence = graph
+        .array_parameter_confidence("foo")
+        .expect("foo is a parameter");
+
+    assert_eq!(confidence, ArrayConfidence::default());
+    assert!(!confidence.element_type_plausible);
+    assert!(!confidence.is_likely_array());
+    assert_eq!(graph.is_array_parameter("foo"), Some(false));
+}
+
+/// Test that an unnamed pointer parameter with only one signal is reported
+/// below the threshold: its score is positive, but one signal is too few.
+#[test]
+fn test_array_parameter_confidence_below_threshold_for_single_signal() {
+    // C: void process(int*, int) { }  (unnamed parameters)
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("_arg0".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("_arg1".to_string(), HirType::Int),
+        ],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let confidence = graph
+        .array_parameter_confidence("_arg0")
+        .expect("_arg0 is a parameter");
+
+    assert_eq!(confidence.signal_count, 1, "Only the length-param signal should fire");
+    assert_eq!(confidence.score, 3);
+    assert!(
+        !confidence.is_likely_array(),
+        "A single signal should not clear the 2-signal threshold"
+    );
+    assert_eq!(graph.is_array_parameter("_arg0"), Some(false));
+}
+
+// ============================================================================
+// Tests: Pointer Role Classification (Slice / OutParam / Opaque / SingleRef)
+// ============================================================================
+
+/// Test that a detected array parameter is classified as Slice.
+#[test]
+fn test_pointer_role_array_parameter_is_slice() {
+    // C: void process(int* arr, int len) { arr[0] = 1; }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::ArrayIndexAssignment {
+            array: Box::new(HirExpression::Variable("arr".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+            value: HirExpression::IntLiteral(1),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.pointer_role("arr"), Some(PointerRole::Slice));
+}
+
+/// Test that a single pointer written through but never read is an OutParam.
+#[test]
+fn test_pointer_role_write_only_is_out_param() {
+    // C: void get_result(int* result) { *result = 42; }
+    let func = HirFunction::new_with_body(
+        "get_result".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "result".to_string(),
+            HirType::Pointer(Box::new(HirType::Int)),
+        )],
+        vec![HirStatement::DerefAssignment {
+            target: HirExpression::Dereference(Box::new(HirExpression::Variable(
+                "result".to_string(),
+            ))),
+            value: HirExpression::IntLiteral(42),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.pointer_role("result"), Some(PointerRole::OutParam));
+}
+
+/// Test that a struct pointer written through via `p->field = value` and
+/// never read is also an OutParam.
+#[test]
+fn test_pointer_role_field_write_only_is_out_param() {
+    // C: void set_count(struct Counter* c) { c->value = 0; }
+    let func = HirFunction::new_with_body(
+        "set_count".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "c".to_string(),
+            HirType::Pointer(Box::new(HirType::Struct("Counter".to_string()))),
+        )],
+        vec![HirStatement::FieldAssignment {
+            object: HirExpression::Variable("c".to_string()),
+            field: "value".to_string(),
+            value: HirExpression::IntLiteral(0),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.pointer_role("c"), Some(PointerRole::OutParam));
+}
+
+/// Test that a `void*` with no dereference evidence stays Opaque.
+#[test]
+fn test_pointer_role_void_pointer_is_opaque() {
+    // C: void register_handle(void* handle) { }
+    let func = HirFunction::new_with_body(
+        "register_handle".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "handle".to_string(),
+            HirType::Pointer(Box::new(HirType::Void)),
+        )],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.pointer_role("handle"), Some(PointerRole::Opaque));
+}
+
+/// Test that a struct pointer with no dereference or field-access evidence
+/// stays Opaque, not a SingleRef.
+#[test]
+fn test_pointer_role_untouched_struct_pointer_is_opaque() {
+    // C: void register(struct Handle* h) { }
+    let func = HirFunction::new_with_body(
+        "register".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "h".to_string(),
+            HirType::Pointer(Box::new(HirType::Struct("Handle".to_string()))),
+        )],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.pointer_role("h"), Some(PointerRole::Opaque));
+}
+
+/// Test that a struct pointer read via `p->field` is a SingleRef.
+#[test]
+fn test_pointer_role_field_read_is_single_ref() {
+    // C: int get_value(struct Counter* c) { return c->value; }
+    let func = HirFunction::new_with_body(
+        "get_value".to_string(),
+        HirType::Int,
+        vec![HirParameter::new(
+            "c".to_string(),
+            HirType::Pointer(Box::new(HirType::Struct("Counter".to_string()))),
+        )],
+        vec![HirStatement::Return(Some(
+            HirExpression::PointerFieldAccess {
+                pointer: Box::new(HirExpression::Variable("c".to_string())),
+                field: "value".to_string(),
+            },
+        ))],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.pointer_role("c"), Some(PointerRole::SingleRef));
+}
+
+/// Test that a non-pointer parameter has no pointer role.
+#[test]
+fn test_pointer_role_none_for_non_pointer_parameter() {
+    let func = HirFunction::new_with_body(
+        "add".to_string(),
+        HirType::Int,
+        vec![HirParameter::new("x".to_string(), HirType::Int)],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.pointer_role("x"), None);
+}
+
+// ============================================================================
+// Tests: Array Length Binding (array_length_binding)
+// ============================================================================
+
+/// Test that a detected array/length pair yields a binding with the correct
+/// name and index, flagged as read when the body uses the length in a loop
+/// condition.
+#[test]
+fn test_array_length_binding_detects_read_pairing() {
+    // C: void process(int* arr, int len) {
+    //        for (int i = 0; i < len; i++) { arr[i] = 0; }
+    //    }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::For {
+            init: Some(Box::new(HirStatement::VariableDeclaration {
+                name: "i".to_string(),
+                var_type: HirType::Int,
+                initializer: Some(HirExpression::IntLiteral(0)),
+            })),
+            condition: HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::LessThan,
+                left: Box::new(HirExpression::Variable("i".to_string())),
+                right: Box::new(HirExpression::Variable("len".to_string())),
+            },
+            increment: Some(Box::new(HirStatement::Assignment {
+                target: "i".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: decy_hir::BinaryOperator::Add,
+                    left: Box::new(HirExpression::Variable("i".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            })),
+            body: vec![HirStatement::ArrayIndexAssignment {
+                array: Box::new(HirExpression::Variable("arr".to_string())),
+                index: Box::new(HirExpression::Variable("i".to_string())),
+                value: HirExpression::IntLiteral(0),
+            }],
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let binding = graph
+        .array_length_binding("arr")
+        .expect("arr is paired with len");
+
+    assert_eq!(binding.length_param, "len");
+    assert_eq!(binding.length_param_index, 1);
+    assert!(binding.length_is_read, "len is read in the loop condition");
+}
+
+/// Test that a length parameter never read in the body still yields a
+/// binding, just with `length_is_read` false.
+#[test]
+fn test_array_length_binding_unread_length_still_binds() {
+    // C: void fill(int* arr, int len) { arr[0] = 1; }
+    let func = HirFunction::new_with_body(
+        "fill".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::ArrayIndexAssignment {
+            array: Box::new(HirExpression::Variable("arr".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+            value: HirExpression::IntLiteral(1),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let binding = graph
+        .array_length_binding("arr")
+        .expect("arr is paired with len");
+
+    assert_eq!(binding.length_param, "len");
+    assert!(!binding.length_is_read);
+}
+
+/// Test that a mutated length parameter rejects the binding entirely, since
+/// folding it into `slice.len()` would no longer be sound.
+#[test]
+fn test_array_length_binding_rejected_when_length_mutated() {
+    // C: void shrink(int* arr, int len) { arr[0] = 1; len = len - 1; }
+    let func = HirFunction::new_with_body(
+        "shrink".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![
+            HirStatement::ArrayIndexAssignment {
+                array: Box::new(HirExpression::Variable("arr".to_string())),
+                index: Box::new(HirExpression::IntLiteral(0)),
+                value: HirExpression::IntLiteral(1),
+            },
+            HirStatement::Assignment {
+                target: "len".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: decy_hir::BinaryOperator::Subtract,
+                    left: Box::new(HirExpression::Variable("len".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            },
+        ],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.array_length_binding("arr"), None);
+}
+
+/// Test that a parameter not detected as an array yields no length binding.
+#[test]
+fn test_array_length_binding_none_for_non_array_parameter() {
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "count".to_string(),
+            HirType::Int,
+        )],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.array_length_binding("count"), None);
+}
+
+// ============================================================================
+// Tests: Array Kind Classification (array_kind)
+// ============================================================================
+
+/// Test that a detected array parameter with a length pairing resolves to
+/// `LengthBoundByParam` at the paired parameter's index.
+#[test]
+fn test_array_kind_length_bound_by_param() {
+    // C: void process(int* arr, int len) { arr[0] = 1; }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::ArrayIndexAssignment {
+            array: Box::new(HirExpression::Variable("arr".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+            value: HirExpression::IntLiteral(1),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.array_kind("arr"), Some(ArrayKind::LengthBoundByParam(1)));
+}
+
+/// Test that a non-array pointer parameter resolves to `Unknown`.
+#[test]
+fn test_array_kind_unknown_for_non_array_parameter() {
+    let func = HirFunction::new_with_body(
+        "register_handle".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "handle".to_string(),
+            HirType::Pointer(Box::new(HirType::Void)),
+        )],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.array_kind("handle"), Some(ArrayKind::Unknown));
+}
+
+/// Test that an array parameter whose length is mutated (so the binding is
+/// rejected by `array_length_binding`) falls back to `Unknown` rather than
+/// `LengthBoundByParam`.
+#[test]
+fn test_array_kind_unknown_when_length_binding_rejected() {
+    // C: void shrink(int* arr, int len) { arr[0] = 1; len = len - 1; }
+    let func = HirFunction::new_with_body(
+        "shrink".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![
+            HirStatement::ArrayIndexAssignment {
+                array: Box::new(HirExpression::Variable("arr".to_string())),
+                index: Box::new(HirExpression::IntLiteral(0)),
+                value: HirExpression::IntLiteral(1),
+            },
+            HirStatement::Assignment {
+                target: "len".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: decy_hir::BinaryOperator::Subtract,
+                    left: Box::new(HirExpression::Variable("len".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            },
+        ],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.array_kind("arr"), Some(ArrayKind::Unknown));
+}
+
+/// Test that a non-parameter variable has no array kind.
+#[test]
+fn test_array_kind_none_for_non_parameter() {
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.array_kind("arr"), None);
+}
+
+/// Test that an array parameter with no paired length parameter, but a `for`
+/// loop indexing it against a constant upper bound, resolves to
+/// `LengthBoundByConstant`.
+#[test]
+fn test_array_kind_length_bound_by_constant_from_loop() {
+    // C: void fill(int* arr) { for (int i = 0; i < 16; i++) arr[i] = 0; }
+    let func = HirFunction::new_with_body(
+        "fill".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "arr".to_string(),
+            HirType::Pointer(Box::new(HirType::Int)),
+        )],
+        vec![HirStatement::For {
+            init: Some(Box::new(HirStatement::VariableDeclaration {
+                name: "i".to_string(),
+                var_type: HirType::Int,
+                initializer: Some(HirExpression::IntLiteral(0)),
+            })),
+            condition: HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::LessThan,
+                left: Box::new(HirExpression::Variable("i".to_string())),
+                right: Box::new(HirExpression::IntLiteral(16)),
+            },
+            increment: Some(Box::new(HirStatement::Assignment {
+                target: "i".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: decy_hir::BinaryOperator::Add,
+                    left: Box::new(HirExpression::Variable("i".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            })),
+            body: vec![HirStatement::ArrayIndexAssignment {
+                array: Box::new(HirExpression::Variable("arr".to_string())),
+                index: Box::new(HirExpression::Variable("i".to_string())),
+                value: HirExpression::IntLiteral(0),
+            }],
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.array_kind("arr"), Some(ArrayKind::LengthBoundByConstant(16)));
+}
+
+/// Test that a `for` loop bounded by `i <= n` (visiting indices `0..=n`, one
+/// more than `i < n`) resolves to `LengthBoundByConstant(n + 1)`, not `n`.
+#[test]
+fn test_array_kind_length_bound_by_constant_from_loop_less_equal() {
+    // C: void fill(int* arr) { for (int i = 0; i <= 15; i++) arr[i] = 0; }
+    let func = HirFunction::new_with_body(
+        "fill".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "arr".to_string(),
+            HirType::Pointer(Box::new(HirType::Int)),
+        )],
+        vec![HirStatement::For {
+            init: Some(Box::new(HirStatement::VariableDeclaration {
+                name: "i".to_string(),
+                var_type: HirType::Int,
+                initializer: Some(HirExpression::IntLiteral(0)),
+            })),
+            condition: HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::LessEqual,
+                left: Box::new(HirExpression::Variable("i".to_string())),
+                right: Box::new(HirExpression::IntLiteral(15)),
+            },
+            increment: Some(Box::new(HirStatement::Assignment {
+                target: "i".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: decy_hir::BinaryOperator::Add,
+                    left: Box::new(HirExpression::Variable("i".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            })),
+            body: vec![HirStatement::ArrayIndexAssignment {
+                array: Box::new(HirExpression::Variable("arr".to_string())),
+                index: Box::new(HirExpression::Variable("i".to_string())),
+                value: HirExpression::IntLiteral(0),
+            }],
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.array_kind("arr"), Some(ArrayKind::LengthBoundByConstant(16)));
+}
+
+/// Test that an array parameter with no paired length parameter, but a `for`
+/// loop indexing it against a struct field read off another parameter,
+/// resolves to `LengthBoundByStructField`.
+#[test]
+fn test_array_kind_length_bound_by_struct_field_from_loop() {
+    // C: void fill(int* arr, Obj* obj) { for (int i = 0; i < obj->count; i++) arr[i] = 0; }
+    let func = HirFunction::new_with_body(
+        "fill".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new(
+                "obj".to_string(),
+                HirType::Pointer(Box::new(HirType::Struct("Obj".to_string()))),
+            ),
+        ],
+        vec![HirStatement::For {
+            init: Some(Box::new(HirStatement::VariableDeclaration {
+                name: "i".to_string(),
+                var_type: HirType::Int,
+                initializer: Some(HirExpression::IntLiteral(0)),
+            })),
+            condition: HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::LessThan,
+                left: Box::new(HirExpression::Variable("i".to_string())),
+                right: Box::new(HirExpression::PointerFieldAccess {
+                    pointer: Box::new(HirExpression::Variable("obj".to_string())),
+                    field: "count".to_string(),
+                }),
+            },
+            increment: Some(Box::new(HirStatement::Assignment {
+                target: "i".to_string(),
+                value: HirExpression::BinaryOp {
+                    op: decy_hir::BinaryOperator::Add,
+                    left: Box::new(HirExpression::Variable("i".to_string())),
+                    right: Box::new(HirExpression::IntLiteral(1)),
+                },
+            })),
+            body: vec![HirStatement::ArrayIndexAssignment {
+                array: Box::new(HirExpression::Variable("arr".to_string())),
+                index: Box::new(HirExpression::Variable("i".to_string())),
+                value: HirExpression::IntLiteral(0),
+            }],
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.array_kind("arr"),
+        Some(ArrayKind::LengthBoundByStructField("count".to_string()))
+    );
+}
+
+// ============================================================================
+// DECY-080 Tests: Count-Before-Pointer and Windowed Length Binding
+// ============================================================================
+
+/// Test that `f(int count, T* buf)` (count-before-pointer) is detected as an
+/// array parameter.
+#[test]
+fn test_count_before_pointer_is_array_parameter() {
+    // C: void process(int count, int* buf) { }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("count".to_string(), HirType::Int),
+            HirParameter::new("buf".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+        ],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let confidence = graph
+        .array_parameter_confidence("buf")
+        .expect("buf is a parameter");
+    assert!(confidence.preceded_by_int_length);
+    assert!(confidence.preceded_by_common_length_name);
+    assert_eq!(graph.is_array_parameter("buf"), Some(true));
+}
+
+/// Test that `array_length_binding` resolves the preceding count parameter
+/// for the count-before-pointer ordering.
+#[test]
+fn test_array_length_binding_count_before_pointer() {
+    // C: void process(int count, int* buf) { buf[0] = count; }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("count".to_string(), HirType::Int),
+            HirParameter::new("buf".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+        ],
+        vec![HirStatement::ArrayIndexAssignment {
+            array: Box::new(HirExpression::Variable("buf".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+            value: HirExpression::Variable("count".to_string()),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let binding = graph
+        .array_length_binding("buf")
+        .expect("buf is paired with count");
+
+    assert_eq!(binding.length_param, "count");
+    assert_eq!(binding.length_param_index, 0);
+    assert!(binding.length_is_read, "count is read as the stored value");
+    assert_eq!(graph.array_kind("buf"), Some(ArrayKind::LengthBoundByParam(0)));
+}
+
+/// Test that a length parameter two positions away (within the small
+/// window, interleaved with an unrelated argument) is still resolved as the
+/// best candidate over an unrelated nearer non-integer parameter.
+#[test]
+fn test_array_length_binding_within_window_when_interleaved() {
+    // C: void process(int* buf, double scale, int len) { buf[0] = 1; }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("buf".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("scale".to_string(), HirType::Double),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::ArrayIndexAssignment {
+            array: Box::new(HirExpression::Variable("buf".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+            value: HirExpression::IntLiteral(1),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let binding = graph
+        .array_length_binding("buf")
+        .expect("buf is paired with len despite the interleaved scale parameter");
+
+    assert_eq!(binding.length_param, "len");
+    assert_eq!(binding.length_param_index, 2);
+}
+
+/// Test that a pointer-to-integer out-param length (`size_t *out_len`) is
+/// resolved as a binding, and is never rejected for being "mutated" - writing
+/// through it is exactly its purpose (unlike a by-value length, which would
+/// be rejected by the mutation-soundness check).
+#[test]
+fn test_array_length_binding_pointer_out_param_length() {
+    // C: void fill(int* buf, int* out_len) { *out_len = 10; }
+    let func = HirFunction::new_with_body(
+        "fill".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("buf".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new(
+                "out_len".to_string(),
+                HirType::Pointer(Box::new(HirType::Int)),
+            ),
+        ],
+        vec![HirStatement::DerefAssignment {
+            target: HirExpression::Dereference(Box::new(HirExpression::Variable(
+                "out_len".to_string(),
+            ))),
+            value: HirExpression::IntLiteral(10),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    let binding = graph
+        .array_length_binding("buf")
+        .expect("buf is paired with out_len despite being written through");
+
+    assert_eq!(binding.length_param, "out_len");
+    assert!(
+        !binding.length_is_read,
+        "out_len is only ever written, never read back"
+    );
+}
+
+// ============================================================================
+// DECY-081 Tests: Sentinel/NUL Termination Detection
+// ============================================================================
+
+/// Test that a `char*` parameter passed to `strlen` is classified NUL-terminated.
+#[test]
+fn test_termination_style_strlen_call_is_nul_terminated() {
+    // C: int text_len(const char* s) { return strlen(s); }
+    let func = HirFunction::new_with_body(
+        "text_len".to_string(),
+        HirType::Int,
+        vec![HirParameter::new(
+            "s".to_string(),
+            HirType::Pointer(Box::new(HirType::Char)),
+        )],
+        vec![HirStatement::Return(Some(HirExpression::FunctionCall {
+            function: "strlen".to_string(),
+            arguments: vec![HirExpression::Variable("s".to_string())],
+        }))],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.termination_style("s"),
+        Some(TerminationStyle::NulTerminated)
+    );
+}
+
+/// Test that a `char*` parameter scanned with a `while (*p)` loop is
+/// classified NUL-terminated.
+#[test]
+fn test_termination_style_zero_check_loop_is_nul_terminated() {
+    // C: void print(const char* p) { while (*p) { p = p; } }
+    let func = HirFunction::new_with_body(
+        "print".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "p".to_string(),
+            HirType::Pointer(Box::new(HirType::Char)),
+        )],
+        vec![HirStatement::While {
+            condition: HirExpression::Dereference(Box::new(HirExpression::Variable(
+                "p".to_string(),
+            ))),
+            body: vec![HirStatement::Assignment {
+                target: "p".to_string(),
+                value: HirExpression::Variable("p".to_string()),
+            }],
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.termination_style("p"),
+        Some(TerminationStyle::NulTerminated)
+    );
+}
+
+/// Test that an `int*` parameter scanned with `*p != 0` is classified
+/// sentinel-terminated, sized to the element type.
+#[test]
+fn test_termination_style_zero_check_non_char_is_sentinel_terminated() {
+    // C: void process(int* p) { while (*p != 0) { p = p; } }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "p".to_string(),
+            HirType::Pointer(Box::new(HirType::Int)),
+        )],
+        vec![HirStatement::While {
+            condition: HirExpression::BinaryOp {
+                op: decy_hir::BinaryOperator::NotEqual,
+                left: Box::new(HirExpression::Dereference(Box::new(
+                    HirExpression::Variable("p".to_string()),
+                ))),
+                right: Box::new(HirExpression::IntLiteral(0)),
+            },
+            body: vec![HirStatement::Assignment {
+                target: "p".to_string(),
+                value: HirExpression::Variable("p".to_string()),
+            }],
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(
+        graph.termination_style("p"),
+        Some(TerminationStyle::SentinelTerminated(4))
+    );
+}
+
+/// Test that a detected array parameter (already length-bound) never gets a
+/// termination style, even if it happens to also contain a zero check.
+#[test]
+fn test_termination_style_none_for_length_bound_array() {
+    // C: void process(int* arr, int len) { arr[0] = 1; }
+    let func = HirFunction::new_with_body(
+        "process".to_string(),
+        HirType::Void,
+        vec![
+            HirParameter::new("arr".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+            HirParameter::new("len".to_string(), HirType::Int),
+        ],
+        vec![HirStatement::ArrayIndexAssignment {
+            array: Box::new(HirExpression::Variable("arr".to_string())),
+            index: Box::new(HirExpression::IntLiteral(0)),
+            value: HirExpression::IntLiteral(1),
+        }],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.termination_style("arr"), None);
+}
+
+/// Test that a pointer parameter with no termination evidence at all (no
+/// length, no scan, no strlen-family call) yields no termination style.
+#[test]
+fn test_termination_style_none_without_evidence() {
+    // C: void register_handle(void* handle) { }
+    let func = HirFunction::new_with_body(
+        "register_handle".to_string(),
+        HirType::Void,
+        vec![HirParameter::new(
+            "handle".to_string(),
+            HirType::Pointer(Box::new(HirType::Void)),
+        )],
+        vec![],
+    );
+
+    let analyzer = DataflowAnalyzer::new();
+    let graph = analyzer.analyze(&func);
+
+    assert_eq!(graph.termination_style("handle"), None);
+}
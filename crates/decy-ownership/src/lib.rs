@@ -29,6 +29,7 @@ pub mod lifetime;
 pub mod lifetime_gen;
 pub mod ml_features;
 pub mod model_versioning;
+pub mod provenance;
 pub mod retraining_pipeline;
 pub mod struct_lifetime;
 pub mod threshold_tuning;
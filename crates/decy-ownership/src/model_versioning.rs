@@ -33,7 +33,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Semantic version for models.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+///
+/// Ordering compares the numeric `(major, minor, patch)` triple first; when
+/// that triple is equal, a pre-release `channel` (e.g. `"rc1"`) sorts below
+/// the channel-less release (so `1.20.0-rc1 < 1.20.0`). Use
+/// [`ModelVersion::numeric_eq`] when only the triple matters.
+#[derive(Debug, Clone, Eq, Hash, Serialize, Deserialize)]
 pub struct ModelVersion {
     /// Major version (breaking changes)
     pub major: u32,
@@ -41,15 +46,28 @@ pub struct ModelVersion {
     pub minor: u32,
     /// Patch version (bug fixes)
     pub patch: u32,
+    /// Pre-release channel label (e.g. `"rc1"`, `"nightly"`, `"beta"`), if any.
+    pub channel: Option<String>,
 }
 
 impl ModelVersion {
-    /// Create a new version.
+    /// Create a new version with no pre-release channel.
     pub fn new(major: u32, minor: u32, patch: u32) -> Self {
         Self {
             major,
             minor,
             patch,
+            channel: None,
+        }
+    }
+
+    /// Create a new pre-release version tagged with the given channel.
+    pub fn with_channel(major: u32, minor: u32, patch: u32, channel: impl Into<String>) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            channel: Some(channel.into()),
         }
     }
 
@@ -68,24 +86,85 @@ impl ModelVersion {
         Self::new(self.major, self.minor, self.patch + 1)
     }
 
-    /// Parse from string (e.g., "1.2.3").
+    /// True if the numeric `(major, minor, patch)` triple matches, ignoring
+    /// the pre-release channel (so `1.0.0-rc1` and `1.0.0` compare equal here
+    /// even though `==` considers them distinct).
+    pub fn numeric_eq(&self, other: &Self) -> bool {
+        (self.major, self.minor, self.patch) == (other.major, other.minor, other.patch)
+    }
+
+    /// Parse from string: `major[.minor[.patch[-channel]]]`, e.g. `"1"`,
+    /// `"1.3"`, `"v2.3.4"`, or `"1.20.0-rc1"`. Omitted minor/patch default to
+    /// 0 and a leading `v`/`V` is stripped.
     pub fn parse(s: &str) -> Option<Self> {
-        let parts: Vec<&str> = s.trim_start_matches('v').split('.').collect();
-        if parts.len() != 3 {
-            return None;
-        }
+        let s = s.trim_start_matches(['v', 'V']);
+        let mut parts = s.splitn(3, '.');
+
+        let major: u32 = parts.next()?.parse().ok()?;
+        let minor: u32 = match parts.next() {
+            Some(p) => p.parse().ok()?,
+            None => 0,
+        };
+
+        let (patch_str, channel) = match parts.next() {
+            Some(p) => match p.split_once('-') {
+                Some((patch_str, channel)) => (patch_str, Some(channel.to_string())),
+                None => (p, None),
+            },
+            None => ("0", None),
+        };
+        let patch: u32 = patch_str.parse().ok()?;
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+            channel,
+        })
+    }
+}
+
+impl std::str::FromStr for ModelVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s).ok_or_else(|| format!("invalid model version: {s:?}"))
+    }
+}
 
-        let major = parts[0].parse().ok()?;
-        let minor = parts[1].parse().ok()?;
-        let patch = parts[2].parse().ok()?;
+impl PartialEq for ModelVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.numeric_eq(other) && self.channel == other.channel
+    }
+}
+
+impl PartialOrd for ModelVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-        Some(Self::new(major, minor, patch))
+impl Ord for ModelVersion {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.channel, &other.channel) {
+                (None, None) => std::cmp::Ordering::Equal,
+                // A pre-release sorts below its corresponding release.
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
     }
 }
 
 impl std::fmt::Display for ModelVersion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "v{}.{}.{}", self.major, self.minor, self.patch)
+        write!(f, "v{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(channel) = &self.channel {
+            write!(f, "-{channel}")?;
+        }
+        Ok(())
     }
 }
 
@@ -185,6 +264,502 @@ impl Default for QualityThresholds {
     }
 }
 
+/// How [`ModelVersionManager::register_version`] treats an entry whose
+/// accuracy falls below the manager's configured `min_accuracy` floor,
+/// mirroring Cargo's MSRV resolver evolution (an unset/failing constraint
+/// de-prioritizes a candidate instead of rejecting it outright).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccuracyGate {
+    /// Reject registration outright if accuracy is below `min_accuracy`.
+    Require,
+    /// Accept the registration, but flag the entry as below-threshold so
+    /// rollback-target selection skips it unless no above-threshold
+    /// candidate is available.
+    Prefer,
+}
+
+impl Default for AccuracyGate {
+    fn default() -> Self {
+        AccuracyGate::Require
+    }
+}
+
+/// Configures how [`ModelVersionManager::resolve_active`] ranks candidate
+/// versions: passing `thresholds` beats failing them, rolled-back entries
+/// sink to the bottom instead of being excluded outright, and entries with
+/// no real samples (`sample_count == 0`) rank below entries with measured
+/// metrics rather than being treated as failures. Within any tier, the
+/// higher version wins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivationPolicy {
+    /// Quality thresholds used to decide the passing tier.
+    pub thresholds: QualityThresholds,
+}
+
+impl Default for ActivationPolicy {
+    fn default() -> Self {
+        Self {
+            thresholds: QualityThresholds::default(),
+        }
+    }
+}
+
+/// Strategy for choosing among multiple rollback-eligible versions, used by
+/// [`ModelVersionManager::rollback`] (the no-argument "go back one") and by
+/// [`ModelVersionManager::rollback_to_req`] when several registered versions
+/// satisfy the given requirement. Set via
+/// [`ModelVersionManager::with_selection_policy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SelectionPolicy {
+    /// Prefer the newest eligible version. This is the default. For
+    /// [`ModelVersionManager::rollback`] every non-rolled-back entry other
+    /// than the active one is eligible, so a registered but never-activated
+    /// version newer than the active one can be picked.
+    MaximumVersion,
+    /// Prefer the oldest compatible version - useful for finding the
+    /// earliest still-good model.
+    MinimumVersion,
+    /// Ignore version order entirely and pick the eligible entry with the
+    /// best recorded accuracy.
+    HighestAccuracy,
+}
+
+impl Default for SelectionPolicy {
+    fn default() -> Self {
+        SelectionPolicy::MaximumVersion
+    }
+}
+
+/// A set of version constraints, stored as a sorted list of half-open
+/// `[lo, hi)` segments (an unbounded end is represented as `None`).
+///
+/// Supports the usual set operations (`union`, `intersection`) so callers
+/// can combine constraints like "`>= 1.1.0`" and "`< 2.0.0`" into a single
+/// range to query against [`ModelVersionManager::versions_in_range`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VersionRange {
+    segments: Vec<(Option<ModelVersion>, Option<ModelVersion>)>,
+}
+
+impl VersionRange {
+    /// The unconstrained range: every version matches.
+    pub fn any() -> Self {
+        Self {
+            segments: vec![(None, None)],
+        }
+    }
+
+    /// The empty range: no version matches.
+    pub fn none() -> Self {
+        Self {
+            segments: Vec::new(),
+        }
+    }
+
+    /// Matches only `v` exactly (numeric triple; channel is ignored for
+    /// range purposes).
+    pub fn exact(v: ModelVersion) -> Self {
+        let hi = Self::successor(&v);
+        Self {
+            segments: vec![(Some(v), Some(hi))],
+        }
+    }
+
+    /// Matches any version strictly greater than `v`.
+    pub fn higher_than(v: ModelVersion) -> Self {
+        Self {
+            segments: vec![(Some(Self::successor(&v)), None)],
+        }
+    }
+
+    /// Matches any version greater than or equal to `v`.
+    pub fn at_least(v: ModelVersion) -> Self {
+        Self {
+            segments: vec![(Some(v), None)],
+        }
+    }
+
+    /// Matches any version strictly lower than `v`.
+    pub fn strictly_lower_than(v: ModelVersion) -> Self {
+        Self {
+            segments: vec![(None, Some(v))],
+        }
+    }
+
+    /// Matches any version in the inclusive interval `[v1, v2]`.
+    pub fn between(v1: ModelVersion, v2: ModelVersion) -> Self {
+        let hi = Self::successor(&v2);
+        Self {
+            segments: vec![(Some(v1), Some(hi))],
+        }
+    }
+
+    /// The smallest numeric triple strictly greater than `v`'s.
+    fn successor(v: &ModelVersion) -> ModelVersion {
+        ModelVersion::new(v.major, v.minor, v.patch + 1)
+    }
+
+    /// Parse a semver-style requirement string into the range it selects:
+    /// - `^MAJOR.MINOR` - same major, minor >= given (bounded below the next major)
+    /// - `~MAJOR.MINOR` - same major and minor, any patch
+    /// - `MAJOR.x` / `MAJOR.*` - any version with that major
+    /// - an exact version (e.g. `1.1.0`) - that triple only
+    pub fn parse_requirement(req: &str) -> Result<Self, String> {
+        let trimmed = req.trim();
+        let invalid = || format!("invalid version requirement: {trimmed:?}");
+
+        if let Some(rest) = trimmed.strip_prefix('^') {
+            let v = ModelVersion::parse(rest).ok_or_else(invalid)?;
+            let lo = ModelVersion::new(v.major, v.minor, 0);
+            let hi = ModelVersion::new(v.major + 1, 0, 0);
+            return Ok(Self::at_least(lo).intersection(&Self::strictly_lower_than(hi)));
+        }
+
+        if let Some(rest) = trimmed.strip_prefix('~') {
+            let v = ModelVersion::parse(rest).ok_or_else(invalid)?;
+            let lo = ModelVersion::new(v.major, v.minor, 0);
+            let hi = ModelVersion::new(v.major, v.minor + 1, 0);
+            return Ok(Self::at_least(lo).intersection(&Self::strictly_lower_than(hi)));
+        }
+
+        if let Some((major_str, tail)) = trimmed.split_once('.') {
+            if tail.eq_ignore_ascii_case("x") || tail == "*" {
+                let major: u32 = major_str.parse().map_err(|_| invalid())?;
+                let lo = ModelVersion::new(major, 0, 0);
+                let hi = ModelVersion::new(major + 1, 0, 0);
+                return Ok(Self::at_least(lo).intersection(&Self::strictly_lower_than(hi)));
+            }
+        }
+
+        let v = ModelVersion::parse(trimmed).ok_or_else(invalid)?;
+        Ok(Self::exact(v))
+    }
+
+    fn lo_key(lo: &Option<ModelVersion>) -> (u32, u32, u32) {
+        lo.as_ref()
+            .map(|v| (v.major, v.minor, v.patch))
+            .unwrap_or((0, 0, 0))
+    }
+
+    fn le(a: &Option<ModelVersion>, b: &Option<ModelVersion>) -> bool {
+        match (a, b) {
+            (_, None) => true,
+            (None, Some(_)) => true,
+            (Some(a), Some(b)) => (a.major, a.minor, a.patch) <= (b.major, b.minor, b.patch),
+        }
+    }
+
+    fn lt(a: &Option<ModelVersion>, b: &Option<ModelVersion>) -> bool {
+        match (a, b) {
+            (None, None) => false,
+            (None, Some(_)) => true,
+            (Some(_), None) => false,
+            (Some(a), Some(b)) => (a.major, a.minor, a.patch) < (b.major, b.minor, b.patch),
+        }
+    }
+
+    fn max_lo(a: &Option<ModelVersion>, b: &Option<ModelVersion>) -> Option<ModelVersion> {
+        // A `None` lower bound is unbounded below, so the other bound wins.
+        match (a, b) {
+            (None, _) => b.clone(),
+            (_, None) => a.clone(),
+            _ if Self::le(a, b) => b.clone(),
+            _ => a.clone(),
+        }
+    }
+
+    fn min_hi(a: &Option<ModelVersion>, b: &Option<ModelVersion>) -> Option<ModelVersion> {
+        match (a, b) {
+            (None, other) | (other, None) => other.clone(),
+            (Some(x), Some(y)) => {
+                if (x.major, x.minor, x.patch) <= (y.major, y.minor, y.patch) {
+                    Some(x.clone())
+                } else {
+                    Some(y.clone())
+                }
+            }
+        }
+    }
+
+    fn max_hi(a: &Option<ModelVersion>, b: &Option<ModelVersion>) -> Option<ModelVersion> {
+        match (a, b) {
+            (None, _) | (_, None) => None,
+            (Some(x), Some(y)) => {
+                if (x.major, x.minor, x.patch) >= (y.major, y.minor, y.patch) {
+                    Some(x.clone())
+                } else {
+                    Some(y.clone())
+                }
+            }
+        }
+    }
+
+    /// True if `v` falls within any segment of this range.
+    pub fn contains(&self, v: &ModelVersion) -> bool {
+        let as_bound = Some(v.clone());
+        self.segments
+            .iter()
+            .any(|(lo, hi)| Self::le(lo, &as_bound) && Self::lt(&as_bound, hi))
+    }
+
+    /// The union of `self` and `other`: concatenates both segment lists,
+    /// sorts by lower bound, and coalesces adjacent/overlapping segments.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut all: Vec<_> = self
+            .segments
+            .iter()
+            .chain(other.segments.iter())
+            .cloned()
+            .collect();
+        all.sort_by_key(|(lo, _)| Self::lo_key(lo));
+
+        let mut merged: Vec<(Option<ModelVersion>, Option<ModelVersion>)> = Vec::new();
+        for (lo, hi) in all {
+            match merged.last_mut() {
+                // `lo <= last_hi` covers both overlap and exact adjacency
+                // (e.g. [1,2) and [2,3) touch at the boundary and coalesce).
+                Some((_, last_hi)) if Self::le(&lo, last_hi) => {
+                    *last_hi = Self::max_hi(last_hi, &hi);
+                }
+                _ => merged.push((lo, hi)),
+            }
+        }
+        Self { segments: merged }
+    }
+
+    /// The intersection of `self` and `other`: walks both sorted segment
+    /// lists and keeps the overlap of each pair.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut segments = Vec::new();
+        for (lo1, hi1) in &self.segments {
+            for (lo2, hi2) in &other.segments {
+                let lo = Self::max_lo(lo1, lo2);
+                let hi = Self::min_hi(hi1, hi2);
+                if Self::lt(&lo, &hi) || hi.is_none() {
+                    segments.push((lo, hi));
+                }
+            }
+        }
+        segments.sort_by_key(|(lo, _)| Self::lo_key(lo));
+        Self { segments }
+    }
+}
+
+/// A dependency-free 256-bit hash used for the tamper-evident version chain.
+///
+/// This is a simple FNV-1a/splitmix64 mixer, not a cryptographically vetted
+/// hash function - it exists only to make accidental or malicious edits to
+/// the in-memory chain detectable without pulling in an external hashing
+/// crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hash256([u64; 4]);
+
+impl Hash256 {
+    /// The hash chain's starting point (no prior history).
+    pub const GENESIS: Hash256 = Hash256([0, 0, 0, 0]);
+
+    fn of(data: &[u8]) -> Self {
+        const SEEDS: [u64; 4] = [
+            0xcbf2_9ce4_8422_2325,
+            0x0000_0001_0000_01b3,
+            0x9e37_79b9_7f4a_7c15,
+            0xbf58_476d_1ce4_e5b9,
+        ];
+        let mut lanes = [0u64; 4];
+        for (lane, seed) in lanes.iter_mut().zip(SEEDS.iter()) {
+            let mut h = *seed;
+            for &byte in data {
+                h ^= byte as u64;
+                h = h.wrapping_mul(0x0000_0001_0000_01b3);
+            }
+            // splitmix64 finalizer, to spread the FNV accumulation evenly.
+            h ^= h >> 30;
+            h = h.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            h ^= h >> 27;
+            h = h.wrapping_mul(0x94d0_49bb_1331_11eb);
+            h ^= h >> 31;
+            *lane = h;
+        }
+        Hash256(lanes)
+    }
+
+    /// Chain a new payload onto a previous hash: `H(prev || payload)`.
+    pub fn chain(prev: Hash256, payload: &[u8]) -> Self {
+        let mut buf = Vec::with_capacity(32 + payload.len());
+        for lane in prev.0 {
+            buf.extend_from_slice(&lane.to_le_bytes());
+        }
+        buf.extend_from_slice(payload);
+        Self::of(&buf)
+    }
+
+    /// Render as lowercase hex.
+    pub fn to_hex(self) -> String {
+        self.0.iter().map(|lane| format!("{lane:016x}")).collect()
+    }
+}
+
+impl Default for Hash256 {
+    fn default() -> Self {
+        Self::GENESIS
+    }
+}
+
+impl std::fmt::Display for Hash256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_hex())
+    }
+}
+
+/// The chain content bytes committed for a newly registered version:
+/// `version || checksum_of_artifact || metrics || released_at` (the
+/// preceding hash is threaded in separately by [`Hash256::chain`]).
+fn entry_content_bytes(
+    version: &ModelVersion,
+    metrics: &ModelQualityMetrics,
+    artifact_path: &str,
+    released_at: u64,
+) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&version.major.to_le_bytes());
+    buf.extend_from_slice(&version.minor.to_le_bytes());
+    buf.extend_from_slice(&version.patch.to_le_bytes());
+    buf.extend_from_slice(version.channel.as_deref().unwrap_or("").as_bytes());
+
+    // Stand-in for a real artifact checksum: hash the artifact path/contents
+    // identifier, since the manager itself never reads the artifact file.
+    let artifact_checksum = Hash256::of(artifact_path.as_bytes());
+    for lane in artifact_checksum.0 {
+        buf.extend_from_slice(&lane.to_le_bytes());
+    }
+
+    buf.extend_from_slice(&metrics.accuracy.to_le_bytes());
+    buf.extend_from_slice(&metrics.precision.to_le_bytes());
+    buf.extend_from_slice(&metrics.recall.to_le_bytes());
+    buf.extend_from_slice(&metrics.f1_score.to_le_bytes());
+    buf.extend_from_slice(&metrics.avg_confidence.to_le_bytes());
+    buf.extend_from_slice(&metrics.fallback_rate.to_le_bytes());
+    buf.extend_from_slice(&metrics.sample_count.to_le_bytes());
+    buf.extend_from_slice(&released_at.to_le_bytes());
+    buf
+}
+
+/// The chain content bytes committed for a rollback record:
+/// `from || to || reason || timestamp`.
+fn rollback_content_bytes(
+    from: &ModelVersion,
+    to: &ModelVersion,
+    reason: &str,
+    timestamp: u64,
+) -> Vec<u8> {
+    let mut buf = Vec::new();
+    buf.extend_from_slice(&from.major.to_le_bytes());
+    buf.extend_from_slice(&from.minor.to_le_bytes());
+    buf.extend_from_slice(&from.patch.to_le_bytes());
+    buf.extend_from_slice(&to.major.to_le_bytes());
+    buf.extend_from_slice(&to.minor.to_le_bytes());
+    buf.extend_from_slice(&to.patch.to_le_bytes());
+    buf.extend_from_slice(reason.as_bytes());
+    buf.extend_from_slice(&timestamp.to_le_bytes());
+    buf
+}
+
+/// Sort key for [`ModelVersionManager::resolve_active`]: larger is a better
+/// activation candidate. Compared lexicographically, so earlier fields take
+/// priority - not-rolled-back, then quality-passing, then has-real-samples,
+/// then the version itself.
+fn activation_rank(
+    entry: &ModelEntry,
+    thresholds: &QualityThresholds,
+) -> (bool, bool, bool, ModelVersion) {
+    (
+        !entry.rolled_back,
+        entry.metrics.meets_thresholds(thresholds),
+        entry.metrics.sample_count > 0,
+        entry.version.clone(),
+    )
+}
+
+/// One link in the manager's append-only tamper-evident hash chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum ChainEvent {
+    /// A version was registered.
+    Registered {
+        version: ModelVersion,
+        metrics: ModelQualityMetrics,
+        artifact_path: String,
+        released_at: u64,
+        content: Vec<u8>,
+        hash: Hash256,
+    },
+    /// A rollback was committed.
+    RolledBack { content: Vec<u8>, hash: Hash256 },
+}
+
+impl ChainEvent {
+    fn content(&self) -> &[u8] {
+        match self {
+            ChainEvent::Registered { content, .. } => content,
+            ChainEvent::RolledBack { content, .. } => content,
+        }
+    }
+
+    fn hash(&self) -> Hash256 {
+        match self {
+            ChainEvent::Registered { hash, .. } | ChainEvent::RolledBack { hash, .. } => *hash,
+        }
+    }
+}
+
+/// The sibling data needed to recompute the chain's root hash from a single
+/// registered version, without needing the rest of the log.
+///
+/// Returned by [`ModelVersionManager::prove_existed`] and checked with the
+/// standalone [`verify_existence`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExistenceProof {
+    /// The version this proof attests to.
+    pub version: ModelVersion,
+    /// The metrics recorded for that version at registration time.
+    pub metrics: ModelQualityMetrics,
+    /// The artifact path recorded for that version.
+    pub artifact_path: String,
+    /// The release timestamp recorded for that version.
+    pub released_at: u64,
+    /// The chain hash immediately before this version was registered.
+    pub prev_hash: Hash256,
+    /// The hash this version committed at registration time.
+    pub entry_hash: Hash256,
+    /// Content bytes of every chain event after this one, in order, needed
+    /// to replay the chain from `entry_hash` up to the root.
+    pub subsequent_contents: Vec<Vec<u8>>,
+}
+
+/// Verify an [`ExistenceProof`] against a known-good `root_hash`, without
+/// access to the rest of the chain.
+///
+/// Recomputes `entry_hash` from the proof's own version/metrics/artifact so
+/// a forged proof cannot claim metrics it didn't actually commit, then
+/// replays the remaining chain to confirm it reaches `root_hash`.
+pub fn verify_existence(proof: &ExistenceProof, root_hash: Hash256) -> bool {
+    let content = entry_content_bytes(
+        &proof.version,
+        &proof.metrics,
+        &proof.artifact_path,
+        proof.released_at,
+    );
+    let recomputed_entry_hash = Hash256::chain(proof.prev_hash, &content);
+    if recomputed_entry_hash != proof.entry_hash {
+        return false;
+    }
+
+    let mut hash = proof.entry_hash;
+    for content in &proof.subsequent_contents {
+        hash = Hash256::chain(hash, content);
+    }
+    hash == root_hash
+}
+
 /// A versioned model entry.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelEntry {
@@ -204,10 +779,20 @@ pub struct ModelEntry {
     pub rolled_back: bool,
     /// Rollback reason (if applicable)
     pub rollback_reason: Option<String>,
+    /// Set when this entry's accuracy was below the manager's `min_accuracy`
+    /// floor at registration time under [`AccuracyGate::Prefer`]. Never set
+    /// under [`AccuracyGate::Require`], since such entries are rejected.
+    pub below_accuracy_threshold: bool,
+    /// This entry's commitment in the manager's tamper-evident hash chain.
+    pub entry_hash: Hash256,
 }
 
 impl ModelEntry {
     /// Create a new model entry.
+    ///
+    /// `entry_hash` starts at [`Hash256::GENESIS`] and is set for real by
+    /// [`ModelVersionManager::register_version`], which knows the chain's
+    /// current tip.
     pub fn new(
         version: ModelVersion,
         metrics: ModelQualityMetrics,
@@ -228,10 +813,25 @@ impl ModelEntry {
             is_active: false,
             rolled_back: false,
             rollback_reason: None,
+            below_accuracy_threshold: false,
+            entry_hash: Hash256::GENESIS,
         }
     }
 }
 
+/// A non-fatal issue noticed about a registered version - e.g. unset
+/// accuracy, missing metadata, or a `(major, minor, patch)` that duplicates
+/// an already-registered version's. Collected by
+/// [`ModelVersionManager::register_version`] rather than rejecting the
+/// registration outright, mirroring Cargo's warning on an unset edition.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RegistrationWarning {
+    /// Version the warning is about.
+    pub version: ModelVersion,
+    /// Human-readable description of what's missing or suspicious.
+    pub message: String,
+}
+
 /// Result of a rollback operation.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RollbackResult {
@@ -245,6 +845,149 @@ pub struct RollbackResult {
     pub reason: String,
     /// Timestamp
     pub timestamp: u64,
+    /// This record's commitment in the manager's tamper-evident hash chain.
+    pub record_hash: Hash256,
+}
+
+/// A preview of what `rollback()` or `rollback_to()` would do, computed by
+/// running the same selection logic and error paths without mutating any
+/// manager state (no index/flag changes, no hash chain commit, no history
+/// entry).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RollbackPlan {
+    /// Version that would be rolled back from.
+    pub from_version: ModelVersion,
+    /// Version that would become active.
+    pub to_version: ModelVersion,
+    /// Reason that would be recorded.
+    pub reason: String,
+}
+
+/// Direction of change for one [`MetricDelta`], mirroring how `cargo update`
+/// labels a lockfile change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiffStatus {
+    /// The metric increased from `from` to `to`.
+    Upgrading,
+    /// The metric decreased from `from` to `to`.
+    Downgrading,
+    /// The metric is the same in both versions.
+    Unchanged,
+}
+
+impl std::fmt::Display for DiffStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            DiffStatus::Upgrading => "Upgrading",
+            DiffStatus::Downgrading => "Downgrading",
+            DiffStatus::Unchanged => "Unchanged",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// One quality-metric's before/after comparison within a [`VersionDiff`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricDelta {
+    /// Metric field name (e.g. `"accuracy"`).
+    pub name: &'static str,
+    /// Value on the `from` version.
+    pub from: f64,
+    /// Value on the `to` version.
+    pub to: f64,
+    /// Whether `to` is an increase, decrease, or no change from `from`.
+    pub status: DiffStatus,
+}
+
+impl MetricDelta {
+    fn new(name: &'static str, from: f64, to: f64) -> Self {
+        let status = if (to - from).abs() < f64::EPSILON {
+            DiffStatus::Unchanged
+        } else if to > from {
+            DiffStatus::Upgrading
+        } else {
+            DiffStatus::Downgrading
+        };
+        Self {
+            name,
+            from,
+            to,
+            status,
+        }
+    }
+
+    /// `to - from`.
+    pub fn delta(&self) -> f64 {
+        self.to - self.from
+    }
+}
+
+/// A `cargo update`-style summary of the quality-metric changes between two
+/// registered versions, as produced by [`ModelVersionManager::diff`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionDiff {
+    /// Version being compared from.
+    pub from_version: ModelVersion,
+    /// Version being compared to.
+    pub to_version: ModelVersion,
+    /// Per-metric before/after comparisons.
+    pub metrics: Vec<MetricDelta>,
+    /// How many registered versions are newer than `to_version` - how far an
+    /// operator rolling back to it would be reverting.
+    pub versions_behind: usize,
+}
+
+impl VersionDiff {
+    /// Render as a `cargo update`-style report.
+    pub fn to_markdown(&self) -> String {
+        let mut report = format!("{} -> {}\n", self.from_version, self.to_version);
+        for metric in &self.metrics {
+            report.push_str(&format!(
+                "  {} {} {:.3} -> {:.3} ({:+.3})\n",
+                metric.status,
+                metric.name,
+                metric.from,
+                metric.to,
+                metric.delta()
+            ));
+        }
+        report.push_str(&format!(
+            "  {} version(s) behind latest\n",
+            self.versions_behind
+        ));
+        report
+    }
+}
+
+/// A full preview of what `rollback()`/`rollback_to()` would do - target
+/// version, success flag, and the `cargo update --dry-run`-style diff -
+/// computed without mutating any manager state.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollbackDryRun {
+    /// Whether a rollback with this shape would succeed (construction fails
+    /// with an `Err` otherwise, so this is always `true`).
+    pub success: bool,
+    /// Version that would be rolled back from.
+    pub from_version: ModelVersion,
+    /// Version that would become active.
+    pub to_version: ModelVersion,
+    /// Reason that would be recorded.
+    pub reason: String,
+    /// Quality-metric diff between `from_version` and `to_version`.
+    pub diff: VersionDiff,
+}
+
+impl RollbackDryRun {
+    /// Render as a `cargo update --dry-run`-style report: what would happen,
+    /// clearly marked as not applied.
+    pub fn to_markdown(&self) -> String {
+        let mut report = format!(
+            "Would roll back {} -> {}: {}\n(dry run - not applied)\n\n",
+            self.from_version, self.to_version, self.reason
+        );
+        report.push_str(&self.diff.to_markdown());
+        report
+    }
 }
 
 /// Model version manager with rollback capability.
@@ -260,6 +1003,21 @@ pub struct ModelVersionManager {
     max_history: usize,
     /// Rollback history
     rollback_history: Vec<RollbackResult>,
+    /// Current tip of the tamper-evident hash chain.
+    root_hash: Hash256,
+    /// Append-only log backing the hash chain (independent of `versions`,
+    /// which is pruned, so the chain survives pruning intact).
+    chain_log: Vec<ChainEvent>,
+    /// Strategy for choosing among multiple rollback-eligible versions.
+    selection_policy: SelectionPolicy,
+    /// Minimum accuracy a registered entry should meet. Defaults to `0.0`,
+    /// which never gates anything.
+    min_accuracy: f64,
+    /// Whether `min_accuracy` is a hard requirement or a soft preference.
+    accuracy_gate: AccuracyGate,
+    /// Non-fatal issues noticed about registered entries, accumulated
+    /// across every `register_version` call.
+    warnings: Vec<RegistrationWarning>,
 }
 
 impl Default for ModelVersionManager {
@@ -277,6 +1035,12 @@ impl ModelVersionManager {
             thresholds: QualityThresholds::default(),
             max_history: 10,
             rollback_history: Vec::new(),
+            root_hash: Hash256::GENESIS,
+            chain_log: Vec::new(),
+            selection_policy: SelectionPolicy::default(),
+            min_accuracy: 0.0,
+            accuracy_gate: AccuracyGate::default(),
+            warnings: Vec::new(),
         }
     }
 
@@ -294,6 +1058,21 @@ impl ModelVersionManager {
         self
     }
 
+    /// Set the strategy used to choose among multiple rollback-eligible
+    /// versions (see [`SelectionPolicy`]).
+    pub fn with_selection_policy(mut self, policy: SelectionPolicy) -> Self {
+        self.selection_policy = policy;
+        self
+    }
+
+    /// Set the minimum accuracy floor and how registration enforces it
+    /// (see [`AccuracyGate`]).
+    pub fn with_min_accuracy(mut self, min_accuracy: f64, gate: AccuracyGate) -> Self {
+        self.min_accuracy = min_accuracy;
+        self.accuracy_gate = gate;
+        self
+    }
+
     /// Get current active version.
     pub fn active_version(&self) -> Option<&ModelEntry> {
         self.active_index.and_then(|i| self.versions.get(i))
@@ -314,6 +1093,56 @@ impl ModelVersionManager {
         &self.thresholds
     }
 
+    /// Get all registered versions whose `version` falls within `range`.
+    pub fn versions_in_range<'a>(
+        &'a self,
+        range: &'a VersionRange,
+    ) -> impl Iterator<Item = &'a ModelEntry> {
+        self.versions
+            .iter()
+            .filter(move |entry| range.contains(&entry.version))
+    }
+
+    /// The highest-version entry within `range` that is not rolled back and
+    /// meets the current quality thresholds, if any.
+    pub fn best_in_range(&self, range: &VersionRange) -> Option<&ModelEntry> {
+        self.versions_in_range(range)
+            .filter(|entry| !entry.rolled_back && entry.metrics.meets_thresholds(&self.thresholds))
+            .max_by(|a, b| a.version.cmp(&b.version))
+    }
+
+    /// Re-rank every registered version under `policy` and make the winner
+    /// active, then return it.
+    ///
+    /// Unlike [`Self::register_version`], which only ever compares the
+    /// newly-registered entry against whatever is currently active, this
+    /// re-evaluates the whole fleet: quality-passing versions beat failing
+    /// ones, rolled-back entries are pushed to the bottom rather than
+    /// excluded, and entries with no real samples are de-prioritized below
+    /// entries with measured metrics. This lets an operator register an
+    /// experimental candidate without it silently becoming active or being
+    /// discarded, then decide later whether it should win.
+    pub fn resolve_active(&mut self, policy: &ActivationPolicy) -> Option<&ModelEntry> {
+        let winner_idx = self
+            .versions
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, entry)| activation_rank(entry, &policy.thresholds))
+            .map(|(i, _)| i)?;
+
+        if let Some(idx) = self.active_index {
+            if let Some(current) = self.versions.get_mut(idx) {
+                current.is_active = false;
+            }
+        }
+        if let Some(winner) = self.versions.get_mut(winner_idx) {
+            winner.is_active = true;
+        }
+        self.active_index = Some(winner_idx);
+
+        self.versions.get(winner_idx)
+    }
+
     /// Register a new model version.
     ///
     /// Returns Ok(true) if version was activated, Ok(false) if registered but not activated
@@ -329,7 +1158,39 @@ impl ModelVersionManager {
             }
         }
 
-        // Check quality thresholds
+        // Enforce the accuracy floor, if configured.
+        let below_accuracy_threshold = entry.metrics.accuracy < self.min_accuracy;
+        if below_accuracy_threshold && self.accuracy_gate == AccuracyGate::Require {
+            return Err(format!(
+                "accuracy {:.3} is below the required minimum {:.3}",
+                entry.metrics.accuracy, self.min_accuracy
+            ));
+        }
+        entry.below_accuracy_threshold = below_accuracy_threshold;
+
+        // Warn (but don't fail) about likely-incomplete metadata, mirroring
+        // Cargo's warning on an unset edition rather than refusing to build.
+        if entry.metrics.accuracy == 0.0 {
+            self.warnings.push(RegistrationWarning {
+                version: entry.version.clone(),
+                message: "accuracy was not set (defaults to 0.0)".to_string(),
+            });
+        }
+        if entry.artifact_path.trim().is_empty() {
+            self.warnings.push(RegistrationWarning {
+                version: entry.version.clone(),
+                message: "no artifact path was provided".to_string(),
+            });
+        }
+        if self.versions.iter().any(|e| e.version.numeric_eq(&entry.version)) {
+            self.warnings.push(RegistrationWarning {
+                version: entry.version.clone(),
+                message: "duplicates the (major, minor, patch) of an already-registered version"
+                    .to_string(),
+            });
+        }
+
+        // Check quality thresholds
         let meets_quality = entry.metrics.meets_thresholds(&self.thresholds);
 
         // Check if better than current active
@@ -341,6 +1202,25 @@ impl ModelVersionManager {
         // Decide whether to activate
         let should_activate = meets_quality && is_better;
 
+        // Commit this version onto the tamper-evident hash chain.
+        let content = entry_content_bytes(
+            &entry.version,
+            &entry.metrics,
+            &entry.artifact_path,
+            entry.released_at,
+        );
+        let hash = Hash256::chain(self.root_hash, &content);
+        entry.entry_hash = hash;
+        self.chain_log.push(ChainEvent::Registered {
+            version: entry.version.clone(),
+            metrics: entry.metrics.clone(),
+            artifact_path: entry.artifact_path.clone(),
+            released_at: entry.released_at,
+            content,
+            hash,
+        });
+        self.root_hash = hash;
+
         if should_activate {
             // Deactivate current
             if let Some(idx) = self.active_index {
@@ -365,10 +1245,10 @@ impl ModelVersionManager {
         Ok(should_activate)
     }
 
-    /// Rollback to the previous version.
-    pub fn rollback(&mut self, reason: impl Into<String>) -> Result<RollbackResult, String> {
-        let reason = reason.into();
-
+    /// Resolve the versions `rollback()` would act on, without mutating
+    /// anything. Shared by `rollback()` and `rollback_plan()` so both walk
+    /// the exact same selection and error paths.
+    fn resolve_rollback(&self) -> Result<(usize, ModelVersion, usize, ModelVersion), String> {
         // Need at least 2 versions to rollback
         if self.versions.len() < 2 {
             return Err("Not enough versions to rollback".to_string());
@@ -377,19 +1257,83 @@ impl ModelVersionManager {
         let current_idx = self.active_index.ok_or("No active version")?;
         let current_version = self.versions[current_idx].version.clone();
 
-        // Find previous non-rolled-back version
-        let prev_idx = self
+        // Pick a rollback target among every other non-rolled-back version
+        // per the configured selection policy, rather than always assuming
+        // the immediately-previous entry.
+        let candidates = self
             .versions
             .iter()
             .enumerate()
-            .rev()
-            .skip(1) // Skip current
-            .find(|(_, e)| !e.rolled_back)
-            .map(|(i, _)| i)
-            .ok_or("No previous version available for rollback")?;
+            .filter(|(i, entry)| *i != current_idx && !entry.rolled_back)
+            .map(|(i, _)| i);
+        let prev_idx =
+            self.select_index(candidates).ok_or("No previous version available for rollback")?;
 
         let prev_version = self.versions[prev_idx].version.clone();
 
+        Ok((current_idx, current_version, prev_idx, prev_version))
+    }
+
+    /// Pick one index out of `candidates` according to `self.selection_policy`.
+    fn select_index(&self, candidates: impl Iterator<Item = usize>) -> Option<usize> {
+        // Above-threshold candidates win over below-threshold ones; a
+        // below-threshold entry is only picked when it's the only option,
+        // so a `Prefer`-gated low-accuracy model stays a last resort rather
+        // than becoming an invisible rollback trap.
+        let candidates: Vec<usize> = candidates.collect();
+        let above_threshold: Vec<usize> = candidates
+            .iter()
+            .copied()
+            .filter(|&i| !self.versions[i].below_accuracy_threshold)
+            .collect();
+        let pool = if above_threshold.is_empty() {
+            candidates
+        } else {
+            above_threshold
+        };
+
+        match self.selection_policy {
+            SelectionPolicy::MaximumVersion => pool
+                .into_iter()
+                .max_by(|&a, &b| self.versions[a].version.cmp(&self.versions[b].version)),
+            SelectionPolicy::MinimumVersion => pool
+                .into_iter()
+                .min_by(|&a, &b| self.versions[a].version.cmp(&self.versions[b].version)),
+            SelectionPolicy::HighestAccuracy => pool.into_iter().max_by(|&a, &b| {
+                self.versions[a]
+                    .metrics
+                    .accuracy
+                    .partial_cmp(&self.versions[b].metrics.accuracy)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            }),
+        }
+    }
+
+    /// Preview what `rollback()` would do, without mutating any state.
+    pub fn rollback_plan(&self, reason: impl Into<String>) -> Result<RollbackPlan, String> {
+        let (_, current_version, _, prev_version) = self.resolve_rollback()?;
+        Ok(RollbackPlan {
+            from_version: current_version,
+            to_version: prev_version,
+            reason: reason.into(),
+        })
+    }
+
+    /// Like [`Self::rollback_plan`], but also computes the [`VersionDiff`]
+    /// between the two versions so the preview can be rendered as a full
+    /// `cargo update --dry-run`-style report via [`RollbackDryRun::to_markdown`].
+    pub fn rollback_dry_run(&self, reason: impl Into<String>) -> Result<RollbackDryRun, String> {
+        let plan = self.rollback_plan(reason)?;
+        self.dry_run_from_plan(plan)
+    }
+
+    /// Roll back from the active version to the one the manager's
+    /// [`SelectionPolicy`] picks among every other non-rolled-back version
+    /// (which may be newer than the active one).
+    pub fn rollback(&mut self, reason: impl Into<String>) -> Result<RollbackResult, String> {
+        let reason = reason.into();
+        let (current_idx, current_version, prev_idx, prev_version) = self.resolve_rollback()?;
+
         // Mark current as rolled back
         if let Some(current) = self.versions.get_mut(current_idx) {
             current.is_active = false;
@@ -408,12 +1352,16 @@ impl ModelVersionManager {
             .unwrap_or_default()
             .as_millis() as u64;
 
+        let record_hash =
+            self.commit_rollback_to_chain(&current_version, &prev_version, &reason, now);
+
         let result = RollbackResult {
             success: true,
             from_version: current_version,
             to_version: prev_version,
             reason,
             timestamp: now,
+            record_hash,
         };
 
         self.rollback_history.push(result.clone());
@@ -421,14 +1369,13 @@ impl ModelVersionManager {
         Ok(result)
     }
 
-    /// Rollback to a specific version.
-    pub fn rollback_to(
-        &mut self,
+    /// Resolve the versions `rollback_to()` would act on, without mutating
+    /// anything. Shared by `rollback_to()` and `rollback_to_plan()` so both
+    /// walk the exact same selection and error paths.
+    fn resolve_rollback_to(
+        &self,
         target: &ModelVersion,
-        reason: impl Into<String>,
-    ) -> Result<RollbackResult, String> {
-        let reason = reason.into();
-
+    ) -> Result<(usize, usize, ModelVersion), String> {
         let target_idx = self
             .versions
             .iter()
@@ -443,6 +1390,57 @@ impl ModelVersionManager {
 
         let current_version = self.versions[current_idx].version.clone();
 
+        Ok((current_idx, target_idx, current_version))
+    }
+
+    /// Preview what `rollback_to(target, ..)` would do, without mutating any
+    /// state.
+    pub fn rollback_to_plan(
+        &self,
+        target: &ModelVersion,
+        reason: impl Into<String>,
+    ) -> Result<RollbackPlan, String> {
+        let (_, _, current_version) = self.resolve_rollback_to(target)?;
+        Ok(RollbackPlan {
+            from_version: current_version,
+            to_version: target.clone(),
+            reason: reason.into(),
+        })
+    }
+
+    /// Like [`Self::rollback_to_plan`], but also computes the
+    /// [`VersionDiff`] between the two versions so the preview can be
+    /// rendered as a full `cargo update --dry-run`-style report via
+    /// [`RollbackDryRun::to_markdown`].
+    pub fn rollback_to_dry_run(
+        &self,
+        target: &ModelVersion,
+        reason: impl Into<String>,
+    ) -> Result<RollbackDryRun, String> {
+        let plan = self.rollback_to_plan(target, reason)?;
+        self.dry_run_from_plan(plan)
+    }
+
+    fn dry_run_from_plan(&self, plan: RollbackPlan) -> Result<RollbackDryRun, String> {
+        let diff = self.diff(&plan.from_version, &plan.to_version)?;
+        Ok(RollbackDryRun {
+            success: true,
+            from_version: plan.from_version,
+            to_version: plan.to_version,
+            reason: plan.reason,
+            diff,
+        })
+    }
+
+    /// Rollback to a specific version.
+    pub fn rollback_to(
+        &mut self,
+        target: &ModelVersion,
+        reason: impl Into<String>,
+    ) -> Result<RollbackResult, String> {
+        let reason = reason.into();
+        let (current_idx, target_idx, current_version) = self.resolve_rollback_to(target)?;
+
         // Mark current as rolled back
         if let Some(current) = self.versions.get_mut(current_idx) {
             current.is_active = false;
@@ -462,12 +1460,15 @@ impl ModelVersionManager {
             .unwrap_or_default()
             .as_millis() as u64;
 
+        let record_hash = self.commit_rollback_to_chain(&current_version, target, &reason, now);
+
         let result = RollbackResult {
             success: true,
             from_version: current_version,
             to_version: target.clone(),
             reason,
             timestamp: now,
+            record_hash,
         };
 
         self.rollback_history.push(result.clone());
@@ -475,11 +1476,171 @@ impl ModelVersionManager {
         Ok(result)
     }
 
+    /// Roll back to a registered version satisfying a semver-style
+    /// requirement (`^1.1`, `~1.2`, `1.x`/`1.*`, or an exact `1.1.0`), rather
+    /// than an exact [`ModelVersion`]. Among the matches, the manager's
+    /// [`SelectionPolicy`] picks the target (the highest matching version
+    /// under the default [`SelectionPolicy::MaximumVersion`]).
+    ///
+    /// Lets an operator say "roll back to anything compatible with 1.x"
+    /// without knowing exactly which patch survived pruning.
+    pub fn rollback_to_req(
+        &mut self,
+        req: &str,
+        reason: impl Into<String>,
+    ) -> Result<RollbackResult, String> {
+        let range = VersionRange::parse_requirement(req)?;
+        let candidates = self
+            .versions
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| range.contains(&entry.version))
+            .map(|(i, _)| i);
+        let target_idx = self
+            .select_index(candidates)
+            .ok_or_else(|| format!("no registered version satisfies requirement {req:?}"))?;
+        let target = self.versions[target_idx].version.clone();
+
+        self.rollback_to(&target, reason)
+    }
+
+    fn entry_for(&self, version: &ModelVersion) -> Option<&ModelEntry> {
+        self.versions.iter().find(|entry| &entry.version == version)
+    }
+
+    /// Compare two registered versions the way `cargo update` reports a
+    /// lockfile change: an `Upgrading`/`Downgrading`/`Unchanged` status and
+    /// numeric delta for each tracked quality metric, plus how many
+    /// registered versions sit between `to` and the newest one.
+    pub fn diff(&self, from: &ModelVersion, to: &ModelVersion) -> Result<VersionDiff, String> {
+        let from_entry = self
+            .entry_for(from)
+            .ok_or_else(|| format!("Version {} not found", from))?;
+        let to_entry = self
+            .entry_for(to)
+            .ok_or_else(|| format!("Version {} not found", to))?;
+
+        let metrics = vec![
+            MetricDelta::new("accuracy", from_entry.metrics.accuracy, to_entry.metrics.accuracy),
+            MetricDelta::new("precision", from_entry.metrics.precision, to_entry.metrics.precision),
+            MetricDelta::new("recall", from_entry.metrics.recall, to_entry.metrics.recall),
+            MetricDelta::new("f1_score", from_entry.metrics.f1_score, to_entry.metrics.f1_score),
+            MetricDelta::new(
+                "avg_confidence",
+                from_entry.metrics.avg_confidence,
+                to_entry.metrics.avg_confidence,
+            ),
+            MetricDelta::new(
+                "fallback_rate",
+                from_entry.metrics.fallback_rate,
+                to_entry.metrics.fallback_rate,
+            ),
+        ];
+
+        let versions_behind = self.versions.iter().filter(|entry| &entry.version > to).count();
+
+        Ok(VersionDiff {
+            from_version: from.clone(),
+            to_version: to.clone(),
+            metrics,
+            versions_behind,
+        })
+    }
+
     /// Get rollback history.
     pub fn rollback_history(&self) -> &[RollbackResult] {
         &self.rollback_history
     }
 
+    /// Non-fatal warnings accumulated by `register_version` calls so far
+    /// (unset metadata, duplicate core versions, etc).
+    pub fn warnings(&self) -> &[RegistrationWarning] {
+        &self.warnings
+    }
+
+    /// Append a rollback record onto the hash chain and return its commitment.
+    fn commit_rollback_to_chain(
+        &mut self,
+        from: &ModelVersion,
+        to: &ModelVersion,
+        reason: &str,
+        timestamp: u64,
+    ) -> Hash256 {
+        let content = rollback_content_bytes(from, to, reason, timestamp);
+        let hash = Hash256::chain(self.root_hash, &content);
+        self.chain_log.push(ChainEvent::RolledBack { content, hash });
+        self.root_hash = hash;
+        hash
+    }
+
+    /// The current tip of the tamper-evident hash chain.
+    pub fn root_hash(&self) -> Hash256 {
+        self.root_hash
+    }
+
+    /// Recompute the hash chain from genesis and confirm every stored link
+    /// still matches what its content would produce.
+    pub fn verify_history(&self) -> Result<(), String> {
+        let mut running = Hash256::GENESIS;
+        for (i, event) in self.chain_log.iter().enumerate() {
+            let expected = Hash256::chain(running, event.content());
+            if expected != event.hash() {
+                return Err(format!(
+                    "hash chain diverges at link {i}: expected {expected}, found {}",
+                    event.hash()
+                ));
+            }
+            running = event.hash();
+        }
+
+        if running != self.root_hash {
+            return Err(format!(
+                "recomputed root {running} does not match stored root {}",
+                self.root_hash
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Build a proof that `version` was once registered, sufficient for a
+    /// third party to confirm it against [`Self::root_hash`] via
+    /// [`verify_existence`] without needing the rest of the log.
+    pub fn prove_existed(&self, version: &ModelVersion) -> Option<ExistenceProof> {
+        let index = self.chain_log.iter().position(|event| {
+            matches!(event, ChainEvent::Registered { version: v, .. } if v == version)
+        })?;
+
+        let ChainEvent::Registered {
+            metrics,
+            artifact_path,
+            released_at,
+            ..
+        } = &self.chain_log[index]
+        else {
+            unreachable!("index was located via a Registered match above");
+        };
+
+        let prev_hash = match index {
+            0 => Hash256::GENESIS,
+            i => self.chain_log[i - 1].hash(),
+        };
+        let subsequent_contents = self.chain_log[index + 1..]
+            .iter()
+            .map(|event| event.content().to_vec())
+            .collect();
+
+        Some(ExistenceProof {
+            version: version.clone(),
+            metrics: metrics.clone(),
+            artifact_path: artifact_path.clone(),
+            released_at: *released_at,
+            prev_hash,
+            entry_hash: self.chain_log[index].hash(),
+            subsequent_contents,
+        })
+    }
+
     /// Check if current model needs rollback based on new metrics.
     ///
     /// Implements Jidoka (stop the line) principle.
@@ -518,6 +1679,14 @@ impl ModelVersionManager {
 
     /// Generate markdown report.
     pub fn to_markdown(&self) -> String {
+        self.to_markdown_with_pending(None)
+    }
+
+    /// Generate the markdown report, optionally rendering a "pending
+    /// rollback" preview section built from a [`RollbackPlan`] (see
+    /// [`Self::rollback_plan`]/[`Self::rollback_to_plan`]) that hasn't been
+    /// applied yet.
+    pub fn to_markdown_with_pending(&self, pending: Option<&RollbackPlan>) -> String {
         let mut report = String::from("## Model Version Report\n\n");
 
         // Active version
@@ -532,6 +1701,8 @@ impl ModelVersionManager {
             report.push_str("**Active Version**: None\n\n");
         }
 
+        report.push_str(&format!("**Root Hash**: `{}`\n\n", self.root_hash));
+
         // Version history
         report.push_str("### Version History\n\n");
         report.push_str("| Version | Accuracy | F1 | Status | Released |\n");
@@ -542,6 +1713,8 @@ impl ModelVersionManager {
                 "✅ Active"
             } else if entry.rolled_back {
                 "🔙 Rolled Back"
+            } else if entry.below_accuracy_threshold {
+                "📉 below-threshold, de-prioritized"
             } else {
                 "📦 Available"
             };
@@ -559,17 +1732,45 @@ impl ModelVersionManager {
             ));
         }
 
+        // Warnings
+        if !self.warnings.is_empty() {
+            report.push_str("\n### Warnings\n\n");
+            for warning in &self.warnings {
+                report.push_str(&format!("- {}: {}\n", warning.version, warning.message));
+            }
+        }
+
         // Rollback history
         if !self.rollback_history.is_empty() {
             report.push_str("\n### Rollback History\n\n");
             for rb in &self.rollback_history {
                 report.push_str(&format!(
-                    "- {} → {}: {}\n",
+                    "- {} → {}: {}",
                     rb.from_version, rb.to_version, rb.reason
                 ));
+                if let Ok(diff) = self.diff(&rb.from_version, &rb.to_version) {
+                    if let Some(accuracy) = diff.metrics.iter().find(|m| m.name == "accuracy") {
+                        report.push_str(&format!(
+                            " (accuracy {:.3} -> {:.3} ({:+.3}))",
+                            accuracy.from,
+                            accuracy.to,
+                            accuracy.delta()
+                        ));
+                    }
+                }
+                report.push('\n');
             }
         }
 
+        // Pending rollback preview
+        if let Some(plan) = pending {
+            report.push_str("\n### Pending Rollback (preview)\n\n");
+            report.push_str(&format!(
+                "- {} → {}: {}\n",
+                plan.from_version, plan.to_version, plan.reason
+            ));
+        }
+
         report
     }
 
@@ -642,7 +1843,61 @@ mod tests {
             Some(ModelVersion::new(1, 2, 3))
         );
         assert_eq!(ModelVersion::parse("invalid"), None);
-        assert_eq!(ModelVersion::parse("1.2"), None);
+    }
+
+    #[test]
+    fn model_version_parse_fills_omitted_minor_and_patch_with_zero() {
+        assert_eq!(ModelVersion::parse("1"), Some(ModelVersion::new(1, 0, 0)));
+        assert_eq!(ModelVersion::parse("1.3"), Some(ModelVersion::new(1, 3, 0)));
+    }
+
+    #[test]
+    fn model_version_parse_strips_leading_v_and_accepts_full_triple() {
+        assert_eq!(
+            ModelVersion::parse("v2.3.4"),
+            Some(ModelVersion::new(2, 3, 4))
+        );
+    }
+
+    #[test]
+    fn model_version_parse_reads_pre_release_channel() {
+        let v = ModelVersion::parse("1.20.0-rc1").unwrap();
+        assert_eq!(v, ModelVersion::with_channel(1, 20, 0, "rc1"));
+        assert_eq!(v.channel.as_deref(), Some("rc1"));
+    }
+
+    #[test]
+    fn model_version_from_str_delegates_to_parse() {
+        use std::str::FromStr;
+        assert_eq!(
+            ModelVersion::from_str("1.2.3").unwrap(),
+            ModelVersion::new(1, 2, 3)
+        );
+        assert!(ModelVersion::from_str("not-a-version").is_err());
+    }
+
+    #[test]
+    fn model_version_display_renders_channel_label() {
+        assert_eq!(
+            ModelVersion::with_channel(1, 20, 0, "rc1").to_string(),
+            "v1.20.0-rc1"
+        );
+    }
+
+    #[test]
+    fn model_version_pre_release_sorts_below_its_release() {
+        let release = ModelVersion::new(1, 20, 0);
+        let pre_release = ModelVersion::with_channel(1, 20, 0, "rc1");
+        assert!(pre_release < release);
+        assert_ne!(pre_release, release);
+    }
+
+    #[test]
+    fn model_version_numeric_eq_ignores_channel() {
+        let release = ModelVersion::new(1, 20, 0);
+        let pre_release = ModelVersion::with_channel(1, 20, 0, "rc1");
+        assert!(pre_release.numeric_eq(&release));
+        assert_ne!(pre_release, release);
     }
 
     #[test]
@@ -665,6 +1920,144 @@ mod tests {
         assert!(v1 < v3);
     }
 
+    // ========================================================================
+    // VersionRange tests
+    // ========================================================================
+
+    #[test]
+    fn version_range_any_contains_everything() {
+        let r = VersionRange::any();
+        assert!(r.contains(&ModelVersion::new(0, 0, 0)));
+        assert!(r.contains(&ModelVersion::new(99, 0, 0)));
+    }
+
+    #[test]
+    fn version_range_exact_matches_only_that_version() {
+        let r = VersionRange::exact(ModelVersion::new(1, 2, 0));
+        assert!(r.contains(&ModelVersion::new(1, 2, 0)));
+        assert!(!r.contains(&ModelVersion::new(1, 2, 1)));
+        assert!(!r.contains(&ModelVersion::new(1, 1, 0)));
+    }
+
+    #[test]
+    fn version_range_higher_than_excludes_the_bound() {
+        let r = VersionRange::higher_than(ModelVersion::new(1, 1, 0));
+        assert!(!r.contains(&ModelVersion::new(1, 1, 0)));
+        assert!(r.contains(&ModelVersion::new(1, 1, 1)));
+    }
+
+    #[test]
+    fn version_range_strictly_lower_than_excludes_the_bound() {
+        let r = VersionRange::strictly_lower_than(ModelVersion::new(2, 0, 0));
+        assert!(r.contains(&ModelVersion::new(1, 9, 9)));
+        assert!(!r.contains(&ModelVersion::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn version_range_between_is_inclusive_on_both_ends() {
+        let r = VersionRange::between(ModelVersion::new(1, 1, 0), ModelVersion::new(2, 0, 0));
+        assert!(r.contains(&ModelVersion::new(1, 1, 0)));
+        assert!(r.contains(&ModelVersion::new(1, 5, 0)));
+        assert!(r.contains(&ModelVersion::new(2, 0, 0)));
+        assert!(!r.contains(&ModelVersion::new(2, 0, 1)));
+        assert!(!r.contains(&ModelVersion::new(1, 0, 9)));
+    }
+
+    #[test]
+    fn version_range_union_coalesces_adjacent_segments() {
+        let a = VersionRange::between(ModelVersion::new(1, 0, 0), ModelVersion::new(1, 1, 0));
+        let b = VersionRange::higher_than(ModelVersion::new(1, 1, 0));
+        let u = a.union(&b);
+
+        // [1.0.0, 1.1.1) and [1.1.1, inf) touch at the boundary and merge
+        // into a single unbounded-above segment.
+        assert_eq!(u.segments.len(), 1);
+        assert!(u.contains(&ModelVersion::new(1, 0, 0)));
+        assert!(u.contains(&ModelVersion::new(5, 0, 0)));
+    }
+
+    #[test]
+    fn version_range_union_keeps_disjoint_segments_separate() {
+        let a = VersionRange::exact(ModelVersion::new(1, 0, 0));
+        let b = VersionRange::exact(ModelVersion::new(3, 0, 0));
+        let u = a.union(&b);
+
+        assert_eq!(u.segments.len(), 2);
+        assert!(u.contains(&ModelVersion::new(1, 0, 0)));
+        assert!(u.contains(&ModelVersion::new(3, 0, 0)));
+        assert!(!u.contains(&ModelVersion::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn version_range_intersection_keeps_only_the_overlap() {
+        let a = VersionRange::higher_than(ModelVersion::new(1, 0, 0));
+        let b = VersionRange::strictly_lower_than(ModelVersion::new(2, 0, 0));
+        let i = a.intersection(&b);
+
+        assert!(!i.contains(&ModelVersion::new(1, 0, 0)));
+        assert!(i.contains(&ModelVersion::new(1, 5, 0)));
+        assert!(!i.contains(&ModelVersion::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn version_range_intersection_of_disjoint_ranges_is_empty() {
+        let a = VersionRange::exact(ModelVersion::new(1, 0, 0));
+        let b = VersionRange::exact(ModelVersion::new(2, 0, 0));
+        let i = a.intersection(&b);
+
+        assert!(i.segments.is_empty());
+        assert!(!i.contains(&ModelVersion::new(1, 0, 0)));
+    }
+
+    #[test]
+    fn version_range_at_least_includes_the_bound() {
+        let r = VersionRange::at_least(ModelVersion::new(1, 1, 0));
+        assert!(r.contains(&ModelVersion::new(1, 1, 0)));
+        assert!(r.contains(&ModelVersion::new(9, 0, 0)));
+        assert!(!r.contains(&ModelVersion::new(1, 0, 9)));
+    }
+
+    #[test]
+    fn version_range_parse_requirement_caret_is_same_major_minor_or_above() {
+        let r = VersionRange::parse_requirement("^1.1").unwrap();
+        assert!(!r.contains(&ModelVersion::new(1, 0, 9)));
+        assert!(r.contains(&ModelVersion::new(1, 1, 0)));
+        assert!(r.contains(&ModelVersion::new(1, 9, 0)));
+        assert!(!r.contains(&ModelVersion::new(2, 0, 0)));
+    }
+
+    #[test]
+    fn version_range_parse_requirement_tilde_pins_major_and_minor() {
+        let r = VersionRange::parse_requirement("~1.2").unwrap();
+        assert!(r.contains(&ModelVersion::new(1, 2, 0)));
+        assert!(r.contains(&ModelVersion::new(1, 2, 99)));
+        assert!(!r.contains(&ModelVersion::new(1, 3, 0)));
+        assert!(!r.contains(&ModelVersion::new(1, 1, 9)));
+    }
+
+    #[test]
+    fn version_range_parse_requirement_wildcard_matches_any_minor_or_patch() {
+        for req in ["1.x", "1.X", "1.*"] {
+            let r = VersionRange::parse_requirement(req).unwrap();
+            assert!(r.contains(&ModelVersion::new(1, 0, 0)), "{req}");
+            assert!(r.contains(&ModelVersion::new(1, 99, 99)), "{req}");
+            assert!(!r.contains(&ModelVersion::new(2, 0, 0)), "{req}");
+        }
+    }
+
+    #[test]
+    fn version_range_parse_requirement_exact_matches_only_that_triple() {
+        let r = VersionRange::parse_requirement("1.1.0").unwrap();
+        assert!(r.contains(&ModelVersion::new(1, 1, 0)));
+        assert!(!r.contains(&ModelVersion::new(1, 1, 1)));
+    }
+
+    #[test]
+    fn version_range_parse_requirement_rejects_garbage() {
+        assert!(VersionRange::parse_requirement("not-a-version").is_err());
+        assert!(VersionRange::parse_requirement("^not-a-version").is_err());
+    }
+
     // ========================================================================
     // ModelQualityMetrics tests
     // ========================================================================
@@ -944,6 +2337,47 @@ mod tests {
         assert_eq!(mgr.active_version().unwrap().version.to_string(), "v1.4.0");
     }
 
+    #[test]
+    fn version_manager_versions_in_range_filters_by_constraint() {
+        let mut mgr = ModelVersionManager::new();
+        for i in 0..4 {
+            let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+            let e = ModelEntry::new(
+                ModelVersion::new(1, i, 0),
+                m,
+                format!("v1.{i}.0"),
+                format!("/v1.{i}.0"),
+            );
+            mgr.register_version(e).unwrap();
+        }
+
+        let range = VersionRange::between(ModelVersion::new(1, 1, 0), ModelVersion::new(1, 2, 0));
+        let matched: Vec<_> = mgr
+            .versions_in_range(&range)
+            .map(|e| e.version.to_string())
+            .collect();
+        assert_eq!(matched, vec!["v1.1.0", "v1.2.0"]);
+    }
+
+    #[test]
+    fn version_manager_best_in_range_picks_highest_passing_non_rolled_back() {
+        let mut mgr = ModelVersionManager::new();
+
+        let good = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let e1 = ModelEntry::new(ModelVersion::new(1, 0, 0), good.clone(), "v1", "/v1");
+        mgr.register_version(e1).unwrap();
+        let e2 = ModelEntry::new(ModelVersion::new(1, 1, 0), good.clone(), "v1.1", "/v1.1");
+        mgr.register_version(e2).unwrap();
+
+        // v1.2.0 is registered with failing quality, so it must be skipped.
+        let bad = ModelQualityMetrics::new(0.10, 0.10, 0.10, 0.10, 0.1, 0.9, 1000);
+        let e3 = ModelEntry::new(ModelVersion::new(1, 2, 0), bad, "v1.2", "/v1.2");
+        mgr.register_version(e3).unwrap();
+
+        let best = mgr.best_in_range(&VersionRange::any()).unwrap();
+        assert_eq!(best.version, ModelVersion::new(1, 1, 0));
+    }
+
     // ========================================================================
     // RollbackResult tests
     // ========================================================================
@@ -965,4 +2399,730 @@ mod tests {
         assert_eq!(mgr.rollback_history().len(), 1);
         assert_eq!(mgr.rollback_history()[0].reason, "Test rollback");
     }
+
+    // ========================================================================
+    // Hash chain tests
+    // ========================================================================
+
+    #[test]
+    fn registering_versions_extends_the_root_hash() {
+        let mut mgr = ModelVersionManager::new();
+        assert_eq!(mgr.root_hash(), Hash256::GENESIS);
+
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let e = ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1");
+        mgr.register_version(e).unwrap();
+
+        assert_ne!(mgr.root_hash(), Hash256::GENESIS);
+        assert_eq!(mgr.active_version().unwrap().entry_hash, mgr.root_hash());
+    }
+
+    #[test]
+    fn rollback_extends_the_same_chain_as_registration() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m.clone(), "v1", "/v1"))
+            .unwrap();
+        let after_register = mgr.root_hash();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let result = mgr.rollback("regression found").unwrap();
+
+        assert_eq!(result.record_hash, mgr.root_hash());
+        assert_ne!(mgr.root_hash(), after_register);
+    }
+
+    #[test]
+    fn verify_history_passes_on_an_untampered_chain() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m.clone(), "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m, "v1.1", "/v1.1"))
+            .unwrap();
+        mgr.rollback("rolling back").unwrap();
+
+        assert!(mgr.verify_history().is_ok());
+    }
+
+    #[test]
+    fn verify_history_detects_a_tampered_entry() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+
+        // Forge the stored root hash, simulating a tampered chain.
+        mgr.root_hash = Hash256::chain(Hash256::GENESIS, b"forged");
+
+        assert!(mgr.verify_history().is_err());
+    }
+
+    #[test]
+    fn prove_existed_round_trips_through_verify_existence() {
+        let mut mgr = ModelVersionManager::new();
+        let m1 = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m1, "v1", "/v1"))
+            .unwrap();
+        let m2 = ModelQualityMetrics::new(0.92, 0.87, 0.87, 0.87, 0.82, 0.18, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m2, "v1.1", "/v1.1"))
+            .unwrap();
+        mgr.rollback("rolling back").unwrap();
+
+        let proof = mgr.prove_existed(&ModelVersion::new(1, 0, 0)).unwrap();
+        assert!(verify_existence(&proof, mgr.root_hash()));
+    }
+
+    #[test]
+    fn verify_existence_rejects_a_forged_proof() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+
+        let mut proof = mgr.prove_existed(&ModelVersion::new(1, 0, 0)).unwrap();
+        proof.metrics.accuracy = 0.01; // claim different metrics than were committed
+
+        assert!(!verify_existence(&proof, mgr.root_hash()));
+    }
+
+    #[test]
+    fn prove_existed_returns_none_for_an_unknown_version() {
+        let mgr = ModelVersionManager::new();
+        assert!(mgr.prove_existed(&ModelVersion::new(9, 9, 9)).is_none());
+    }
+
+    // ========================================================================
+    // Rollback plan (dry-run) tests
+    // ========================================================================
+
+    #[test]
+    fn rollback_plan_matches_what_rollback_would_do_without_mutating_state() {
+        let mut mgr = ModelVersionManager::new();
+        let m1 = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m1, "v1", "/v1"))
+            .unwrap();
+        let m2 = ModelQualityMetrics::new(0.92, 0.87, 0.87, 0.87, 0.82, 0.18, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m2, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let root_before = mgr.root_hash();
+        let plan = mgr.rollback_plan("dry run").unwrap();
+
+        assert_eq!(plan.from_version, ModelVersion::new(1, 1, 0));
+        assert_eq!(plan.to_version, ModelVersion::new(1, 0, 0));
+        assert_eq!(plan.reason, "dry run");
+
+        // Nothing was mutated.
+        assert_eq!(mgr.root_hash(), root_before);
+        assert!(mgr.rollback_history().is_empty());
+        assert!(mgr.active_version().unwrap().version == ModelVersion::new(1, 1, 0));
+
+        let result = mgr.rollback("dry run").unwrap();
+        assert_eq!(result.from_version, plan.from_version);
+        assert_eq!(result.to_version, plan.to_version);
+    }
+
+    #[test]
+    fn rollback_plan_reports_not_enough_versions() {
+        let mgr = ModelVersionManager::new();
+        assert_eq!(
+            mgr.rollback_plan("too soon"),
+            Err("Not enough versions to rollback".to_string())
+        );
+    }
+
+    #[test]
+    fn rollback_to_plan_matches_what_rollback_to_would_do_without_mutating_state() {
+        let mut mgr = ModelVersionManager::new();
+        let m1 = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m1, "v1", "/v1"))
+            .unwrap();
+        let m2 = ModelQualityMetrics::new(0.92, 0.87, 0.87, 0.87, 0.82, 0.18, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m2, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let root_before = mgr.root_hash();
+        let target = ModelVersion::new(1, 0, 0);
+        let plan = mgr.rollback_to_plan(&target, "targeted dry run").unwrap();
+
+        assert_eq!(plan.from_version, ModelVersion::new(1, 1, 0));
+        assert_eq!(plan.to_version, target);
+        assert_eq!(mgr.root_hash(), root_before);
+        assert!(!mgr.versions().next().unwrap().rolled_back);
+    }
+
+    #[test]
+    fn rollback_to_plan_rejects_an_already_active_target() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let version = ModelVersion::new(1, 0, 0);
+        mgr.register_version(ModelEntry::new(version.clone(), m, "v1", "/v1"))
+            .unwrap();
+
+        assert_eq!(
+            mgr.rollback_to_plan(&version, "no-op"),
+            Err("Target is already the active version".to_string())
+        );
+    }
+
+    #[test]
+    fn rollback_to_plan_rejects_an_unknown_target() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+
+        let missing = ModelVersion::new(9, 9, 9);
+        assert_eq!(
+            mgr.rollback_to_plan(&missing, "no-op"),
+            Err(format!("Version {} not found", missing))
+        );
+    }
+
+    #[test]
+    fn to_markdown_with_pending_renders_the_preview_section() {
+        let mut mgr = ModelVersionManager::new();
+        let m1 = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m1, "v1", "/v1"))
+            .unwrap();
+        let m2 = ModelQualityMetrics::new(0.92, 0.87, 0.87, 0.87, 0.82, 0.18, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m2, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let plan = mgr.rollback_plan("quality regression").unwrap();
+        let report = mgr.to_markdown_with_pending(Some(&plan));
+
+        assert!(report.contains("### Pending Rollback (preview)"));
+        assert!(report.contains("quality regression"));
+        assert!(!mgr.to_markdown().contains("Pending Rollback"));
+    }
+
+    // ========================================================================
+    // resolve_active tests
+    // ========================================================================
+
+    #[test]
+    fn resolve_active_prefers_the_highest_passing_version() {
+        let mut mgr = ModelVersionManager::new();
+        let passing = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), passing.clone(), "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), passing, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let winner = mgr.resolve_active(&ActivationPolicy::default()).unwrap();
+        assert_eq!(winner.version, ModelVersion::new(1, 1, 0));
+        assert_eq!(mgr.active_version().unwrap().version, ModelVersion::new(1, 1, 0));
+    }
+
+    #[test]
+    fn resolve_active_prefers_passing_over_failing_quality() {
+        let mut mgr = ModelVersionManager::new();
+        let failing = ModelQualityMetrics::new(0.50, 0.50, 0.50, 0.50, 0.5, 0.5, 1000);
+        let passing = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        // Register the higher (failing) version last so it would otherwise win on version alone.
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), passing, "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(2, 0, 0), failing, "v2", "/v2"))
+            .unwrap();
+
+        let winner = mgr.resolve_active(&ActivationPolicy::default()).unwrap();
+        assert_eq!(winner.version, ModelVersion::new(1, 0, 0));
+    }
+
+    #[test]
+    fn resolve_active_pushes_rolled_back_entries_to_the_bottom() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+        // Better metrics, so registering v2.0.0 activates it.
+        let better = ModelQualityMetrics::new(0.92, 0.87, 0.87, 0.87, 0.82, 0.18, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(2, 0, 0), better, "v2", "/v2"))
+            .unwrap();
+        mgr.rollback("bad release").unwrap(); // marks v2.0.0 as rolled back, activates v1.0.0
+
+        // v2.0.0 is still the higher, quality-passing version, but being
+        // rolled back should keep it from winning resolve_active.
+        let winner = mgr.resolve_active(&ActivationPolicy::default()).unwrap();
+        assert_eq!(winner.version, ModelVersion::new(1, 0, 0));
+    }
+
+    #[test]
+    fn resolve_active_deprioritizes_zero_sample_entries_below_real_metrics() {
+        // A candidate with no real samples that still happens to meet thresholds
+        // numerically (thresholds are all 0.0 here, so zero metrics trivially pass).
+        let lenient = QualityThresholds {
+            min_accuracy: 0.0,
+            min_precision: 0.0,
+            min_recall: 0.0,
+            min_f1: 0.0,
+        };
+        let untested = ModelQualityMetrics::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0);
+        let measured = ModelQualityMetrics::new(0.60, 0.55, 0.55, 0.55, 0.5, 0.4, 500);
+
+        let mut mgr = ModelVersionManager::with_thresholds(lenient.clone());
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), measured, "measured", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(2, 0, 0), untested, "untested", "/v2"))
+            .unwrap();
+
+        let policy = ActivationPolicy { thresholds: lenient };
+        let winner = mgr.resolve_active(&policy).unwrap();
+        // Despite being the higher version, the untested (sample_count == 0)
+        // entry loses to the lower version with real measured samples.
+        assert_eq!(winner.version, ModelVersion::new(1, 0, 0));
+    }
+
+    // ========================================================================
+    // rollback_to_req tests
+    // ========================================================================
+
+    #[test]
+    fn rollback_to_req_picks_the_highest_matching_version() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m.clone(), "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m.clone(), "v1.1", "/v1.1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 2, 0), m.clone(), "v1.2", "/v1.2"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(2, 0, 0), m, "v2", "/v2"))
+            .unwrap();
+
+        let result = mgr.rollback_to_req("^1.1", "roll back off v2").unwrap();
+
+        assert_eq!(result.to_version, ModelVersion::new(1, 2, 0));
+        assert_eq!(mgr.active_version().unwrap().version, ModelVersion::new(1, 2, 0));
+    }
+
+    #[test]
+    fn rollback_to_req_supports_tilde_and_wildcard() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 2, 0), m.clone(), "v1.2.0", "/a"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 2, 5), m.clone(), "v1.2.5", "/b"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 3, 0), m, "v1.3.0", "/c"))
+            .unwrap();
+
+        let result = mgr.rollback_to_req("~1.2", "pin to 1.2.x").unwrap();
+        assert_eq!(result.to_version, ModelVersion::new(1, 2, 5));
+    }
+
+    #[test]
+    fn rollback_to_req_errors_when_nothing_matches() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(2, 0, 0), m, "v2", "/v2"))
+            .unwrap();
+
+        let err = mgr.rollback_to_req("1.x", "no 1.x exists").unwrap_err();
+        assert!(err.contains("1.x"), "{err}");
+    }
+
+    #[test]
+    fn rollback_to_req_propagates_an_invalid_requirement() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+
+        assert!(mgr.rollback_to_req("garbage", "oops").is_err());
+    }
+
+    // ========================================================================
+    // VersionDiff tests
+    // ========================================================================
+
+    #[test]
+    fn diff_reports_status_and_delta_per_metric() {
+        let mut mgr = ModelVersionManager::new();
+        let better = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let worse = ModelQualityMetrics::new(0.86, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), better.clone(), "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), worse, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let diff = mgr
+            .diff(&ModelVersion::new(1, 1, 0), &ModelVersion::new(1, 0, 0))
+            .unwrap();
+
+        let accuracy = diff.metrics.iter().find(|m| m.name == "accuracy").unwrap();
+        assert_eq!(accuracy.status, DiffStatus::Upgrading);
+        assert!((accuracy.delta() - 0.04).abs() < 1e-9);
+
+        let precision = diff.metrics.iter().find(|m| m.name == "precision").unwrap();
+        assert_eq!(precision.status, DiffStatus::Unchanged);
+    }
+
+    #[test]
+    fn diff_counts_versions_behind_the_newest() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m.clone(), "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m.clone(), "v1.1", "/v1.1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 2, 0), m, "v1.2", "/v1.2"))
+            .unwrap();
+
+        let diff = mgr
+            .diff(&ModelVersion::new(1, 2, 0), &ModelVersion::new(1, 0, 0))
+            .unwrap();
+        assert_eq!(diff.versions_behind, 2);
+
+        let diff_latest = mgr
+            .diff(&ModelVersion::new(1, 0, 0), &ModelVersion::new(1, 2, 0))
+            .unwrap();
+        assert_eq!(diff_latest.versions_behind, 0);
+    }
+
+    #[test]
+    fn diff_errors_on_an_unregistered_version() {
+        let mgr = ModelVersionManager::new();
+        assert!(mgr.diff(&ModelVersion::new(9, 9, 9), &ModelVersion::new(1, 0, 0)).is_err());
+    }
+
+    #[test]
+    fn version_diff_to_markdown_renders_status_and_versions_behind() {
+        let mut mgr = ModelVersionManager::new();
+        let better = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let worse = ModelQualityMetrics::new(0.86, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), worse, "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), better, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let diff = mgr
+            .diff(&ModelVersion::new(1, 1, 0), &ModelVersion::new(1, 0, 0))
+            .unwrap();
+        let report = diff.to_markdown();
+
+        assert!(report.contains("Downgrading accuracy"));
+        assert!(report.contains("1 version(s) behind latest"));
+    }
+
+    #[test]
+    fn rollback_history_markdown_shows_accuracy_delta() {
+        let mut mgr = ModelVersionManager::new();
+        let m1 = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m1, "v1", "/v1"))
+            .unwrap();
+        let m2 = ModelQualityMetrics::new(0.95, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m2, "v1.1", "/v1.1"))
+            .unwrap();
+
+        mgr.rollback("regression").unwrap();
+
+        let report = mgr.to_markdown();
+        assert!(report.contains("### Rollback History"));
+        assert!(report.contains("accuracy"));
+    }
+
+    // ========================================================================
+    // Rollback dry-run tests
+    // ========================================================================
+
+    #[test]
+    fn rollback_dry_run_does_not_mutate_state() {
+        let mut mgr = ModelVersionManager::new();
+        let m1 = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m1, "v1", "/v1"))
+            .unwrap();
+        // Better than v1.0.0, so registering v1.1.0 activates it.
+        let m2 = ModelQualityMetrics::new(0.92, 0.87, 0.87, 0.87, 0.82, 0.18, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m2, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let root_before = mgr.root_hash();
+        let preview = mgr.rollback_dry_run("quality regression").unwrap();
+
+        assert!(preview.success);
+        assert_eq!(preview.from_version, ModelVersion::new(1, 1, 0));
+        assert_eq!(preview.to_version, ModelVersion::new(1, 0, 0));
+
+        assert_eq!(mgr.root_hash(), root_before);
+        assert!(mgr.rollback_history().is_empty());
+        assert_eq!(mgr.active_version().unwrap().version, ModelVersion::new(1, 1, 0));
+    }
+
+    #[test]
+    fn rollback_dry_run_markdown_reports_not_applied() {
+        let mut mgr = ModelVersionManager::new();
+        let m1 = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m1, "v1", "/v1"))
+            .unwrap();
+        let m2 = ModelQualityMetrics::new(0.86, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m2, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let preview = mgr.rollback_dry_run("quality regression").unwrap();
+        let report = preview.to_markdown();
+
+        assert!(report.contains("dry run - not applied"));
+        assert!(report.contains("Downgrading accuracy"));
+    }
+
+    #[test]
+    fn rollback_to_dry_run_does_not_mutate_state() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+        // Better metrics, so registering v1.1.0 activates it.
+        let better = ModelQualityMetrics::new(0.92, 0.87, 0.87, 0.87, 0.82, 0.18, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), better, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let root_before = mgr.root_hash();
+        let target = ModelVersion::new(1, 0, 0);
+        let preview = mgr.rollback_to_dry_run(&target, "targeted preview").unwrap();
+
+        assert_eq!(preview.to_version, target);
+        assert_eq!(mgr.root_hash(), root_before);
+        assert!(mgr.rollback_history().is_empty());
+    }
+
+    #[test]
+    fn rollback_dry_run_propagates_resolution_errors() {
+        let mgr = ModelVersionManager::new();
+        assert!(mgr.rollback_dry_run("too soon").is_err());
+    }
+
+    // ========================================================================
+    // SelectionPolicy tests
+    // ========================================================================
+
+    #[test]
+    fn default_selection_policy_is_maximum_version() {
+        assert_eq!(SelectionPolicy::default(), SelectionPolicy::MaximumVersion);
+    }
+
+    #[test]
+    fn minimum_version_policy_rolls_back_to_the_oldest_eligible_version() {
+        let mut mgr =
+            ModelVersionManager::new().with_selection_policy(SelectionPolicy::MinimumVersion);
+        let m1 = ModelQualityMetrics::new(0.70, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let m2 = ModelQualityMetrics::new(0.80, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let m3 = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m1, "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), m2, "v1.1", "/v1.1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 2, 0), m3, "v1.2", "/v1.2"))
+            .unwrap();
+
+        let result = mgr.rollback("pin to oldest").unwrap();
+
+        assert_eq!(result.from_version, ModelVersion::new(1, 2, 0));
+        assert_eq!(result.to_version, ModelVersion::new(1, 0, 0));
+    }
+
+    #[test]
+    fn highest_accuracy_policy_ignores_version_order() {
+        let mut mgr =
+            ModelVersionManager::new().with_selection_policy(SelectionPolicy::HighestAccuracy);
+        let more_accurate = ModelQualityMetrics::new(0.85, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let less_accurate = ModelQualityMetrics::new(0.70, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let best = ModelQualityMetrics::new(0.99, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), more_accurate, "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), less_accurate, "v1.1", "/v1.1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 2, 0), best, "v1.2", "/v1.2"))
+            .unwrap();
+
+        // v1.2 is active and v1.1 is the immediately preceding version, but
+        // v1.0 has the better accuracy of the two eligible predecessors, so
+        // it wins under this policy instead of the higher-versioned v1.1.
+        let result = mgr.rollback("chase accuracy").unwrap();
+
+        assert_eq!(result.from_version, ModelVersion::new(1, 2, 0));
+        assert_eq!(result.to_version, ModelVersion::new(1, 0, 0));
+    }
+
+    #[test]
+    fn highest_accuracy_policy_also_governs_requirement_based_rollback() {
+        let mut mgr =
+            ModelVersionManager::new().with_selection_policy(SelectionPolicy::HighestAccuracy);
+        let more_accurate = ModelQualityMetrics::new(0.95, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let less_accurate = ModelQualityMetrics::new(0.60, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let current = ModelQualityMetrics::new(0.99, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), more_accurate, "v1.0", "/v1.0"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), less_accurate, "v1.1", "/v1.1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(2, 0, 0), current, "v2", "/v2"))
+            .unwrap();
+
+        // v1.0 is the lower version, but under HighestAccuracy it beats the
+        // higher-versioned (and less accurate) v1.1 for the "1.x" match.
+        let result = mgr.rollback_to_req("1.x", "pick the more accurate 1.x").unwrap();
+
+        assert_eq!(result.to_version, ModelVersion::new(1, 0, 0));
+    }
+
+    // ========================================================================
+    // AccuracyGate tests
+    // ========================================================================
+
+    #[test]
+    fn require_gate_rejects_registration_below_the_floor() {
+        let mut mgr =
+            ModelVersionManager::new().with_min_accuracy(0.80, AccuracyGate::Require);
+        let m = ModelQualityMetrics::new(0.75, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+
+        let err = mgr
+            .register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap_err();
+        assert!(err.contains("0.750"), "{err}");
+        assert!(mgr.active_version().is_none());
+    }
+
+    #[test]
+    fn prefer_gate_accepts_but_flags_below_floor_entries() {
+        let mut mgr =
+            ModelVersionManager::new().with_min_accuracy(0.90, AccuracyGate::Prefer);
+        // Passes the manager's default quality thresholds (0.85) but falls
+        // short of the stricter 0.90 accuracy floor just configured.
+        let m = ModelQualityMetrics::new(0.87, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+
+        let activated = mgr
+            .register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+
+        assert!(activated);
+        assert!(mgr.active_version().unwrap().below_accuracy_threshold);
+    }
+
+    #[test]
+    fn prefer_gate_de_prioritizes_below_floor_entries_for_rollback() {
+        let mut mgr =
+            ModelVersionManager::new().with_min_accuracy(0.90, AccuracyGate::Prefer);
+        let good = ModelQualityMetrics::new(0.92, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let below_floor = ModelQualityMetrics::new(0.87, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let current = ModelQualityMetrics::new(0.96, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), good, "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), below_floor, "v1.1", "/v1.1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 2, 0), current, "v1.2", "/v1.2"))
+            .unwrap();
+
+        // v1.1 is the immediately preceding version, but it's below the
+        // accuracy floor, so rollback skips it in favor of the
+        // above-threshold v1.0.
+        let result = mgr.rollback("avoid the de-prioritized build").unwrap();
+
+        assert_eq!(result.from_version, ModelVersion::new(1, 2, 0));
+        assert_eq!(result.to_version, ModelVersion::new(1, 0, 0));
+    }
+
+    #[test]
+    fn prefer_gate_falls_back_to_below_floor_entry_as_last_resort() {
+        let mut mgr =
+            ModelVersionManager::new().with_min_accuracy(0.90, AccuracyGate::Prefer);
+        let below_floor = ModelQualityMetrics::new(0.87, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let current = ModelQualityMetrics::new(0.96, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), below_floor, "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), current, "v1.1", "/v1.1"))
+            .unwrap();
+
+        // v1.0 is the only eligible predecessor, and it's below the floor -
+        // it's still picked rather than leaving rollback with nowhere to go.
+        let result = mgr.rollback("last resort").unwrap();
+
+        assert_eq!(result.to_version, ModelVersion::new(1, 0, 0));
+    }
+
+    #[test]
+    fn to_markdown_flags_below_floor_entries() {
+        let mut mgr =
+            ModelVersionManager::new().with_min_accuracy(0.90, AccuracyGate::Prefer);
+        let below_floor = ModelQualityMetrics::new(0.87, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        let current = ModelQualityMetrics::new(0.96, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), below_floor, "v1", "/v1"))
+            .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 1, 0), current, "v1.1", "/v1.1"))
+            .unwrap();
+
+        let report = mgr.to_markdown();
+        assert!(report.contains("below-threshold, de-prioritized"));
+    }
+
+    // ========================================================================
+    // RegistrationWarning tests
+    // ========================================================================
+
+    #[test]
+    fn register_version_warns_on_unset_accuracy() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.0, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+
+        assert_eq!(mgr.warnings().len(), 1);
+        assert!(mgr.warnings()[0].message.contains("accuracy was not set"));
+    }
+
+    #[test]
+    fn register_version_warns_on_missing_artifact_path() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", ""))
+            .unwrap();
+
+        assert_eq!(mgr.warnings().len(), 1);
+        assert!(mgr.warnings()[0].message.contains("no artifact path"));
+    }
+
+    #[test]
+    fn register_version_warns_on_duplicate_core_version() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(
+            ModelVersion::with_channel(1, 0, 0, "rc1"),
+            m.clone(),
+            "v1-rc1",
+            "/v1-rc1",
+        ))
+        .unwrap();
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+
+        assert!(mgr
+            .warnings()
+            .iter()
+            .any(|w| w.message.contains("duplicates the (major, minor, patch)")));
+    }
+
+    #[test]
+    fn register_version_with_complete_metadata_has_no_warnings() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.90, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", "/v1"))
+            .unwrap();
+
+        assert!(mgr.warnings().is_empty());
+    }
+
+    #[test]
+    fn to_markdown_renders_warnings_section() {
+        let mut mgr = ModelVersionManager::new();
+        let m = ModelQualityMetrics::new(0.0, 0.85, 0.85, 0.85, 0.8, 0.2, 1000);
+        mgr.register_version(ModelEntry::new(ModelVersion::new(1, 0, 0), m, "v1", ""))
+            .unwrap();
+
+        let report = mgr.to_markdown();
+        assert!(report.contains("### Warnings"));
+        assert!(report.contains("accuracy was not set"));
+        assert!(report.contains("no artifact path"));
+    }
 }
@@ -415,13 +415,18 @@ fn with_max_history_enforces_minimum() {
 #[test]
 fn model_version_parse_invalid_inputs() {
     assert!(ModelVersion::parse("").is_none());
-    assert!(ModelVersion::parse("1").is_none());
-    assert!(ModelVersion::parse("1.2").is_none());
     assert!(ModelVersion::parse("a.b.c").is_none());
     assert!(ModelVersion::parse("1.2.3.4").is_none());
     assert!(ModelVersion::parse("v").is_none());
 }
 
+#[test]
+fn model_version_parse_fills_omitted_parts_with_zero() {
+    // Omitted minor/patch default to 0 rather than failing to parse.
+    assert_eq!(ModelVersion::parse("1"), Some(ModelVersion::new(1, 0, 0)));
+    assert_eq!(ModelVersion::parse("1.2"), Some(ModelVersion::new(1, 2, 0)));
+}
+
 #[test]
 fn model_version_default() {
     let v = ModelVersion::default();
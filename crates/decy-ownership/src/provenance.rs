@@ -0,0 +1,147 @@
+//! Stable C-source provenance keys for incremental re-transpilation (DECY-270).
+//!
+//! Array/pointer parameter classifications (and other per-function analysis
+//! results) are expensive enough that re-running decy after an edit
+//! elsewhere in a translation unit shouldn't force recomputing all of them.
+//! A raw byte offset or line number is the wrong cache key for this: editing
+//! an unrelated declaration above a function shifts every later line number
+//! without changing that function at all.
+//!
+//! [`ProvenanceKey`] instead pairs a human-readable [`SourceLocation`]
+//! (`file:line:column`, useful for location comments and source maps but
+//! *not* used for cache lookups) with a [`ProvenanceKey::fingerprint`]: a
+//! hash of the function's own name, signature, and body structure. Since
+//! `HirFunction` carries no absolute position information, this fingerprint
+//! is inherently stable under reordering or insertion of unrelated
+//! declarations elsewhere in the file — only an edit to the symbol itself
+//! changes it. [`ProvenanceCache`] is a cache keyed on that fingerprint.
+
+use decy_hir::HirFunction;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// A C source location: `file:line:column`, 1-based like clang's own
+/// diagnostics (see `decy_parser::diagnostic::Diagnostic`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl SourceLocation {
+    /// Create a new source location.
+    pub fn new(file: impl Into<String>, line: u32, column: u32) -> Self {
+        Self {
+            file: file.into(),
+            line,
+            column,
+        }
+    }
+
+    /// Render as `file:line:column`.
+    pub fn display(&self) -> String {
+        format!("{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// A stable identity for a C function, independent of where it sits in the
+/// translation unit.
+///
+/// `location` is for human-facing output only (location comments, source
+/// maps); cache lookups must use [`ProvenanceKey::fingerprint`] instead,
+/// since `location` shifts when unrelated code elsewhere is edited.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenanceKey {
+    location: SourceLocation,
+    fingerprint: u64,
+}
+
+impl ProvenanceKey {
+    /// Compute a provenance key for `func`, first seen at `location`.
+    pub fn for_function(location: SourceLocation, func: &HirFunction) -> Self {
+        Self {
+            location,
+            fingerprint: structural_fingerprint(func),
+        }
+    }
+
+    /// The function's declared location, for diagnostics only.
+    pub fn location(&self) -> &SourceLocation {
+        &self.location
+    }
+
+    /// Hash of the function's own name, signature, and body structure.
+    /// Stable under reordering/insertion of unrelated declarations
+    /// elsewhere in the translation unit; changes only when this function
+    /// itself is edited.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// A `// decy:provenance <location> fp=<fingerprint>` comment suitable
+    /// for emitting above the generated Rust item, or for a side-car source
+    /// map keyed the same way.
+    pub fn location_comment(&self) -> String {
+        format!(
+            "// decy:provenance {} fp={:016x}",
+            self.location.display(),
+            self.fingerprint
+        )
+    }
+}
+
+/// Hash `func`'s name, return type, parameters, and body. `HirFunction`
+/// carries no byte offset or line information, so this is automatically
+/// invariant to edits elsewhere in the file.
+fn structural_fingerprint(func: &HirFunction) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    func.name().hash(&mut hasher);
+    format!("{:?}", func.return_type()).hash(&mut hasher);
+    format!("{:?}", func.parameters()).hash(&mut hasher);
+    format!("{:?}", func.body()).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// A cache of analysis results keyed on [`ProvenanceKey::fingerprint`]
+/// rather than location, so unaffected functions reuse their prior
+/// classification across re-transpilation runs.
+#[derive(Debug, Clone, Default)]
+pub struct ProvenanceCache<T> {
+    entries: HashMap<u64, T>,
+}
+
+impl<T> ProvenanceCache<T> {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Look up a cached result for `key`, if this function's fingerprint
+    /// hasn't changed since it was cached.
+    pub fn get(&self, key: &ProvenanceKey) -> Option<&T> {
+        self.entries.get(&key.fingerprint)
+    }
+
+    /// Cache `value` for `key`.
+    pub fn insert(&mut self, key: &ProvenanceKey, value: T) {
+        self.entries.insert(key.fingerprint, value);
+    }
+
+    /// Number of cached entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True when the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+#[path = "provenance_tests.rs"]
+mod provenance_tests;
@@ -0,0 +1,151 @@
+//! Tests for stable C-source provenance keys (DECY-270).
+
+#[cfg(test)]
+mod tests {
+    use super::super::{ProvenanceCache, ProvenanceKey, SourceLocation};
+    use decy_hir::{HirFunction, HirParameter, HirType};
+
+    fn sample_function() -> HirFunction {
+        HirFunction::new_with_body(
+            "process".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("buf".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+                HirParameter::new("len".to_string(), HirType::Int),
+            ],
+            vec![],
+        )
+    }
+
+    #[test]
+    fn source_location_displays_as_file_line_column() {
+        let loc = SourceLocation::new("foo.c", 10, 3);
+        assert_eq!(loc.display(), "foo.c:10:3");
+    }
+
+    #[test]
+    fn fingerprint_is_stable_across_different_locations() {
+        let func = sample_function();
+        let key_a = ProvenanceKey::for_function(SourceLocation::new("foo.c", 10, 1), &func);
+        // Simulates an unrelated declaration being inserted above `process`,
+        // shifting its line number without changing the function itself.
+        let key_b = ProvenanceKey::for_function(SourceLocation::new("foo.c", 57, 1), &func);
+
+        assert_eq!(key_a.fingerprint(), key_b.fingerprint());
+        assert_ne!(key_a.location(), key_b.location());
+    }
+
+    #[test]
+    fn fingerprint_changes_when_body_changes() {
+        let func_a = sample_function();
+        let func_b = HirFunction::new_with_body(
+            "process".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("buf".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+                HirParameter::new("len".to_string(), HirType::Int),
+            ],
+            vec![decy_hir::HirStatement::Return(None)],
+        );
+        let loc = SourceLocation::new("foo.c", 10, 1);
+
+        let key_a = ProvenanceKey::for_function(loc.clone(), &func_a);
+        let key_b = ProvenanceKey::for_function(loc, &func_b);
+
+        assert_ne!(key_a.fingerprint(), key_b.fingerprint());
+    }
+
+    #[test]
+    fn fingerprint_changes_when_signature_changes() {
+        let func_a = sample_function();
+        let func_b = HirFunction::new_with_body(
+            "process".to_string(),
+            HirType::Int,
+            vec![HirParameter::new(
+                "buf".to_string(),
+                HirType::Pointer(Box::new(HirType::Int)),
+            )],
+            vec![],
+        );
+        let loc = SourceLocation::new("foo.c", 10, 1);
+
+        let key_a = ProvenanceKey::for_function(loc.clone(), &func_a);
+        let key_b = ProvenanceKey::for_function(loc, &func_b);
+
+        assert_ne!(key_a.fingerprint(), key_b.fingerprint());
+    }
+
+    #[test]
+    fn fingerprint_changes_when_name_changes() {
+        let func_a = sample_function();
+        let func_b = HirFunction::new_with_body(
+            "process2".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("buf".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+                HirParameter::new("len".to_string(), HirType::Int),
+            ],
+            vec![],
+        );
+        let loc = SourceLocation::new("foo.c", 10, 1);
+
+        let key_a = ProvenanceKey::for_function(loc.clone(), &func_a);
+        let key_b = ProvenanceKey::for_function(loc, &func_b);
+
+        assert_ne!(key_a.fingerprint(), key_b.fingerprint());
+    }
+
+    #[test]
+    fn location_comment_includes_location_and_fingerprint() {
+        let func = sample_function();
+        let key = ProvenanceKey::for_function(SourceLocation::new("foo.c", 10, 1), &func);
+        let comment = key.location_comment();
+
+        assert!(comment.starts_with("// decy:provenance foo.c:10:1 fp="));
+        assert_eq!(
+            comment.len(),
+            "// decy:provenance foo.c:10:1 fp=".len() + 16
+        );
+    }
+
+    #[test]
+    fn cache_hits_for_unchanged_function_despite_relocation() {
+        let func = sample_function();
+        let mut cache: ProvenanceCache<&'static str> = ProvenanceCache::new();
+
+        let key_a = ProvenanceKey::for_function(SourceLocation::new("foo.c", 10, 1), &func);
+        cache.insert(&key_a, "cached-classification");
+
+        let key_b = ProvenanceKey::for_function(SourceLocation::new("foo.c", 200, 1), &func);
+        assert_eq!(cache.get(&key_b), Some(&"cached-classification"));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn cache_misses_for_changed_function() {
+        let func_a = sample_function();
+        let func_b = HirFunction::new_with_body(
+            "process".to_string(),
+            HirType::Int,
+            vec![
+                HirParameter::new("buf".to_string(), HirType::Pointer(Box::new(HirType::Int))),
+                HirParameter::new("len".to_string(), HirType::Int),
+            ],
+            vec![decy_hir::HirStatement::Return(None)],
+        );
+        let mut cache: ProvenanceCache<&'static str> = ProvenanceCache::new();
+
+        let key_a = ProvenanceKey::for_function(SourceLocation::new("foo.c", 10, 1), &func_a);
+        cache.insert(&key_a, "stale");
+
+        let key_b = ProvenanceKey::for_function(SourceLocation::new("foo.c", 10, 1), &func_b);
+        assert!(cache.get(&key_b).is_none());
+    }
+
+    #[test]
+    fn empty_cache_is_empty() {
+        let cache: ProvenanceCache<()> = ProvenanceCache::new();
+        assert!(cache.is_empty());
+        assert_eq!(cache.len(), 0);
+    }
+}
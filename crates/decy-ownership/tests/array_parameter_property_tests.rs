@@ -674,6 +674,19 @@ proptest! {
     fn prop_pointer_as_last_param_weak_signal(
         arr_name in "[a-z]{3,8}",
     ) {
+        // DECY-080 added preceding-int-parameter detection (`f(int count, T*
+        // buf)`), so an unrelated-but-array-named `arr_name` colliding with
+        // "first" (an Int, but not a length-like name) could now supply a
+        // second signal alongside `common_array_name`. Exclude that overlap
+        // so this test keeps covering what it's meant to: a bare unrelated
+        // preceding int, with no other signal, shouldn't trigger detection.
+        prop_assume!(
+            !arr_name.contains("arr")
+                && !arr_name.contains("buf")
+                && arr_name != "data"
+                && arr_name != "items"
+        );
+
         // Pointer as last parameter (no following length)
         let params = vec![
             HirParameter::new("first".to_string(), HirType::Int),
@@ -0,0 +1,249 @@
+//! Recovery suggestions for compound literals missing their `(Type)` prefix.
+//!
+//! `(struct Point){ .x = 10, .y = 20 }` parses fine, but a typo'd or omitted
+//! cast-type - `{ .x = 10, .y = 20 }` alone, or `(Poiint){...}` - leaves
+//! clang with nothing but "expected expression" at the brace. This module
+//! recognizes that shape from the raw source text and suggests the
+//! best-matching declared struct by comparing its field names against the
+//! designated initializers actually used.
+//!
+//! Builds on [`crate::span`] for the insertion-point location and on the
+//! accumulated diagnostics from [`crate::parser::CParser::parse_recovering`],
+//! which is what calls [`suggest_compound_literal_fix`].
+//!
+//! Part of DECY-281: struct-literal-without-path diagnostic and auto-fix
+//! suggestion for malformed compound literals.
+
+use crate::parser::Struct;
+use crate::span::{BytePos, Span};
+
+/// A suggested fix for a compound literal whose cast-type prefix is missing
+/// or names an unknown type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompoundLiteralFix {
+    /// Name of the best-matching declared struct.
+    pub struct_name: String,
+    /// Designated field names used in the brace body that matched a field of
+    /// `struct_name`.
+    pub matched_fields: Vec<String>,
+    /// Span of the opening `{` - where `(struct Name)` should be inserted.
+    pub insertion_point: Span,
+    /// The corrected source text, e.g. `(struct Point){ .x = 10, .y = 20 }`.
+    pub suggested_fix: String,
+}
+
+impl CompoundLiteralFix {
+    /// Render as the message/help pair `infer_note_and_help`-style
+    /// diagnostics use.
+    pub fn message(&self) -> String {
+        format!(
+            "compound literal missing or unknown type name (did you mean `struct {}`?)",
+            self.struct_name
+        )
+    }
+
+    /// Actionable help text naming the exact corrected form.
+    pub fn help(&self) -> String {
+        format!(
+            "insert the type name: `{}` matches field{} {}",
+            self.suggested_fix,
+            if self.matched_fields.len() == 1 { "" } else { "s" },
+            self.matched_fields.join(", ")
+        )
+    }
+}
+
+/// Extracts `.field = ` designator names from a brace-delimited initializer
+/// body (the text between - and excluding - the outermost `{` `}`).
+///
+/// Purely lexical: splits on top-level commas and looks for a leading
+/// `.identifier =`. This is enough to recognize the shape DECY-281 calls
+/// out; it is not a general C expression parser.
+fn designated_field_names(body: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0usize;
+
+    for (i, b) in body.bytes().enumerate() {
+        match b {
+            b'{' | b'(' | b'[' => depth += 1,
+            b'}' | b')' | b']' => depth -= 1,
+            b',' if depth == 0 => {
+                if let Some(name) = designator_name(&body[start..i]) {
+                    names.push(name);
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if let Some(name) = designator_name(&body[start..]) {
+        names.push(name);
+    }
+    names
+}
+
+/// Parses a single `.field = ...` element, returning `field` if it really is
+/// a designator (a leading `.identifier` followed by `=`).
+fn designator_name(element: &str) -> Option<String> {
+    let rest = element.trim_start().strip_prefix('.')?;
+    let ident_len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    if ident_len == 0 {
+        return None;
+    }
+    let ident = &rest[..ident_len];
+    rest[ident_len..]
+        .trim_start()
+        .starts_with('=')
+        .then(|| ident.to_string())
+}
+
+/// Finds the `{ ... }` block whose opening brace is at byte offset
+/// `open_brace` in `source`, returning its body (excluding both braces) and
+/// the byte offset just past the closing `}`.
+fn brace_body(source: &str, open_brace: usize) -> Option<(&str, usize)> {
+    let bytes = source.as_bytes();
+    if bytes.get(open_brace) != Some(&b'{') {
+        return None;
+    }
+
+    let mut depth = 0i32;
+    for (i, &b) in bytes[open_brace..].iter().enumerate() {
+        match b {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let close = open_brace + i;
+                    return Some((&source[open_brace + 1..close], close + 1));
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Suggests the best-matching declared struct for a `{ .field = value, ... }`
+/// block found at `open_brace` in `source` - a diagnosis for a compound
+/// literal whose `(struct Name)` cast-type prefix is missing or unrecognized.
+///
+/// "Best-matching" is whichever declared struct shares the most designated
+/// field names with the brace body; ties keep the first declared. Returns
+/// `None` if `open_brace` isn't actually a `{`, the body has no designated
+/// initializers, or no declared struct shares a field name with it.
+pub fn suggest_compound_literal_fix(
+    source: &str,
+    open_brace: usize,
+    structs: &[Struct],
+) -> Option<CompoundLiteralFix> {
+    let (body, close) = brace_body(source, open_brace)?;
+    let designators = designated_field_names(body);
+    if designators.is_empty() {
+        return None;
+    }
+
+    // `max_by_key` keeps the last of equal maxima, so walk in reverse to
+    // let the first declared struct win a tie.
+    let (matched_struct, matched_fields) = structs
+        .iter()
+        .rev()
+        .map(|s| {
+            let matched: Vec<String> = designators
+                .iter()
+                .filter(|d| s.fields.iter().any(|f| f.name() == d.as_str()))
+                .cloned()
+                .collect();
+            (s, matched)
+        })
+        .filter(|(_, matched)| !matched.is_empty())
+        .max_by_key(|(_, matched)| matched.len())?;
+
+    let literal_text = &source[open_brace..close];
+    let suggested_fix = format!("(struct {}){}", matched_struct.name, literal_text);
+
+    Some(CompoundLiteralFix {
+        struct_name: matched_struct.name.clone(),
+        matched_fields,
+        insertion_point: Span::new(BytePos(open_brace as u32), BytePos(open_brace as u32)),
+        suggested_fix,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{StructField, Type};
+
+    fn point_struct() -> Struct {
+        Struct::new(
+            "Point".to_string(),
+            vec![
+                StructField::new("x".to_string(), Type::Int),
+                StructField::new("y".to_string(), Type::Int),
+            ],
+        )
+    }
+
+    fn rect_struct() -> Struct {
+        Struct::new(
+            "Rect".to_string(),
+            vec![
+                StructField::new("x".to_string(), Type::Int),
+                StructField::new("y".to_string(), Type::Int),
+                StructField::new("w".to_string(), Type::Int),
+                StructField::new("h".to_string(), Type::Int),
+            ],
+        )
+    }
+
+    #[test]
+    fn suggests_the_best_matching_struct() {
+        let source = "Point p = { .x = 10, .y = 20 };";
+        let open_brace = source.find('{').unwrap();
+        let structs = vec![point_struct(), rect_struct()];
+
+        let fix = suggest_compound_literal_fix(source, open_brace, &structs).unwrap();
+        assert_eq!(fix.struct_name, "Point");
+        assert_eq!(fix.matched_fields, vec!["x", "y"]);
+        assert_eq!(fix.suggested_fix, "(struct Point){ .x = 10, .y = 20 }");
+    }
+
+    #[test]
+    fn picks_the_struct_with_more_matching_fields() {
+        let source = "foo({ .x = 0, .y = 0, .w = 100, .h = 50 });";
+        let open_brace = source.find('{').unwrap();
+        let structs = vec![point_struct(), rect_struct()];
+
+        let fix = suggest_compound_literal_fix(source, open_brace, &structs).unwrap();
+        assert_eq!(fix.struct_name, "Rect");
+    }
+
+    #[test]
+    fn returns_none_when_no_struct_shares_a_field() {
+        let source = "{ .q = 1 }";
+        let open_brace = source.find('{').unwrap();
+        let structs = vec![point_struct()];
+
+        assert!(suggest_compound_literal_fix(source, open_brace, &structs).is_none());
+    }
+
+    #[test]
+    fn returns_none_for_positional_initializers() {
+        let source = "{ 10, 20 }";
+        let open_brace = source.find('{').unwrap();
+        let structs = vec![point_struct()];
+
+        assert!(suggest_compound_literal_fix(source, open_brace, &structs).is_none());
+    }
+
+    #[test]
+    fn returns_none_when_offset_is_not_an_open_brace() {
+        let source = "Point p = { .x = 10 };";
+        let structs = vec![point_struct()];
+
+        assert!(suggest_compound_literal_fix(source, 0, &structs).is_none());
+    }
+}
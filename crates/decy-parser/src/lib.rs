@@ -7,6 +7,12 @@
 // Note: clang-sys requires unsafe for FFI, but we allow it only in this crate
 #![allow(unsafe_code)]
 
+pub mod compound_literal_fix;
+pub mod diagnostic;
 pub mod parser;
+pub mod span;
 
+pub use compound_literal_fix::{suggest_compound_literal_fix, CompoundLiteralFix};
+pub use diagnostic::{Diagnostic, Severity};
 pub use parser::{Ast, CParser, Function, Parameter, Type};
+pub use span::{BytePos, SourceMap, Span};
@@ -91,6 +91,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_recovering_reports_errors_but_keeps_good_functions() {
+        let parser = CParser::new().expect("Parser creation failed");
+        // The first function is missing a closing brace; the second is fine.
+        let source = r#"
+            int broken(int a, int b) { return a + b
+            int ok(void) { return 0; }
+        "#;
+
+        let (ast, diagnostics) = parser.parse_recovering(source);
+
+        assert!(
+            !diagnostics.is_empty(),
+            "Broken function should produce at least one diagnostic"
+        );
+        assert!(
+            ast.functions().iter().any(|f| f.name == "ok"),
+            "Well-formed function after the broken one should still be parsed"
+        );
+    }
+
+    #[test]
+    fn test_parse_recovering_enriches_compound_literal_diagnostic() {
+        let parser = CParser::new().expect("Parser creation failed");
+        // `{ .x = 10, .y = 20 }` used as a call argument is missing its
+        // `(struct Point)` cast-type prefix, which clang reports as a bare
+        // "expected expression" at the brace; DECY-281 turns that into a
+        // suggestion naming `Point`.
+        let source = r#"
+            struct Point { int x; int y; };
+            void consume(struct Point p);
+            int test(void) {
+                consume({ .x = 10, .y = 20 });
+                return 0;
+            }
+        "#;
+
+        let (_ast, diagnostics) = parser.parse_recovering(source);
+
+        assert!(
+            diagnostics
+                .iter()
+                .any(|d| d.message.contains("struct Point")),
+            "Expected a compound-literal fix suggestion naming Point, got: {diagnostics:?}"
+        );
+    }
+
+    #[test]
+    fn test_parse_recovering_clean_source_has_no_errors() {
+        let parser = CParser::new().expect("Parser creation failed");
+        let source = "int main(void) { return 0; }";
+
+        let (ast, diagnostics) = parser.parse_recovering(source);
+
+        assert_eq!(ast.functions().len(), 1);
+        assert!(
+            diagnostics.is_empty(),
+            "Clean source should not produce diagnostics"
+        );
+    }
+
+    #[test]
+    fn test_take_errors_drains_diagnostics_from_parse_recovering() {
+        let parser = CParser::new().expect("Parser creation failed");
+        let source = "int broken(int a, int b) { return a + b";
+
+        let (_ast, diagnostics) = parser.parse_recovering(source);
+        assert!(!diagnostics.is_empty());
+
+        let taken = parser.take_errors();
+        assert_eq!(taken.len(), diagnostics.len());
+
+        // A second call finds nothing left to drain.
+        assert!(parser.take_errors().is_empty());
+    }
+
+    #[test]
+    fn test_parse_recovering_empty_input() {
+        let parser = CParser::new().expect("Parser creation failed");
+        let (ast, diagnostics) = parser.parse_recovering("");
+
+        assert_eq!(ast.functions().len(), 0);
+        assert!(diagnostics.is_empty());
+    }
+
     #[test]
     fn test_parse_empty_input() {
         // RED PHASE: This test will FAIL
@@ -322,6 +407,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_ternary_expression() {
+        // DECY-192: Test that `cond ? then : else` parses as Expression::Ternary
+        let parser = CParser::new().expect("Parser creation failed");
+        let source = "int max(int a, int b) { return a > b ? a : b; }";
+
+        let ast = parser
+            .parse(source)
+            .expect("Parsing ternary expression should succeed");
+
+        let func = &ast.functions()[0];
+        assert_eq!(func.name, "max");
+        assert_eq!(func.body.len(), 1, "Should have one statement");
+
+        match &func.body[0] {
+            Statement::Return(Some(Expression::Ternary {
+                condition,
+                then_expr,
+                else_expr,
+            })) => {
+                assert!(
+                    matches!(**condition, Expression::BinaryOp { op: BinaryOperator::GreaterThan, .. }),
+                    "Condition should be a > b"
+                );
+                match **then_expr {
+                    Expression::Variable(ref name) => {
+                        assert_eq!(name, "a", "Then-arm should be variable 'a'");
+                    }
+                    _ => panic!("Then-arm should be a variable"),
+                }
+                match **else_expr {
+                    Expression::Variable(ref name) => {
+                        assert_eq!(name, "b", "Else-arm should be variable 'b'");
+                    }
+                    _ => panic!("Else-arm should be a variable"),
+                }
+            }
+            _ => panic!("Expected Return statement with Ternary expression"),
+        }
+    }
+
     #[test]
     fn test_parse_assignment_statement() {
         // DECY-028 Phase 3: Test that assignment statements are parsed
@@ -1544,6 +1670,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_struct_bitfield_members_capture_bit_width() {
+        let parser = CParser::new().expect("Parser creation failed");
+        let source = r#"
+            struct Flags {
+                unsigned int ready : 1;
+                unsigned int mode : 3;
+                unsigned int reserved : 28;
+            };
+        "#;
+
+        let ast = parser.parse(source).expect("Parsing bitfield struct should succeed");
+
+        assert_eq!(ast.structs().len(), 1);
+        let flags = &ast.structs()[0];
+        assert_eq!(flags.fields[0].name, "ready");
+        assert_eq!(flags.fields[0].bit_width, Some(1));
+        assert_eq!(flags.fields[1].bit_width, Some(3));
+        assert_eq!(flags.fields[2].bit_width, Some(28));
+        assert!(flags.fields.iter().all(StructField::is_bitfield));
+    }
+
+    #[test]
+    fn test_parse_struct_ordinary_fields_have_no_bit_width() {
+        let parser = CParser::new().expect("Parser creation failed");
+        let source = "struct Point { int x; int y; };";
+
+        let ast = parser.parse(source).expect("Parsing struct should succeed");
+
+        let point = &ast.structs()[0];
+        assert!(point.fields.iter().all(|f| f.bit_width.is_none()));
+        assert!(!point.fields[0].is_bitfield());
+    }
+
     #[test]
     fn test_parse_simple_typedef() {
         // DECY-023 RED PHASE: Test that simple typedefs are parsed
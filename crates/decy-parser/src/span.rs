@@ -0,0 +1,122 @@
+//! Byte-offset source spans and line/column resolution.
+//!
+//! Gives AST nodes enough positional information to point diagnostics back
+//! at the original C source, without requiring every parse rule to carry a
+//! full line/column pair around. A [`Span`] is cheap to copy and compare;
+//! resolving it to a human-readable `(line, column)` pair only happens when
+//! a [`SourceMap`] is asked to do so.
+
+/// A byte offset into the original source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BytePos(pub u32);
+
+/// A half-open byte range `[start, end)` into the original source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset of the first byte covered by this span.
+    pub start: BytePos,
+    /// Byte offset just past the last byte covered by this span.
+    pub end: BytePos,
+}
+
+impl Span {
+    /// Create a new span from a start/end byte offset pair.
+    pub fn new(start: BytePos, end: BytePos) -> Self {
+        Self { start, end }
+    }
+
+    /// A span with no extent, useful as a placeholder for synthesized nodes
+    /// that don't correspond to any real source text.
+    pub fn dummy() -> Self {
+        Self::new(BytePos(0), BytePos(0))
+    }
+}
+
+/// Resolves [`BytePos`] offsets into 1-based `(line, column)` pairs against a
+/// stored copy of the source text.
+///
+/// Line start offsets are computed once at construction time, so repeated
+/// lookups are a binary search rather than a re-scan of the source.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    /// Byte offset of the start of each line (line 0 always starts at byte 0).
+    line_starts: Vec<u32>,
+}
+
+impl SourceMap {
+    /// Build a `SourceMap` from the full source text.
+    pub fn new(source: &str) -> Self {
+        let mut line_starts = vec![0u32];
+        for (i, b) in source.bytes().enumerate() {
+            if b == b'\n' {
+                line_starts.push((i + 1) as u32);
+            }
+        }
+        Self { line_starts }
+    }
+
+    /// Resolve a byte offset to a 1-based `(line, column)` pair.
+    pub fn line_col(&self, pos: BytePos) -> (u32, u32) {
+        let offset = pos.0;
+        let line_idx = match self.line_starts.binary_search(&offset) {
+            Ok(idx) => idx,
+            Err(idx) => idx.saturating_sub(1),
+        };
+        let line_start = self.line_starts[line_idx];
+        let column = offset - line_start;
+        (line_idx as u32 + 1, column + 1)
+    }
+
+    /// Resolve a [`Span`] to its 1-based `(start_line, start_column)` and
+    /// `(end_line, end_column)` pairs.
+    pub fn span_range(&self, span: Span) -> ((u32, u32), (u32, u32)) {
+        (self.line_col(span.start), self.line_col(span.end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_col_on_first_line() {
+        let map = SourceMap::new("int x = 1;");
+        assert_eq!(map.line_col(BytePos(0)), (1, 1));
+        assert_eq!(map.line_col(BytePos(4)), (1, 5));
+    }
+
+    #[test]
+    fn line_col_across_multiple_lines() {
+        let source = "int a;\nint b;\nint c;";
+        let map = SourceMap::new(source);
+        // "int b;" starts at byte 7
+        assert_eq!(map.line_col(BytePos(7)), (2, 1));
+        // "int c;" starts at byte 14
+        assert_eq!(map.line_col(BytePos(14)), (3, 1));
+    }
+
+    #[test]
+    fn line_col_mid_line() {
+        let source = "int a;\nint bb = 2;\n";
+        let map = SourceMap::new(source);
+        // byte 11 is the first 'b' of "bb" on the second line ("int bb = 2;")
+        assert_eq!(map.line_col(BytePos(11)), (2, 5));
+    }
+
+    #[test]
+    fn span_range_resolves_both_endpoints() {
+        let source = "int a;\nint bb = 2;\n";
+        let map = SourceMap::new(source);
+        let span = Span::new(BytePos(7), BytePos(18));
+        let (start, end) = map.span_range(span);
+        assert_eq!(start, (2, 1));
+        assert_eq!(end, (2, 12));
+    }
+
+    #[test]
+    fn dummy_span_is_zero_width_at_origin() {
+        let span = Span::dummy();
+        assert_eq!(span.start, BytePos(0));
+        assert_eq!(span.end, BytePos(0));
+    }
+}